@@ -44,6 +44,54 @@ impl ProcessorAir {
     pub fn last_step(&self) -> usize {
         self.trace_length() - self.context().num_transition_exemptions()
     }
+
+    /// Returns the name of the VM component which defines the main transition constraint at the
+    /// specified index.
+    ///
+    /// # Panics
+    /// Panics if the index is greater than or equal to the number of main transition constraints.
+    pub fn get_transition_constraint_component(&self, idx: usize) -> &'static str {
+        if idx < self.constraint_ranges.stack.start {
+            "system"
+        } else if idx < self.constraint_ranges.stack.end {
+            "stack"
+        } else if idx < self.constraint_ranges.range_checker.end {
+            "range checker"
+        } else if idx < self.constraint_ranges.chiplets.end {
+            "chiplets"
+        } else {
+            panic!("invalid transition constraint index {}", idx)
+        }
+    }
+
+    /// Returns the name of the VM component which defines the auxiliary transition constraint at
+    /// the specified index.
+    ///
+    /// # Panics
+    /// Panics if the index is greater than or equal to the number of auxiliary transition
+    /// constraints.
+    pub fn get_aux_transition_constraint_component(&self, idx: usize) -> &'static str {
+        // currently, only the range checker defines constraints against auxiliary columns
+        assert!(
+            idx < range::NUM_AUX_CONSTRAINTS,
+            "invalid auxiliary transition constraint index {}",
+            idx
+        );
+        "range checker"
+    }
+
+    /// Returns the smallest blowup factor which can be used to generate proofs for this AIR.
+    ///
+    /// The blowup factor must be large enough to accommodate the degree of the highest degree
+    /// transition constraint.
+    pub fn min_blowup_factor() -> usize {
+        get_main_transition_constraint_degrees()
+            .iter()
+            .chain(range::get_aux_transition_constraint_degrees().iter())
+            .map(|degree| degree.min_blowup_factor())
+            .max()
+            .unwrap_or(WinterProofOptions::MIN_BLOWUP_FACTOR)
+    }
 }
 
 impl Air for ProcessorAir {
@@ -51,25 +99,9 @@ impl Air for ProcessorAir {
     type PublicInputs = PublicInputs;
 
     fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: WinterProofOptions) -> Self {
-        // --- system -----------------------------------------------------------------------------
-        let mut main_degrees = vec![
-            TransitionConstraintDegree::new(1), // clk' = clk + 1
-        ];
-
-        // --- stack constraints -------------------------------------------------------------------
-        let mut stack_degrees = stack::get_transition_constraint_degrees();
-        main_degrees.append(&mut stack_degrees);
-
-        // --- range checker ----------------------------------------------------------------------
-        let mut range_checker_degrees = range::get_transition_constraint_degrees();
-        main_degrees.append(&mut range_checker_degrees);
-
+        let main_degrees = get_main_transition_constraint_degrees();
         let aux_degrees = range::get_aux_transition_constraint_degrees();
 
-        // --- chiplets (hasher, bitwise, memory) -------------------------
-        let mut chiplets_degrees = chiplets::get_transition_constraint_degrees();
-        main_degrees.append(&mut chiplets_degrees);
-
         // Define the transition constraint ranges.
         let constraint_ranges = TransitionConstraintRange::new(
             1,
@@ -236,6 +268,32 @@ impl Air for ProcessorAir {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns degrees of all transition constraints against the main segment of the execution trace
+/// in the order in which they are evaluated: system, stack, range checker, and chiplets.
+fn get_main_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    // --- system ---------------------------------------------------------------------------------
+    let mut main_degrees = vec![
+        TransitionConstraintDegree::new(1), // clk' = clk + 1
+    ];
+
+    // --- stack constraints ----------------------------------------------------------------------
+    let mut stack_degrees = stack::get_transition_constraint_degrees();
+    main_degrees.append(&mut stack_degrees);
+
+    // --- range checker --------------------------------------------------------------------------
+    let mut range_checker_degrees = range::get_transition_constraint_degrees();
+    main_degrees.append(&mut range_checker_degrees);
+
+    // --- chiplets (hasher, bitwise, memory) -----------------------------------------------------
+    let mut chiplets_degrees = chiplets::get_transition_constraint_degrees();
+    main_degrees.append(&mut chiplets_degrees);
+
+    main_degrees
+}
+
 // PUBLIC INPUTS
 // ================================================================================================
 
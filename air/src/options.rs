@@ -2,7 +2,7 @@ use core::ops::Deref;
 use winter_air::{FieldExtension, HashFunction, ProofOptions as WinterProofOptions};
 
 /// TODO: add docs
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProofOptions(WinterProofOptions);
 
 impl ProofOptions {
@@ -61,6 +61,12 @@ impl Default for ProofOptions {
     }
 }
 
+impl From<WinterProofOptions> for ProofOptions {
+    fn from(options: WinterProofOptions) -> Self {
+        Self(options)
+    }
+}
+
 impl Deref for ProofOptions {
     type Target = WinterProofOptions;
 
@@ -260,6 +260,7 @@ impl Assembler {
             }
 
             Instruction::AdvU64Div => span.add_decorator(Decorator::Advice(DivResultU64)),
+            Instruction::AdvU256Div => span.add_decorator(Decorator::Advice(DivResultU256)),
             Instruction::AdvKeyval => span.add_decorator(Decorator::Advice(MapValue)),
             Instruction::AdvMem(a, n) => adv_ops::adv_mem(span, *a, *n),
 
@@ -78,7 +78,7 @@ pub fn parse_adv_push(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
-/// Returns `AdvU64Div`, `AdvKeyval`, or `AdvMem`  instruction node.
+/// Returns `AdvU64Div`, `AdvU256Div`, `AdvKeyval`, or `AdvMem`  instruction node.
 ///
 /// # Errors
 /// Returns an error if:
@@ -91,6 +91,10 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             validate_operation!(op, "adv.u64div", 0);
             Ok(Instruction(AdvU64Div))
         }
+        "u256div" => {
+            validate_operation!(op, "adv.u256div", 0);
+            Ok(Instruction(AdvU256Div))
+        }
         "keyval" => {
             validate_operation!(op, "adv.keyval", 0);
             Ok(Instruction(AdvKeyval))
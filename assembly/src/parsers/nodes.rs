@@ -235,6 +235,7 @@ pub enum Instruction {
     AdvLoadW,
 
     AdvU64Div,
+    AdvU256Div,
     AdvKeyval,
     AdvMem(u32, u32),
 
@@ -480,6 +481,7 @@ impl fmt::Display for Instruction {
             Self::AdvLoadW => write!(f, "adv_loadw"),
 
             Self::AdvU64Div => write!(f, "adv.u64div"),
+            Self::AdvU256Div => write!(f, "adv.u256div"),
             Self::AdvKeyval => write!(f, "adv.keyval"),
             Self::AdvMem(start_addr, num_words) => write!(f, "adv.mem.{start_addr}.{num_words}"),
 
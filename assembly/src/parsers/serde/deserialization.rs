@@ -401,6 +401,7 @@ impl Deserializable for Instruction {
             OpCode::AdvPipe => Ok(Instruction::AdvPipe),
 
             OpCode::AdvU64Div => Ok(Instruction::AdvU64Div),
+            OpCode::AdvU256Div => Ok(Instruction::AdvU256Div),
             OpCode::AdvKeyval => Ok(Instruction::AdvKeyval),
             OpCode::AdvMem => {
                 let start_addr = bytes.read_u32()?;
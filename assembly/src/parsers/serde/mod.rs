@@ -249,4 +249,7 @@ pub enum OpCode {
     CallLocal = 215,
     CallImported = 216,
     SysCall = 217,
+
+    // ----- input / output operations (continued) --------------------------------------------
+    AdvU256Div = 218,
 }
@@ -477,6 +477,7 @@ impl Serializable for Instruction {
             Self::AdvPipe => target.write_opcode(OpCode::AdvPipe),
 
             Self::AdvU64Div => target.write_opcode(OpCode::AdvU64Div),
+            Self::AdvU256Div => target.write_opcode(OpCode::AdvU256Div),
             Self::AdvKeyval => target.write_opcode(OpCode::AdvKeyval),
             Self::AdvMem(start_addr, num_words) => {
                 target.write_opcode(OpCode::AdvMem);
@@ -153,9 +153,10 @@ fn test_ast_parsing_adv_ops() {
 
 #[test]
 fn test_ast_parsing_adv_injection() {
-    let source = "begin adv.u64div adv.keyval adv.mem.1.1 end";
+    let source = "begin adv.u64div adv.u256div adv.keyval adv.mem.1.1 end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(Instruction::AdvU64Div),
+        Node::Instruction(Instruction::AdvU256Div),
         Node::Instruction(Instruction::AdvKeyval),
         Node::Instruction(Instruction::AdvMem(1, 1)),
     ];
@@ -46,6 +46,24 @@ impl AdviceSet {
         )?))
     }
 
+    /// Returns a new [AdviceSet] instantiated as a Merkle path set of the specified depth from the
+    /// provided paths. Each path is described by the index and the value of the leaf at which the
+    /// path starts, and by the list of nodes in the path (the leaf itself is not included).
+    ///
+    /// # Errors
+    /// Returns an error if any of the paths is inconsistent with the specified depth, or if the
+    /// paths do not resolve to the same root.
+    pub fn new_merkle_path_set(
+        depth: u32,
+        paths: Vec<(u64, Word, Vec<Word>)>,
+    ) -> Result<Self, AdviceSetError> {
+        let mut set = MerklePathSet::new(depth)?;
+        for (index, value, path) in paths {
+            set.add_path(index, value, path)?;
+        }
+        Ok(Self::MerklePathSet(set))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
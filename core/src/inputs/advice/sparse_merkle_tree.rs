@@ -21,6 +21,11 @@ pub struct SparseMerkleTree {
 }
 
 impl SparseMerkleTree {
+    /// Returns a new sparse Merkle tree of the specified depth with the provided leaves.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is greater than 63, or if any of the keys is not a valid leaf
+    /// index for a tree of the specified depth.
     pub fn new(keys: Vec<u64>, values: Vec<Word>, depth: u32) -> Result<Self, AdviceSetError> {
         if depth > 63 {
             return Err(AdviceSetError::DepthTooBig(depth));
@@ -28,8 +33,7 @@ impl SparseMerkleTree {
         let (store, root) = Store::new(depth);
         let mut tree = Self { root, depth, store };
         for (key, val) in keys.into_iter().zip(values) {
-            tree.insert_leaf(key, val)
-                .expect("Failed to insert leaf value");
+            tree.insert_leaf(key, val)?;
         }
         Ok(tree)
     }
@@ -46,19 +50,27 @@ impl SparseMerkleTree {
 
     /// Returns a node at the specified key
     ///
+    /// Nodes which have not been set are empty, i.e., a leaf which has not been inserted is a word
+    /// of zeros, and an internal node with no leaves below it is the root of an empty subtree.
+    ///
     /// # Errors
     /// Returns an error if:
     /// * The specified depth is greater than the depth of the tree.
-    /// * The specified key does not exist
+    /// * The specified key is not valid for the specified depth.
     pub fn get_node(&self, depth: u32, key: u64) -> Result<Word, AdviceSetError> {
         if depth == 0 {
             Err(AdviceSetError::DepthTooSmall)
         } else if depth > self.depth() {
             Err(AdviceSetError::DepthTooBig(depth))
+        } else if key >= 2u64.pow(depth) {
+            Err(AdviceSetError::InvalidIndex(depth, key))
         } else if depth == self.depth() {
-            self.store.get_leaf_node(key)
+            Ok(self.store.get_leaf_node(key).unwrap_or_default())
         } else {
-            let branch_node = self.store.get_branch_node(key, depth)?;
+            let branch_node = self
+                .store
+                .get_branch_node(key, depth)
+                .unwrap_or_else(|_| self.store.get_empty_node((depth + 1) as usize));
             Ok(hasher::merge(&[branch_node.left, branch_node.right]).into())
         }
     }
@@ -66,25 +78,30 @@ impl SparseMerkleTree {
     /// Returns a Merkle path to the node at the specified key. The node itself is
     /// not included in the path.
     ///
+    /// Siblings which have not been set are roots of empty subtrees, and thus, a path can be
+    /// obtained for any node of the tree.
+    ///
     /// # Errors
     /// Returns an error if:
-    /// * The specified key does not exist as a branch or leaf node
     /// * The specified depth is greater than the depth of the tree.
+    /// * The specified key is not valid for the specified depth.
     pub fn get_path(&self, depth: u32, key: u64) -> Result<Vec<Word>, AdviceSetError> {
-        if self.store.get_leaf_node(key).is_err() {
-            return Err(AdviceSetError::InvalidKey(key));
-        }
         if depth == 0 {
             return Err(AdviceSetError::DepthTooSmall);
         } else if depth > self.depth() {
             return Err(AdviceSetError::DepthTooBig(depth));
+        } else if key >= 2u64.pow(depth) {
+            return Err(AdviceSetError::InvalidIndex(depth, key));
         }
 
         let mut path = Vec::with_capacity(depth as usize);
         let mut curr_key = key;
         for n in (0..depth).rev() {
             let parent_key = curr_key >> 1;
-            let parent_node = self.store.get_branch_node(parent_key, n)?;
+            let parent_node = self
+                .store
+                .get_branch_node(parent_key, n)
+                .unwrap_or_else(|_| self.store.get_empty_node((n + 1) as usize));
             let sibling_node = if curr_key & 1 == 1 {
                 parent_node.left
             } else {
@@ -98,19 +115,22 @@ impl SparseMerkleTree {
 
     /// Replaces the leaf located at the specified key, and recomputes hashes by walking up the tree
     ///
+    /// A leaf which has not been inserted yet is empty, and it can be replaced as well.
+    ///
     /// # Errors
     /// Returns an error if the specified key is not a valid leaf index for this tree.
     pub fn update_leaf(&mut self, key: u64, value: Word) -> Result<(), AdviceSetError> {
-        if self.store.get_leaf_node(key).is_err() {
-            return Err(AdviceSetError::InvalidKey(key));
-        }
-        self.insert_leaf(key, value)?;
-
-        Ok(())
+        self.insert_leaf(key, value)
     }
 
     /// Inserts a leaf located at the specified key, and recomputes hashes by walking up the tree
+    ///
+    /// # Errors
+    /// Returns an error if the specified key is not a valid leaf index for this tree.
     pub fn insert_leaf(&mut self, key: u64, value: Word) -> Result<(), AdviceSetError> {
+        if key >= 2u64.pow(self.depth()) {
+            return Err(AdviceSetError::InvalidKey(key));
+        }
         self.store.insert_leaf_node(key, value);
 
         let depth = self.depth();
@@ -225,7 +245,7 @@ mod tests {
     use super::{
         super::{Felt, FieldElement},
         super::{MerkleTree, SparseMerkleTree},
-        Word,
+        AdviceSetError, Word,
     };
     use crypto::{hashers::Rp64_256, ElementHasher, Hasher};
 
@@ -349,6 +369,38 @@ mod tests {
         assert_eq!(expected_tree.root, tree.root);
     }
 
+    #[test]
+    fn get_empty_nodes() {
+        let mut smt = SparseMerkleTree::new(vec![6], vec![int_to_node(7)], 3).unwrap();
+        let mut values = ZERO_VALUES8.to_vec();
+        values[6] = int_to_node(7);
+        let mt = MerkleTree::new(values.clone()).unwrap();
+
+        // empty leaves and internal nodes are the same as in a tree filled with zeros
+        assert_eq!(int_to_node(0), smt.get_node(3, 2).unwrap());
+        assert_eq!(mt.get_node(1, 0).unwrap(), smt.get_node(1, 0).unwrap());
+        assert_eq!(mt.get_node(2, 3).unwrap(), smt.get_node(2, 3).unwrap());
+        assert_eq!(mt.get_path(3, 2).unwrap(), smt.get_path(3, 2).unwrap());
+        assert_eq!(mt.get_path(2, 0).unwrap(), smt.get_path(2, 0).unwrap());
+
+        // an empty leaf can be updated
+        values[1] = int_to_node(9);
+        smt.update_leaf(1, int_to_node(9)).unwrap();
+        let mt = MerkleTree::new(values).unwrap();
+        assert_eq!(mt.root(), smt.root());
+
+        // keys outside of the tree are rejected
+        assert!(smt.get_node(3, 8).is_err());
+        assert!(smt.get_path(2, 4).is_err());
+        assert!(smt.update_leaf(8, int_to_node(1)).is_err());
+    }
+
+    #[test]
+    fn build_tree_with_invalid_key() {
+        let result = SparseMerkleTree::new(vec![1, 8], vec![int_to_node(1), int_to_node(2)], 3);
+        assert!(matches!(result, Err(AdviceSetError::InvalidKey(8))));
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
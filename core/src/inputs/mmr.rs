@@ -0,0 +1,221 @@
+use super::{hasher, AdviceSet, Felt, FieldElement, IntoBytes, Word};
+use winter_utils::collections::Vec;
+
+// MERKLE MOUNTAIN RANGE
+// ================================================================================================
+
+/// A reference implementation of the Merkle Mountain Range used by `std::collections::mmr`.
+///
+/// An MMR is an append-only accumulator represented by a list of peaks, where each peak is the
+/// root of a fully-balanced Merkle tree. Trees correspond to the bits set in the binary
+/// representation of the number of leaves, and peaks are ordered from the largest tree to the
+/// smallest one. For example, an MMR with 7 leaves consists of 3 trees with 4, 2, and 1 leaves.
+///
+/// This struct keeps all leaves in memory and is intended to be used for generating inputs for
+/// the Miden VM programs which work with MMRs (and for checking their outputs):
+/// - [Mmr::advice_sets()] returns the Merkle trees which are needed to read leaves via
+///   `mmr::get`.
+/// - [Mmr::advice_map_entry()] returns the key-value pair which is needed to load the MMR into
+///   memory via `mmr::unpack`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Mmr {
+    leaves: Vec<Word>,
+}
+
+impl Mmr {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [Mmr].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [Mmr] instantiated from the provided leaves.
+    pub fn from_leaves<I: IntoIterator<Item = Word>>(leaves: I) -> Self {
+        Self {
+            leaves: leaves.into_iter().collect(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of leaves in this MMR.
+    pub fn num_leaves(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns the leaf at the specified position, or None if the position is out of bounds.
+    pub fn get(&self, pos: usize) -> Option<Word> {
+        self.leaves.get(pos).copied()
+    }
+
+    /// Returns the peaks of this MMR ordered from the largest tree to the smallest one.
+    pub fn peaks(&self) -> Vec<Word> {
+        self.trees().map(build_root).collect()
+    }
+
+    /// Returns the Merkle trees of all peaks which have more than one leaf.
+    ///
+    /// Peaks which consist of a single leaf are equal to that leaf, and thus, the leaf can be
+    /// read without a Merkle tree.
+    pub fn advice_sets(&self) -> Vec<AdviceSet> {
+        self.trees()
+            .filter(|leaves| leaves.len() > 1)
+            .map(|leaves| {
+                AdviceSet::new_merkle_tree(leaves.to_vec())
+                    .expect("number of leaves is a power of two")
+            })
+            .collect()
+    }
+
+    /// Returns the elements of this MMR as they are laid out in memory by `std::collections::mmr`.
+    ///
+    /// The first word is [num_leaves, 0, 0, 0] and it is followed by the peaks. If the number of
+    /// words is odd, a word of zeros is appended at the end.
+    pub fn to_elements(&self) -> Vec<Felt> {
+        let peaks = self.peaks();
+        let num_words = (peaks.len() + 2) & !1;
+
+        let mut elements = Vec::with_capacity(num_words * 4);
+        elements.push(Felt::new(self.leaves.len() as u64));
+        elements.resize(4, Felt::ZERO);
+        for peak in peaks.iter() {
+            elements.extend_from_slice(peak);
+        }
+        elements.resize(num_words * 4, Felt::ZERO);
+        elements
+    }
+
+    /// Returns a commitment to this MMR.
+    ///
+    /// The commitment is a sequential hash of the elements returned by [Mmr::to_elements()], and
+    /// it is equal to the value returned by `mmr::pack`.
+    pub fn commitment(&self) -> Word {
+        hasher::hash_elements(&self.to_elements()).into()
+    }
+
+    /// Returns the key-value pair which enables `mmr::unpack` to load this MMR from the advice
+    /// provider.
+    ///
+    /// The key is the commitment to this MMR. The value starts with the number of leaves, which
+    /// is followed by the elements returned by [Mmr::to_elements()].
+    pub fn advice_map_entry(&self) -> ([u8; 32], Vec<Felt>) {
+        let elements = self.to_elements();
+        let key: Word = hasher::hash_elements(&elements).into();
+
+        let mut value = Vec::with_capacity(elements.len() + 1);
+        value.push(Felt::new(self.leaves.len() as u64));
+        value.extend_from_slice(&elements);
+
+        (key.into_bytes(), value)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the provided leaf to this MMR.
+    pub fn add(&mut self, leaf: Word) {
+        self.leaves.push(leaf);
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an iterator over the leaves of each tree in this MMR, starting with the largest
+    /// tree.
+    fn trees(&self) -> impl Iterator<Item = &[Word]> {
+        let num_leaves = self.leaves.len();
+        (0..usize::BITS)
+            .rev()
+            .filter(move |&height| num_leaves & (1 << height) != 0)
+            .scan(0, move |offset, height| {
+                let start = *offset;
+                *offset += 1 << height;
+                Some(&self.leaves[start..*offset])
+            })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the root of a fully-balanced Merkle tree with the provided leaves.
+fn build_root(leaves: &[Word]) -> Word {
+    if leaves.len() == 1 {
+        return leaves[0];
+    }
+    let (left, right) = leaves.split_at(leaves.len() / 2);
+    hasher::merge(&[build_root(left).into(), build_root(right).into()]).into()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{hasher, AdviceSet, Felt, FieldElement, IntoBytes, Mmr, Word};
+
+    #[test]
+    fn peaks() {
+        let leaves = (1..=7).map(int_to_node).collect::<Vec<_>>();
+        let mmr = Mmr::from_leaves(leaves.clone());
+        assert_eq!(7, mmr.num_leaves());
+
+        let tree = AdviceSet::new_merkle_tree(leaves[..4].to_vec()).unwrap();
+        let expected = vec![tree.root(), merge(leaves[4], leaves[5]), leaves[6]];
+        assert_eq!(expected, mmr.peaks());
+
+        // only the trees with more than one leaf are returned as advice sets
+        let roots = mmr
+            .advice_sets()
+            .iter()
+            .map(|set| set.root())
+            .collect::<Vec<_>>();
+        assert_eq!(expected[..2], roots);
+
+        // adding a leaf merges all peaks into a single one
+        let mut mmr = mmr;
+        mmr.add(int_to_node(8));
+        let tree = AdviceSet::new_merkle_tree((1..=8).map(int_to_node).collect()).unwrap();
+        assert_eq!(vec![tree.root()], mmr.peaks());
+        assert_eq!(Some(int_to_node(8)), mmr.get(7));
+        assert_eq!(None, mmr.get(8));
+    }
+
+    #[test]
+    fn packed_elements() {
+        // one peak: two words
+        let mmr = Mmr::from_leaves([int_to_node(1)]);
+        let mut expected = vec![Felt::ONE, Felt::ZERO, Felt::ZERO, Felt::ZERO];
+        expected.extend_from_slice(&int_to_node(1));
+        assert_eq!(expected, mmr.to_elements());
+
+        // two peaks: the number of words is padded to four
+        let mmr = Mmr::from_leaves((1..=3).map(int_to_node));
+        let elements = mmr.to_elements();
+        assert_eq!(16, elements.len());
+        assert_eq!([Felt::ZERO; 4], elements[12..]);
+
+        // the commitment is the hash of the elements; the map value is prefixed by the number of
+        // leaves
+        let (key, value) = mmr.advice_map_entry();
+        let commitment: Word = hasher::hash_elements(&elements).into();
+        assert_eq!(commitment, mmr.commitment());
+        assert_eq!(mmr.commitment().into_bytes(), key);
+        assert_eq!(Felt::new(3), value[0]);
+        assert_eq!(elements, value[1..]);
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    const fn int_to_node(value: u64) -> Word {
+        [Felt::new(value), Felt::ZERO, Felt::ZERO, Felt::ZERO]
+    }
+
+    fn merge(left: Word, right: Word) -> Word {
+        hasher::merge(&[left.into(), right.into()]).into()
+    }
+}
@@ -10,6 +10,9 @@ use winter_utils::collections::{BTreeMap, Vec};
 mod advice;
 pub use advice::AdviceSet;
 
+mod mmr;
+pub use mmr::Mmr;
+
 // PROGRAM INPUTS
 // ================================================================================================
 
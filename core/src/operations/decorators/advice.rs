@@ -19,6 +19,15 @@ pub enum AdviceInjector {
     /// then the quotient is injected.
     DivResultU64,
 
+    /// Injects the result of u256 division (both the quotient and the remainder) at the head of
+    /// the advice tape. The stack is expected to be arranged as follows (from the top):
+    /// - divisor split into eight 32-bit elements, the most significant limb first
+    /// - dividend split into eight 32-bit elements, the most significant limb first
+    ///
+    /// The result is injected into the advice tape as follows: first the remainder is injected,
+    /// then the quotient is injected.
+    DivResultU256,
+
     /// Injects a list of field elements at the front of the advice tape. The list is looked up in
     /// the key-value map maintained by the advice provider using the top 4 elements on the stack
     /// as the key.
@@ -33,6 +42,7 @@ impl fmt::Display for AdviceInjector {
         match self {
             Self::MerkleNode => write!(f, "merkle_node"),
             Self::DivResultU64 => write!(f, "div_result_u64"),
+            Self::DivResultU256 => write!(f, "div_result_u256"),
             Self::MapValue => write!(f, "map_value"),
             Self::Memory(start_addr, num_words) => write!(f, "mem({}, {})", start_addr, num_words),
         }
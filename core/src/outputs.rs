@@ -20,7 +20,7 @@ use winter_utils::collections::Vec;
 /// overflow table (the row representing the deepest element in the stack) and then be followed by
 /// the address (`clk` value) of each row in the table starting from the deepest element in the
 /// stack and finishing with the row which was added to the table last.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ProgramOutputs {
     /// The elements on the stack at the end of execution.
     stack: Vec<u64>,
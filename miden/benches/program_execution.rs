@@ -1,10 +1,32 @@
 use assembly::Assembler;
 use criterion::{criterion_group, criterion_main, Criterion};
 use processor::execute;
+use prover::{prove, ProofOptions};
 use std::time::Duration;
 use stdlib::StdLibrary;
 use vm_core::ProgramInputs;
 
+/// A program which makes heavy use of the hasher, bitwise, and memory chiplets. The loop is
+/// executed 2^12 times, which results in an execution trace of 2^18 rows.
+const CHIPLETS_LOOP: &str = "
+    begin
+        push.4096
+        push.1
+        while.true
+            padw padw padw
+            rpperm
+            dropw dropw
+            mem_storew.0
+            dropw
+            push.1234 push.5678 u32checked_and
+            push.4321 u32checked_xor
+            drop
+            sub.1
+            dup neq.0
+        end
+        drop
+    end";
+
 fn program_execution(c: &mut Criterion) {
     let mut group = c.benchmark_group("program_execution");
     group.measurement_time(Duration::from_secs(10));
@@ -23,8 +45,33 @@ fn program_execution(c: &mut Criterion) {
         bench.iter(|| execute(&program, &ProgramInputs::none()));
     });
 
+    group.bench_function("chiplets_loop", |bench| {
+        let program = Assembler::new()
+            .compile(CHIPLETS_LOOP)
+            .expect("Failed to compile test source.");
+        bench.iter(|| execute(&program, &ProgramInputs::none()));
+    });
+
+    group.finish();
+}
+
+fn program_proving(c: &mut Criterion) {
+    let mut group = c.benchmark_group("program_proving");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(30));
+
+    // proving also exercises construction of the auxiliary trace segment, which is built in
+    // multiple threads when the `concurrent` feature is enabled
+    group.bench_function("chiplets_loop", |bench| {
+        let program = Assembler::new()
+            .compile(CHIPLETS_LOOP)
+            .expect("Failed to compile test source.");
+        let options = ProofOptions::with_96_bit_security();
+        bench.iter(|| prove(&program, &ProgramInputs::none(), &options));
+    });
+
     group.finish();
 }
 
-criterion_group!(sha256_group, program_execution);
-criterion_main!(sha256_group);
+criterion_group!(execution_group, program_execution, program_proving);
+criterion_main!(execution_group);
@@ -1,33 +1,79 @@
-use miden::Assembler;
+use miden::{Assembler, ProofBundle};
 use prover::StarkProof;
 use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::{fs, io::Write, time::Instant};
 use stdlib::StdLibrary;
 use vm_core::ProgramOutputs;
-use vm_core::{chiplets::hasher::Digest, Program, ProgramInputs};
+use vm_core::{
+    chiplets::hasher::Digest, utils::IntoBytes, AdviceSet, Felt, FieldElement, Program,
+    ProgramInputs, StarkField, Word,
+};
 use winter_utils::{Deserializable, SliceReader};
 
 // INPUT FILE
 // ================================================================================================
 
-/// Input file struct
-#[derive(Deserialize, Debug)]
+/// Input file struct.
+///
+/// Scalar values (stack inputs, advice tape values, advice map values, leaf indexes and keys) are
+/// specified as decimal strings. Words (advice map keys, Merkle tree leaves and nodes) are
+/// specified as hex-encoded 32-byte strings, where each element of a word is encoded as 8 bytes
+/// in little-endian byte order.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct InputFile {
     pub stack_init: Vec<String>,
     pub advice_tape: Option<Vec<String>>,
+    pub advice_map: Option<BTreeMap<String, Vec<String>>>,
+    pub merkle_trees: Option<Vec<MerkleTreeData>>,
+    pub sparse_merkle_trees: Option<Vec<SparseMerkleTreeData>>,
+    pub merkle_path_sets: Option<Vec<MerklePathSetData>>,
+}
+
+/// A Merkle tree described by its leaves.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MerkleTreeData {
+    pub leaves: Vec<String>,
+}
+
+/// A sparse Merkle tree of the specified depth described by its non-empty leaves.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SparseMerkleTreeData {
+    pub depth: u32,
+    pub leaves: Vec<SparseMerkleLeafData>,
+}
+
+/// A leaf of a sparse Merkle tree.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SparseMerkleLeafData {
+    pub key: String,
+    pub value: String,
+}
+
+/// A set of Merkle paths of the specified depth which resolve to the same root.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MerklePathSetData {
+    pub depth: u32,
+    pub paths: Vec<MerklePathData>,
+}
+
+/// A Merkle path starting at the leaf with the specified index and value. The leaf itself is not
+/// included in the path.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MerklePathData {
+    pub index: String,
+    pub value: String,
+    pub path: Vec<String>,
 }
 
 /// Helper methods to interact with the input file
 impl InputFile {
     pub fn read(inputs_path: &Option<PathBuf>, program_path: &Path) -> Result<Self, String> {
         // if file not specified explicitly and corresponding file with same name as program_path
-        // with '.inputs' extension does't exist, set stack_init to empty vector
+        // with '.inputs' extension does't exist, return empty inputs
         if !inputs_path.is_some() && !program_path.with_extension("inputs").exists() {
-            return Ok(Self {
-                stack_init: Vec::new(),
-                advice_tape: Some(Vec::new()),
-            });
+            return Ok(Self::default());
         }
 
         // If inputs_path has been provided then use this as path. Alternatively we will
@@ -51,27 +97,141 @@ impl InputFile {
     }
 
     /// Returns program inputs.
-    pub fn get_program_inputs(&self) -> ProgramInputs {
-        ProgramInputs::new(&self.stack_init(), &self.advice_tape(), Vec::new()).unwrap()
+    ///
+    /// # Errors
+    /// Returns an error if any of the values in the input file is invalid, or if the described
+    /// advice sets cannot be constructed.
+    pub fn get_program_inputs(&self) -> Result<ProgramInputs, String> {
+        let stack_init = self.stack_init()?;
+        let advice_tape = self.advice_tape()?;
+        let advice_map = self.advice_map()?;
+        let advice_sets = self.advice_sets()?;
+
+        ProgramInputs::with_advice_map(&stack_init, &advice_tape, advice_map, advice_sets)
+            .map_err(|err| format!("Invalid program inputs - {:?}", err))
     }
 
     /// Parse stack_init vector of strings to a vector of u64
-    pub fn stack_init(&self) -> Vec<u64> {
+    pub fn stack_init(&self) -> Result<Vec<u64>, String> {
         self.stack_init
             .iter()
-            .map(|v| v.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>()
+            .map(|v| parse_element(v, "stack input"))
+            .collect()
     }
 
     /// Parse advice_tape vector of strings to a vector of u64
-    pub fn advice_tape(&self) -> Vec<u64> {
+    pub fn advice_tape(&self) -> Result<Vec<u64>, String> {
         self.advice_tape
-            .as_ref()
-            .unwrap_or(&vec![])
             .iter()
-            .map(|v| v.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>()
+            .flatten()
+            .map(|v| parse_element(v, "advice tape value"))
+            .collect()
+    }
+
+    /// Parse advice_map entries into a map of keys to lists of field elements.
+    pub fn advice_map(&self) -> Result<BTreeMap<[u8; 32], Vec<Felt>>, String> {
+        let mut result = BTreeMap::new();
+        for (key, values) in self.advice_map.iter().flatten() {
+            let key = parse_word(key, "advice map key")?.into_bytes();
+            let values = values
+                .iter()
+                .map(|v| parse_element(v, "advice map value").map(Felt::new))
+                .collect::<Result<_, _>>()?;
+            result.insert(key, values);
+        }
+        Ok(result)
+    }
+
+    /// Builds advice sets from the Merkle trees, sparse Merkle trees and Merkle path sets
+    /// described in the input file.
+    pub fn advice_sets(&self) -> Result<Vec<AdviceSet>, String> {
+        let mut result = Vec::new();
+
+        for (i, tree) in self.merkle_trees.iter().flatten().enumerate() {
+            let leaves = tree
+                .leaves
+                .iter()
+                .map(|v| parse_word(v, "Merkle tree leaf"))
+                .collect::<Result<_, _>>()?;
+            let tree = AdviceSet::new_merkle_tree(leaves)
+                .map_err(|err| format!("Invalid Merkle tree {} - {:?}", i, err))?;
+            result.push(tree);
+        }
+
+        for (i, tree) in self.sparse_merkle_trees.iter().flatten().enumerate() {
+            let mut keys = Vec::with_capacity(tree.leaves.len());
+            let mut values = Vec::with_capacity(tree.leaves.len());
+            for leaf in tree.leaves.iter() {
+                keys.push(parse_int(&leaf.key, "sparse Merkle tree key")?);
+                values.push(parse_word(&leaf.value, "sparse Merkle tree leaf")?);
+            }
+            let tree = AdviceSet::new_sparse_merkle_tree(keys, values, tree.depth)
+                .map_err(|err| format!("Invalid sparse Merkle tree {} - {:?}", i, err))?;
+            result.push(tree);
+        }
+
+        for (i, set) in self.merkle_path_sets.iter().flatten().enumerate() {
+            let mut paths = Vec::with_capacity(set.paths.len());
+            for path in set.paths.iter() {
+                let index = parse_int(&path.index, "Merkle path index")?;
+                let value = parse_word(&path.value, "Merkle path leaf")?;
+                let nodes = path
+                    .path
+                    .iter()
+                    .map(|v| parse_word(v, "Merkle path node"))
+                    .collect::<Result<_, _>>()?;
+                paths.push((index, value, nodes));
+            }
+            let set = AdviceSet::new_merkle_path_set(set.depth, paths)
+                .map_err(|err| format!("Invalid Merkle path set {} - {:?}", i, err))?;
+            result.push(set);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Parses a decimal string into a u64 value.
+fn parse_int(value: &str, name: &str) -> Result<u64, String> {
+    value
+        .parse::<u64>()
+        .map_err(|err| format!("Invalid {} `{}` - {}", name, value, err))
+}
+
+/// Parses a decimal string into a u64 value which is a valid field element.
+fn parse_element(value: &str, name: &str) -> Result<u64, String> {
+    let int = parse_int(value, name)?;
+    if int >= Felt::MODULUS {
+        return Err(format!(
+            "Invalid {} `{}` - not a field element",
+            name, value
+        ));
     }
+    Ok(int)
+}
+
+/// Parses a hex-encoded 32-byte string into a word. The string may be prefixed with `0x`.
+fn parse_word(value: &str, name: &str) -> Result<Word, String> {
+    let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|err| format!("Invalid {} `{}` - {}", name, value, err))?;
+    if bytes.len() != 32 {
+        return Err(format!(
+            "Invalid {} `{}` - expected 32 bytes, but was {}",
+            name,
+            value,
+            bytes.len()
+        ));
+    }
+
+    let mut word = [Felt::ZERO; 4];
+    for (element, chunk) in word.iter_mut().zip(bytes.chunks(8)) {
+        let int = u64::from_le_bytes(chunk.try_into().expect("invalid chunk length"));
+        if int >= Felt::MODULUS {
+            return Err(format!("Invalid {} `{}` - not a valid word", name, value));
+        }
+        *element = Felt::new(int);
+    }
+    Ok(word)
 }
 
 // OUTPUT FILE
@@ -170,6 +330,16 @@ pub struct ProgramFile;
 /// Helper methods to interact with masm program file
 impl ProgramFile {
     pub fn read(path: &PathBuf) -> Result<Program, String> {
+        Self::read_with_mode(path, false)
+    }
+
+    /// Reads and compiles the program in debug mode, which preserves information about the
+    /// assembly instructions in the compiled program.
+    pub fn read_in_debug_mode(path: &PathBuf) -> Result<Program, String> {
+        Self::read_with_mode(path, true)
+    }
+
+    fn read_with_mode(path: &PathBuf, in_debug_mode: bool) -> Result<Program, String> {
         println!("Reading program file `{}`", path.display());
 
         // read program file to string
@@ -181,6 +351,7 @@ impl ProgramFile {
 
         // compile program
         let program = Assembler::new()
+            .with_debug_mode(in_debug_mode)
             .with_module_provider(StdLibrary::default())
             .compile(&program_file)
             .map_err(|err| format!("Failed to compile program - {}", err))?;
@@ -251,6 +422,53 @@ impl ProofFile {
     }
 }
 
+// PROOF BUNDLE FILE
+// ================================================================================================
+
+pub struct ProofBundleFile;
+
+/// Helper methods to interact with proof bundle file
+impl ProofBundleFile {
+    /// Read proof bundle from file
+    pub fn read(path: &Path) -> Result<ProofBundle, String> {
+        println!("Reading proof bundle file `{}`", path.display());
+
+        // read the file to bytes
+        let file = fs::read(path).map_err(|err| {
+            format!(
+                "Failed to open proof bundle file `{}` - {}",
+                path.display(),
+                err
+            )
+        })?;
+
+        // deserialize bytes into a proof bundle
+        ProofBundle::from_bytes(&file)
+            .map_err(|err| format!("Failed to decode proof bundle data - {}", err))
+    }
+
+    /// Write proof bundle to file
+    pub fn write(bundle: &ProofBundle, path: &Path) -> Result<(), String> {
+        println!("Creating proof bundle file `{}`", path.display());
+
+        let bundle_bytes = bundle.to_bytes();
+
+        println!(
+            "Writing data to proof bundle file - size {} KB",
+            bundle_bytes.len() / 1024
+        );
+
+        // write bundle bytes to file
+        fs::write(path, &bundle_bytes).map_err(|err| {
+            format!(
+                "Failed to write proof bundle file `{}` - {}",
+                path.display(),
+                err
+            )
+        })
+    }
+}
+
 // PROGRAM HASH
 // ================================================================================================
 
@@ -273,3 +491,123 @@ impl ProgramHash {
         Ok(program_hash)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::InputFile;
+    use vm_core::{utils::IntoBytes, AdviceSet, Felt, FieldElement, Word};
+
+    fn int_to_word(value: u64) -> Word {
+        [Felt::new(value), Felt::ZERO, Felt::ZERO, Felt::ZERO]
+    }
+
+    fn word_to_hex(word: Word) -> String {
+        format!("0x{}", hex::encode(word.into_bytes()))
+    }
+
+    fn parse(json: &str) -> InputFile {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn advice_inputs() {
+        let leaves = (1..5).map(int_to_word).collect::<Vec<_>>();
+        let tree = AdviceSet::new_merkle_tree(leaves.clone()).unwrap();
+        let path = tree.get_path(2, 1).unwrap();
+        let smt = AdviceSet::new_sparse_merkle_tree(vec![7], vec![int_to_word(5)], 10).unwrap();
+
+        let leaves_hex = leaves
+            .iter()
+            .map(|&leaf| format!("\"{}\"", word_to_hex(leaf)));
+        let path_hex = path
+            .iter()
+            .map(|&node| format!("\"{}\"", word_to_hex(node)));
+        let json = format!(
+            r#"{{
+                "stack_init": ["1", "2"],
+                "advice_tape": ["3"],
+                "advice_map": {{ "{}": ["5", "6"] }},
+                "merkle_trees": [{{ "leaves": [{}] }}],
+                "sparse_merkle_trees": [{{ "depth": 10, "leaves": [{{ "key": "7", "value": "{}" }}] }}],
+                "merkle_path_sets": [{{
+                    "depth": 3,
+                    "paths": [{{ "index": "1", "value": "{}", "path": [{}] }}]
+                }}]
+            }}"#,
+            word_to_hex(int_to_word(9)),
+            leaves_hex.collect::<Vec<_>>().join(","),
+            word_to_hex(int_to_word(5)),
+            word_to_hex(leaves[1]),
+            path_hex.collect::<Vec<_>>().join(",")
+        );
+        let input_file = parse(&json);
+
+        assert_eq!(vec![1, 2], input_file.stack_init().unwrap());
+        assert_eq!(vec![3], input_file.advice_tape().unwrap());
+
+        let advice_map = input_file.advice_map().unwrap();
+        assert_eq!(
+            Some(&vec![Felt::new(5), Felt::new(6)]),
+            advice_map.get(&int_to_word(9).into_bytes())
+        );
+
+        let advice_sets = input_file.advice_sets().unwrap();
+        assert_eq!(3, advice_sets.len());
+        assert_eq!(tree.root(), advice_sets[0].root());
+        assert_eq!(smt.root(), advice_sets[1].root());
+        assert_eq!(tree.root(), advice_sets[2].root());
+
+        // the Merkle tree and the path set have the same root, and advice sets with duplicate
+        // roots cannot be provided to the same program
+        assert!(input_file.get_program_inputs().is_err());
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        // stack inputs must be integers
+        let input_file = parse(r#"{ "stack_init": ["1", "abc"] }"#);
+        assert!(input_file.get_program_inputs().is_err());
+
+        // advice tape values must be valid field elements
+        let input_file = parse(r#"{ "stack_init": [], "advice_tape": ["18446744073709551615"] }"#);
+        assert!(input_file.get_program_inputs().is_err());
+
+        // advice map keys must be 32 bytes long
+        let input_file = parse(r#"{ "stack_init": [], "advice_map": { "0x0102": ["1"] } }"#);
+        assert!(input_file.get_program_inputs().is_err());
+
+        // number of leaves in a Merkle tree must be a power of two
+        let leaf = format!("\"{}\"", word_to_hex(int_to_word(1)));
+        let json = format!(
+            r#"{{ "stack_init": [], "merkle_trees": [{{ "leaves": [{0}, {0}, {0}] }}] }}"#,
+            leaf
+        );
+        let err = parse(&json).get_program_inputs().unwrap_err();
+        assert!(err.starts_with("Invalid Merkle tree 0"));
+
+        // keys of a sparse Merkle tree must be valid leaf indexes for its depth
+        let json = format!(
+            r#"{{ "stack_init": [], "sparse_merkle_trees": [{{ "depth": 3, "leaves": [
+                {{ "key": "8", "value": {} }}
+            ] }}] }}"#,
+            leaf
+        );
+        let err = parse(&json).get_program_inputs().unwrap_err();
+        assert!(err.starts_with("Invalid sparse Merkle tree 0"));
+
+        // paths in a path set must resolve to the same root
+        let json = format!(
+            r#"{{ "stack_init": [], "merkle_path_sets": [{{ "depth": 2, "paths": [
+                {{ "index": "0", "value": {0}, "path": [{0}] }},
+                {{ "index": "1", "value": "{1}", "path": [{0}] }}
+            ] }}] }}"#,
+            leaf,
+            word_to_hex(int_to_word(2))
+        );
+        let err = parse(&json).get_program_inputs().unwrap_err();
+        assert!(err.starts_with("Invalid Merkle path set 0"));
+    }
+}
@@ -0,0 +1,92 @@
+use super::data::{InputFile, ProgramFile};
+use super::proof_options::{describe_proof_options, ProofOptionsArgs};
+use crypto::Digest;
+use miden::ProofOptions;
+use std::path::PathBuf;
+use std::time::Instant;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "Estimate",
+    about = "Estimate proof size and proving cost of a miden program"
+)]
+pub struct EstimateCmd {
+    /// Path to .masm assembly file
+    #[structopt(short = "a", long = "assembly", parse(from_os_str))]
+    assembly_file: PathBuf,
+    /// Path to input file
+    #[structopt(short = "i", long = "input", parse(from_os_str))]
+    input_file: Option<PathBuf>,
+    #[structopt(flatten)]
+    proof_options: ProofOptionsArgs,
+}
+
+impl EstimateCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Estimate proving cost");
+        println!("============================================================");
+
+        // resolve and validate proof options before doing any work; when no options are
+        // specified, estimates for both security presets are printed side by side
+        let proof_options = if self.proof_options.is_specified() {
+            vec![(None, self.proof_options.get_proof_options()?)]
+        } else {
+            vec![
+                (Some("96bits"), ProofOptions::with_96_bit_security()),
+                (Some("128bits"), ProofOptions::with_128_bit_security()),
+            ]
+        };
+
+        // load program from file and compile
+        let program = ProgramFile::read(&self.assembly_file)?;
+
+        // load input data from file
+        let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let program_inputs = input_data.get_program_inputs()?;
+
+        print!(
+            "Executing program with hash {}... ",
+            hex::encode(program.hash().as_bytes())
+        );
+        let now = Instant::now();
+
+        // execute program to get the execution trace
+        let trace = processor::execute(&program, &program_inputs)
+            .map_err(|err| format!("Failed to generate exection trace = {:?}", err))?;
+
+        println!("done ({} ms)", now.elapsed().as_millis());
+
+        for (i, (preset, options)) in proof_options.iter().enumerate() {
+            let estimate = miden::estimate_proof(&trace, options);
+
+            if i == 0 {
+                println!(
+                    "Trace length: {}, main trace width: {}, auxiliary trace width: {}",
+                    estimate.trace_length(),
+                    estimate.main_trace_width(),
+                    estimate.aux_trace_width()
+                );
+            }
+            println!("------------------------------------------------------------");
+            if let Some(preset) = preset {
+                println!("Preset: {}", preset);
+            }
+            println!("Proof options: {}", describe_proof_options(options));
+            println!("Security level: {} bits", estimate.security_level());
+            println!("LDE domain size: {}", estimate.lde_domain_size());
+            println!("Proof size: ~{} KB", estimate.proof_size() / 1024);
+            println!(
+                "Prover memory: ~{} MB",
+                estimate.prover_memory() / (1024 * 1024)
+            );
+            println!(
+                "Proving time (single thread): ~{} ms",
+                estimate.proving_time().as_millis()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,19 @@
 mod compile;
 mod data;
+mod estimate;
+mod proof_options;
 mod prove;
 mod repl;
 mod run;
+mod trace;
 mod verify;
 
 pub use compile::CompileCmd;
 pub use data::InputFile;
+pub use estimate::EstimateCmd;
+pub use proof_options::ProofOptionsArgs;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
+pub use trace::TraceCmd;
 pub use verify::VerifyCmd;
@@ -0,0 +1,348 @@
+use air::ProcessorAir;
+use miden::{FieldExtension, HashFunction, ProofOptions};
+use serde_derive::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+// PROOF OPTIONS ARGUMENTS
+// ================================================================================================
+
+/// Command line arguments which define options for execution proofs generated by the VM.
+///
+/// Options are resolved as follows: a security preset provides defaults for all options, values
+/// specified in the profile file (if any) override the preset, and values specified via explicit
+/// flags override both. When no preset is specified, the 96-bit preset is used.
+#[derive(StructOpt, Debug)]
+pub struct ProofOptionsArgs {
+    /// Security level preset for execution proofs generated by the VM (96bits or 128bits)
+    #[structopt(short = "s", long = "security")]
+    security: Option<String>,
+    /// Path to a JSON file with proof options which override the security preset
+    #[structopt(long = "proof-options", parse(from_os_str))]
+    profile_file: Option<PathBuf>,
+    /// Number of queries
+    #[structopt(long = "num-queries")]
+    num_queries: Option<usize>,
+    /// Blowup factor; must be a power of two
+    #[structopt(long = "blowup")]
+    blowup_factor: Option<usize>,
+    /// Grinding factor (number of proof-of-work bits)
+    #[structopt(long = "grinding")]
+    grinding_factor: Option<u32>,
+    /// Hash function (blake3_192, blake3_256, or sha3_256)
+    #[structopt(long = "hash-fn")]
+    hash_fn: Option<String>,
+    /// Field extension (none, quadratic, or cubic)
+    #[structopt(long = "field-extension")]
+    field_extension: Option<String>,
+    /// FRI folding factor (4, 8, or 16)
+    #[structopt(long = "fri-folding-factor")]
+    fri_folding_factor: Option<usize>,
+    /// FRI max remainder size; must be a power of two
+    #[structopt(long = "fri-remainder-size")]
+    fri_max_remainder_size: Option<usize>,
+}
+
+impl ProofOptionsArgs {
+    /// Returns proof options resolved from the preset, profile file, and explicit flags.
+    ///
+    /// # Errors
+    /// Returns an error if the preset is unknown, the profile file cannot be read, or the
+    /// resulting options are not valid for Miden VM proofs.
+    pub fn get_proof_options(&self) -> Result<ProofOptions, String> {
+        let security = self.security.as_deref().unwrap_or("96bits");
+        let mut profile = ProofOptionsProfile::from_preset(security)?;
+
+        if let Some(path) = &self.profile_file {
+            profile.merge(ProofOptionsProfile::read(path)?);
+        }
+
+        profile.merge(ProofOptionsProfile {
+            num_queries: self.num_queries,
+            blowup_factor: self.blowup_factor,
+            grinding_factor: self.grinding_factor,
+            hash_fn: self.hash_fn.clone(),
+            field_extension: self.field_extension.clone(),
+            fri_folding_factor: self.fri_folding_factor,
+            fri_max_remainder_size: self.fri_max_remainder_size,
+        });
+
+        profile.build()
+    }
+
+    /// Returns true if a security preset, a profile file, or any explicit option flag was
+    /// specified.
+    pub fn is_specified(&self) -> bool {
+        self.security.is_some()
+            || self.profile_file.is_some()
+            || self.num_queries.is_some()
+            || self.blowup_factor.is_some()
+            || self.grinding_factor.is_some()
+            || self.hash_fn.is_some()
+            || self.field_extension.is_some()
+            || self.fri_folding_factor.is_some()
+            || self.fri_max_remainder_size.is_some()
+    }
+}
+
+// PROOF OPTIONS PROFILE
+// ================================================================================================
+
+/// Proof options as specified in a JSON profile file. All fields are optional; missing fields are
+/// taken from the security preset.
+///
+/// For example:
+/// ```json
+/// { "num_queries": 40, "blowup_factor": 16, "hash_fn": "blake3_256" }
+/// ```
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct ProofOptionsProfile {
+    num_queries: Option<usize>,
+    blowup_factor: Option<usize>,
+    grinding_factor: Option<u32>,
+    hash_fn: Option<String>,
+    field_extension: Option<String>,
+    fri_folding_factor: Option<usize>,
+    fri_max_remainder_size: Option<usize>,
+}
+
+impl ProofOptionsProfile {
+    /// Returns a profile with all fields set to the values of the specified security preset.
+    fn from_preset(security: &str) -> Result<Self, String> {
+        let options = match security {
+            "96bits" => ProofOptions::with_96_bit_security(),
+            "128bits" => ProofOptions::with_128_bit_security(),
+            other => return Err(format!("{} is not a valid security setting", other)),
+        };
+
+        Ok(Self {
+            num_queries: Some(options.num_queries()),
+            blowup_factor: Some(options.blowup_factor()),
+            grinding_factor: Some(options.grinding_factor()),
+            hash_fn: Some(hash_fn_name(options.hash_fn()).to_string()),
+            field_extension: Some(field_extension_name(options.field_extension()).to_string()),
+            fri_folding_factor: Some(options.to_fri_options().folding_factor()),
+            fri_max_remainder_size: Some(options.to_fri_options().max_remainder_size()),
+        })
+    }
+
+    /// Reads a profile from the specified JSON file.
+    fn read(path: &Path) -> Result<Self, String> {
+        println!("Reading proof options file `{}`", path.display());
+
+        let profile_file = fs::read_to_string(path).map_err(|err| {
+            format!(
+                "Failed to open proof options file `{}` - {}",
+                path.display(),
+                err
+            )
+        })?;
+
+        serde_json::from_str(&profile_file)
+            .map_err(|err| format!("Failed to deserialize proof options - {}", err))
+    }
+
+    /// Overrides fields of this profile with the fields which are set in the `other` profile.
+    fn merge(&mut self, other: Self) {
+        self.num_queries = other.num_queries.or(self.num_queries);
+        self.blowup_factor = other.blowup_factor.or(self.blowup_factor);
+        self.grinding_factor = other.grinding_factor.or(self.grinding_factor);
+        self.hash_fn = other.hash_fn.or_else(|| self.hash_fn.take());
+        self.field_extension = other
+            .field_extension
+            .or_else(|| self.field_extension.take());
+        self.fri_folding_factor = other.fri_folding_factor.or(self.fri_folding_factor);
+        self.fri_max_remainder_size = other.fri_max_remainder_size.or(self.fri_max_remainder_size);
+    }
+
+    /// Validates the fields of this profile and builds proof options from them.
+    fn build(self) -> Result<ProofOptions, String> {
+        let num_queries = self.num_queries.ok_or("number of queries not specified")?;
+        if !(1..=128).contains(&num_queries) {
+            return Err(format!(
+                "number of queries must be between 1 and 128, but was {}",
+                num_queries
+            ));
+        }
+
+        let blowup_factor = self.blowup_factor.ok_or("blowup factor not specified")?;
+        let min_blowup_factor = ProcessorAir::min_blowup_factor();
+        if !blowup_factor.is_power_of_two()
+            || blowup_factor < min_blowup_factor
+            || blowup_factor > 128
+        {
+            return Err(format!(
+                "blowup factor must be a power of two between {} and 128, but was {}",
+                min_blowup_factor, blowup_factor
+            ));
+        }
+
+        let grinding_factor = self
+            .grinding_factor
+            .ok_or("grinding factor not specified")?;
+        if grinding_factor > 32 {
+            return Err(format!(
+                "grinding factor cannot be greater than 32, but was {}",
+                grinding_factor
+            ));
+        }
+
+        let hash_fn = match self.hash_fn.as_deref() {
+            Some("blake3_192") => HashFunction::Blake3_192,
+            Some("blake3_256") => HashFunction::Blake3_256,
+            Some("sha3_256") => HashFunction::Sha3_256,
+            Some(other) => {
+                return Err(format!(
+                    "{} is not a valid hash function; expected blake3_192, blake3_256, or sha3_256",
+                    other
+                ))
+            }
+            None => return Err("hash function not specified".to_string()),
+        };
+
+        let field_extension = match self.field_extension.as_deref() {
+            Some("none") => FieldExtension::None,
+            Some("quadratic") => FieldExtension::Quadratic,
+            Some("cubic") => FieldExtension::Cubic,
+            Some(other) => {
+                return Err(format!(
+                    "{} is not a valid field extension; expected none, quadratic, or cubic",
+                    other
+                ))
+            }
+            None => return Err("field extension not specified".to_string()),
+        };
+
+        let fri_folding_factor = self
+            .fri_folding_factor
+            .ok_or("FRI folding factor not specified")?;
+        if ![4, 8, 16].contains(&fri_folding_factor) {
+            return Err(format!(
+                "FRI folding factor must be 4, 8, or 16, but was {}",
+                fri_folding_factor
+            ));
+        }
+
+        let fri_max_remainder_size = self
+            .fri_max_remainder_size
+            .ok_or("FRI max remainder size not specified")?;
+        if !fri_max_remainder_size.is_power_of_two()
+            || !(32..=1024).contains(&fri_max_remainder_size)
+        {
+            return Err(format!(
+                "FRI max remainder size must be a power of two between 32 and 1024, but was {}",
+                fri_max_remainder_size
+            ));
+        }
+
+        Ok(ProofOptions::new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            hash_fn,
+            field_extension,
+            fri_folding_factor,
+            fri_max_remainder_size,
+        ))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a human-readable description of the specified proof options.
+pub fn describe_proof_options(options: &ProofOptions) -> String {
+    let fri_options = options.to_fri_options();
+    format!(
+        "queries: {}, blowup: {}, grinding: {}, hash: {}, field extension: {}, \
+        FRI folding factor: {}, FRI max remainder size: {}",
+        options.num_queries(),
+        options.blowup_factor(),
+        options.grinding_factor(),
+        hash_fn_name(options.hash_fn()),
+        field_extension_name(options.field_extension()),
+        fri_options.folding_factor(),
+        fri_options.max_remainder_size()
+    )
+}
+
+fn hash_fn_name(hash_fn: HashFunction) -> &'static str {
+    match hash_fn {
+        HashFunction::Blake3_192 => "blake3_192",
+        HashFunction::Blake3_256 => "blake3_256",
+        HashFunction::Sha3_256 => "sha3_256",
+    }
+}
+
+fn field_extension_name(field_extension: FieldExtension) -> &'static str {
+    match field_extension {
+        FieldExtension::None => "none",
+        FieldExtension::Quadratic => "quadratic",
+        FieldExtension::Cubic => "cubic",
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ProofOptionsArgs, ProofOptionsProfile};
+    use miden::{FieldExtension, HashFunction, ProofOptions};
+    use structopt::StructOpt;
+
+    #[test]
+    fn flags_override_preset() {
+        let args = ProofOptionsArgs::from_iter(&[
+            "prove",
+            "--security",
+            "128bits",
+            "--num-queries",
+            "40",
+            "--hash-fn",
+            "sha3_256",
+        ]);
+        let options = args.get_proof_options().unwrap();
+        let expected = ProofOptions::with_128_bit_security();
+
+        assert_eq!(40, options.num_queries());
+        assert_eq!(HashFunction::Sha3_256, options.hash_fn());
+        assert_eq!(expected.blowup_factor(), options.blowup_factor());
+        assert_eq!(expected.grinding_factor(), options.grinding_factor());
+        assert_eq!(FieldExtension::Cubic, options.field_extension());
+    }
+
+    #[test]
+    fn default_options_use_96_bit_preset() {
+        let args = ProofOptionsArgs::from_iter(&["estimate"]);
+        assert!(!args.is_specified());
+        assert_eq!(
+            ProofOptions::with_96_bit_security(),
+            args.get_proof_options().unwrap()
+        );
+
+        let args = ProofOptionsArgs::from_iter(&["estimate", "--blowup", "16"]);
+        assert!(args.is_specified());
+    }
+
+    #[test]
+    fn invalid_options_are_rejected() {
+        let mut profile = ProofOptionsProfile::from_preset("96bits").unwrap();
+        profile.merge(ProofOptionsProfile {
+            blowup_factor: Some(12),
+            ..Default::default()
+        });
+        assert!(profile.build().is_err());
+
+        let mut profile = ProofOptionsProfile::from_preset("96bits").unwrap();
+        profile.merge(ProofOptionsProfile {
+            field_extension: Some("quartic".to_string()),
+            ..Default::default()
+        });
+        assert!(profile.build().is_err());
+
+        assert!(ProofOptionsProfile::from_preset("64bits").is_err());
+    }
+}
@@ -1,6 +1,7 @@
-use super::data::{InputFile, OutputFile, ProgramFile, ProofFile};
-use air::ProofOptions;
+use super::data::{InputFile, OutputFile, ProgramFile, ProofBundleFile, ProofFile};
+use super::proof_options::{describe_proof_options, ProofOptionsArgs};
 use crypto::Digest;
+use miden::ProofBundle;
 use std::io::Write;
 use std::path::PathBuf;
 use std::time::Instant;
@@ -24,20 +25,14 @@ pub struct ProveCmd {
     /// Path to proof file
     #[structopt(short = "p", long = "proof", parse(from_os_str))]
     proof_file: Option<PathBuf>,
-    /// Security level for execution proofs generated by the VM
-    #[structopt(short = "s", long = "security", default_value = "96bits")]
-    security: String,
+    /// Path to proof bundle file; when provided, a self-contained proof bundle is written as well
+    #[structopt(short = "b", long = "bundle", parse(from_os_str))]
+    bundle_file: Option<PathBuf>,
+    #[structopt(flatten)]
+    proof_options: ProofOptionsArgs,
 }
 
 impl ProveCmd {
-    pub fn get_proof_security(&self) -> ProofOptions {
-        match self.security.as_str() {
-            "96bits" => ProofOptions::with_96_bit_security(),
-            "128bits" => ProofOptions::with_128_bit_security(),
-            other => panic!("{} is not a valid security setting", other),
-        }
-    }
-
     pub fn execute(&self) -> Result<(), String> {
         println!("============================================================");
         println!("Prove program");
@@ -49,6 +44,9 @@ impl ProveCmd {
             .filter_level(log::LevelFilter::Debug)
             .init();
 
+        // resolve and validate proof options before doing any work
+        let proof_options = self.proof_options.get_proof_options()?;
+
         // load program from file and compile
         let program = ProgramFile::read(&self.assembly_file)?;
 
@@ -59,15 +57,13 @@ impl ProveCmd {
             "Proving program with hash {}...",
             hex::encode(program.hash().as_bytes())
         );
+        println!("Proof options: {}", describe_proof_options(&proof_options));
         let now = Instant::now();
 
         // execute program and generate proof
-        let (outputs, proof) = prover::prove(
-            &program,
-            &input_data.get_program_inputs(),
-            &self.get_proof_security(),
-        )
-        .map_err(|err| format!("Failed to prove program - {:?}", err))?;
+        let program_inputs = input_data.get_program_inputs()?;
+        let (outputs, proof) = prover::prove(&program, &program_inputs, &proof_options)
+            .map_err(|err| format!("Failed to prove program - {:?}", err))?;
 
         println!(
             "Program with hash {} proved in {} ms",
@@ -75,6 +71,17 @@ impl ProveCmd {
             now.elapsed().as_millis()
         );
 
+        // write proof bundle to file
+        if let Some(bundle_path) = &self.bundle_file {
+            let bundle = ProofBundle::new(
+                program.hash(),
+                input_data.stack_init()?,
+                outputs.clone(),
+                proof.clone(),
+            );
+            ProofBundleFile::write(&bundle, bundle_path)?;
+        }
+
         // write proof to file
         ProofFile::write(proof, &self.proof_file, &self.assembly_file)?;
 
@@ -1,5 +1,6 @@
 use super::data::{InputFile, OutputFile, ProgramFile};
 use crypto::Digest;
+use miden::ConstraintCheckError;
 use std::path::PathBuf;
 use std::time::Instant;
 use structopt::StructOpt;
@@ -19,6 +20,9 @@ pub struct RunCmd {
     /// Path to output file
     #[structopt(short = "o", long = "output", parse(from_os_str))]
     output_file: Option<PathBuf>,
+    /// Check that the execution trace satisfies all AIR constraints
+    #[structopt(long = "check-constraints")]
+    check_constraints: bool,
 }
 
 impl RunCmd {
@@ -27,11 +31,17 @@ impl RunCmd {
         println!("Run program");
         println!("============================================================");
 
-        // load program from file and compile
-        let program = ProgramFile::read(&self.assembly_file)?;
+        // load program from file and compile; when checking constraints, the program is compiled
+        // in debug mode so that failures can be attributed to assembly instructions
+        let program = if self.check_constraints {
+            ProgramFile::read_in_debug_mode(&self.assembly_file)?
+        } else {
+            ProgramFile::read(&self.assembly_file)?
+        };
 
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let program_inputs = input_data.get_program_inputs()?;
 
         print!(
             "Executing program with hash {}... ",
@@ -39,21 +49,34 @@ impl RunCmd {
         );
         let now = Instant::now();
 
-        // execute program and generate outputs
-        let trace = processor::execute(&program, &input_data.get_program_inputs())
-            .map_err(|err| format!("Failed to generate exection trace = {:?}", err))?;
+        // execute program and generate outputs; an execution trace is generated only when its
+        // constraints need to be checked
+        let outputs = if self.check_constraints {
+            miden::check_constraints(&program, &program_inputs).map_err(|err| match err {
+                ConstraintCheckError::ExecutionError(err) => {
+                    format!("Failed to execute program = {:?}", err)
+                }
+                ConstraintCheckError::ConstraintFailure(failure) => {
+                    format!("Constraint check failed - {}", failure)
+                }
+            })?
+        } else {
+            processor::execute_fast(&program, &program_inputs)
+                .map_err(|err| format!("Failed to execute program = {:?}", err))?
+        };
 
         println!("done ({} ms)", now.elapsed().as_millis());
 
+        if self.check_constraints {
+            println!("All AIR constraints are satisfied");
+        }
+
         if let Some(output_path) = &self.output_file {
             // write outputs to file if one was specified
-            OutputFile::write(trace.program_outputs(), output_path)?;
+            OutputFile::write(outputs, output_path)?;
         } else {
             // write the stack outputs to the screen.
-            println!(
-                "Output: {:?}",
-                trace.program_outputs().stack_outputs(self.num_outputs)
-            );
+            println!("Output: {:?}", outputs.stack_outputs(self.num_outputs));
         }
 
         Ok(())
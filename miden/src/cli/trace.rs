@@ -0,0 +1,90 @@
+use super::data::{InputFile, ProgramFile};
+use crypto::Digest;
+use miden::Trace;
+use processor::TraceExportFormat;
+use std::{fs, io::BufWriter, path::PathBuf, time::Instant};
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "Trace",
+    about = "Execute a miden program and export its execution trace"
+)]
+pub struct TraceCmd {
+    /// Path to .masm assembly file
+    #[structopt(short = "a", long = "assembly", parse(from_os_str))]
+    assembly_file: PathBuf,
+    /// Path to input file
+    #[structopt(short = "i", long = "input", parse(from_os_str))]
+    input_file: Option<PathBuf>,
+    /// Path to trace file
+    #[structopt(short = "t", long = "trace", parse(from_os_str))]
+    trace_file: Option<PathBuf>,
+    /// Format of the trace file (csv or binary)
+    #[structopt(short = "f", long = "format", default_value = "csv")]
+    format: String,
+    /// Include auxiliary trace columns
+    #[structopt(long = "aux")]
+    include_aux: bool,
+}
+
+impl TraceCmd {
+    pub fn get_export_format(&self) -> Result<TraceExportFormat, String> {
+        match self.format.as_str() {
+            "csv" => Ok(TraceExportFormat::Csv),
+            "binary" => Ok(TraceExportFormat::Binary),
+            other => Err(format!("{} is not a valid trace format", other)),
+        }
+    }
+
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Export execution trace");
+        println!("============================================================");
+
+        let format = self.get_export_format()?;
+
+        // load program from file and compile
+        let program = ProgramFile::read(&self.assembly_file)?;
+
+        // load input data from file
+        let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let program_inputs = input_data.get_program_inputs()?;
+
+        print!(
+            "Executing program with hash {}... ",
+            hex::encode(program.hash().as_bytes())
+        );
+        let now = Instant::now();
+
+        // execute program and generate the execution trace
+        let mut trace = processor::execute(&program, &program_inputs)
+            .map_err(|err| format!("Failed to generate exection trace = {:?}", err))?;
+
+        println!("done ({} ms)", now.elapsed().as_millis());
+
+        // If trace_file has been provided then use this as path. Alternatively we will replace
+        // the assembly_file extension with `.csv` or `.trace` and use this as a default.
+        let path = match &self.trace_file {
+            Some(path) => path.clone(),
+            None => match format {
+                TraceExportFormat::Csv => self.assembly_file.with_extension("csv"),
+                TraceExportFormat::Binary => self.assembly_file.with_extension("trace"),
+            },
+        };
+
+        println!("Creating trace file `{}`", path.display());
+
+        let file = fs::File::create(&path)
+            .map_err(|err| format!("Failed to create trace file `{}` - {}", path.display(), err))?;
+
+        // write the trace to the file
+        trace
+            .export(&mut BufWriter::new(file), format, self.include_aux)
+            .map_err(|err| format!("Failed to write trace data - {}", err))?;
+
+        println!("Trace of {} rows written", trace.length());
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
-use super::data::{InputFile, OutputFile, ProgramHash, ProofFile};
-use std::path::PathBuf;
+use super::data::{InputFile, OutputFile, ProgramHash, ProofBundleFile, ProofFile};
+use super::proof_options::describe_proof_options;
+use crypto::Digest as _;
+use miden::Digest;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 use structopt::StructOpt;
 
@@ -13,11 +16,23 @@ pub struct VerifyCmd {
     #[structopt(short = "o", long = "output", parse(from_os_str))]
     output_file: Option<PathBuf>,
     /// Path to proof file
-    #[structopt(short = "p", long = "proof", parse(from_os_str))]
-    proof_file: PathBuf,
+    #[structopt(
+        short = "p",
+        long = "proof",
+        parse(from_os_str),
+        required_unless = "bundle-file"
+    )]
+    proof_file: Option<PathBuf>,
     /// Program hash (hex)
     #[structopt(short = "h", long = "program-hash")]
     program_hash: String,
+    /// Path to proof bundle file; when provided, inputs, outputs, and proof are read from the
+    /// bundle, and the bundle must be for the program with the specified hash
+    #[structopt(short = "b", long = "bundle", parse(from_os_str))]
+    bundle_file: Option<PathBuf>,
+    /// Minimum conjectured security level (in bits) which the proof must provide
+    #[structopt(short = "s", long = "min-security")]
+    min_security: Option<u32>,
 }
 
 impl VerifyCmd {
@@ -29,29 +44,68 @@ impl VerifyCmd {
         // read program hash from input
         let program_hash = ProgramHash::read(&self.program_hash)?;
 
+        if let Some(bundle_path) = &self.bundle_file {
+            return self.verify_bundle(bundle_path, program_hash);
+        }
+
+        // proof file is required by the argument parser when no bundle is provided
+        let proof_file = self.proof_file.clone().expect("proof file not specified");
+
         // load input data from file
-        let input_data = InputFile::read(&self.input_file, &self.proof_file)?;
+        let input_data = InputFile::read(&self.input_file, &proof_file)?;
 
         // load outputs data from file
-        let outputs_data = OutputFile::read(&self.output_file, &self.proof_file)?;
+        let outputs_data = OutputFile::read(&self.output_file, &proof_file)?;
 
         // load proof from file
-        let proof = ProofFile::read(&Some(self.proof_file.clone()), &self.proof_file)?;
+        let proof = ProofFile::read(&Some(proof_file.clone()), &proof_file)?;
 
         println!("verifying program...");
         let now = Instant::now();
 
         // verify proof
-        verifier::verify(
-            program_hash,
-            &input_data.stack_init(),
-            &outputs_data.outputs(),
-            proof,
-        )
+        let stack_inputs = input_data.stack_init()?;
+        let outputs = outputs_data.outputs();
+        match self.min_security {
+            Some(min_security) => verifier::verify_with_min_security(
+                program_hash,
+                &stack_inputs,
+                &outputs,
+                proof,
+                min_security,
+            ),
+            None => verifier::verify(program_hash, &stack_inputs, &outputs, proof),
+        }
         .map_err(|err| format!("Program failed verification! - {}", err))?;
 
         println!("Verification complete in {} ms", now.elapsed().as_millis());
 
         Ok(())
     }
+
+    /// Verifies the proof stored in the specified proof bundle against the inputs and outputs
+    /// stored in the same bundle. The bundle must be for the program with the specified hash.
+    fn verify_bundle(&self, bundle_path: &Path, program_hash: Digest) -> Result<(), String> {
+        // load proof bundle from file
+        let bundle = ProofBundleFile::read(bundle_path)?;
+
+        println!(
+            "Proof options: {}",
+            describe_proof_options(&bundle.options())
+        );
+        println!(
+            "verifying program with hash {}...",
+            hex::encode(program_hash.as_bytes())
+        );
+        let now = Instant::now();
+
+        // verify proof; when no minimum security level is specified, any level is accepted
+        bundle
+            .verify(program_hash, self.min_security)
+            .map_err(|err| format!("Program failed verification! - {}", err))?;
+
+        println!("Verification complete in {} ms", now.elapsed().as_millis());
+
+        Ok(())
+    }
 }
@@ -1,3 +1,4 @@
+use crate::cli::ProofOptionsArgs;
 use miden::{Program, ProgramInputs, ProofOptions, StarkProof};
 use std::io::Write;
 use std::time::Instant;
@@ -25,9 +26,8 @@ pub struct ExampleOptions {
     #[structopt(subcommand)]
     pub example: ExampleType,
 
-    /// Security level for execution proofs generated by the VM
-    #[structopt(short = "s", long = "security", default_value = "96bits")]
-    security: String,
+    #[structopt(flatten)]
+    proof_options: ProofOptionsArgs,
 }
 
 #[derive(StructOpt, Debug)]
@@ -42,12 +42,8 @@ pub enum ExampleType {
 }
 
 impl ExampleOptions {
-    pub fn get_proof_options(&self) -> ProofOptions {
-        match self.security.as_str() {
-            "96bits" => ProofOptions::with_96_bit_security(),
-            "128bits" => ProofOptions::with_128_bit_security(),
-            other => panic!("{} is not a valid security level", other),
-        }
+    pub fn get_proof_options(&self) -> Result<ProofOptions, String> {
+        self.proof_options.get_proof_options()
     }
 
     pub fn execute(&self) -> Result<(), String> {
@@ -59,7 +55,7 @@ impl ExampleOptions {
             .filter_level(log::LevelFilter::Debug)
             .init();
 
-        let proof_options = self.get_proof_options();
+        let proof_options = self.get_proof_options()?;
 
         // instantiate and prepare the example
         let example = match self.example {
@@ -6,10 +6,18 @@
 pub use air::{FieldExtension, HashFunction, ProofOptions};
 pub use assembly::{Assembler, AssemblyError, ParsingError};
 pub use processor::{
-    execute, execute_iter, AsmOpInfo, ExecutionError, ExecutionTrace, VmState, VmStateIterator,
+    execute, execute_debug, execute_fast, execute_iter, AsmOpInfo, ExecutionError, ExecutionTrace,
+    FastProcess, VmState, VmStateIterator,
+};
+pub use prover::{
+    check_constraints, check_trace_constraints, prove, ConstraintCheckError, ConstraintFailure,
+    ConstraintKind, StarkProof, Trace,
+};
+#[cfg(feature = "std")]
+pub use prover::{estimate_proof, ProofEstimate};
+pub use verifier::{
+    verify, verify_with_min_security, BatchItem, BatchVerifier, ProofBundle, VerificationError,
 };
-pub use prover::{prove, StarkProof};
-pub use verifier::{verify, VerificationError};
 pub use vm_core::{
     chiplets::hasher::Digest,
     errors::{AdviceSetError, InputError},
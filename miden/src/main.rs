@@ -20,9 +20,11 @@ pub struct Cli {
 pub enum Actions {
     Analyze(tools::Analyze),
     Compile(cli::CompileCmd),
+    Estimate(cli::EstimateCmd),
     Example(examples::ExampleOptions),
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
+    Trace(cli::TraceCmd),
     Verify(cli::VerifyCmd),
     #[cfg(feature = "std")]
     Repl(cli::ReplCmd),
@@ -34,9 +36,11 @@ impl Cli {
         match &self.action {
             Actions::Analyze(analyze) => analyze.execute(),
             Actions::Compile(compile) => compile.execute(),
+            Actions::Estimate(estimate) => estimate.execute(),
             Actions::Example(example) => example.execute(),
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
+            Actions::Trace(trace) => trace.execute(),
             Actions::Verify(verify) => verify.execute(),
             #[cfg(feature = "std")]
             Actions::Repl(repl) => repl.execute(),
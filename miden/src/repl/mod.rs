@@ -1,7 +1,10 @@
-use super::ProgramError;
-use processor::Process;
+use super::{cli::InputFile, ProgramError};
 use rustyline::{error::ReadlineError, Editor};
-use vm_core::{Felt, ProgramInputs, StarkField, Word};
+use std::path::{Path, PathBuf};
+use vm_core::{Felt, StarkField, Word};
+
+mod session;
+use session::{ReplInput, ReplSession};
 
 /// This work is in continuation to the amazing work done by team `Scribe`
 /// [here](https://github.com/ControlCplusControlV/Scribe/blob/main/transpiler/src/repl.rs#L8)
@@ -44,6 +47,23 @@ use vm_core::{Felt, ProgramInputs, StarkField, Word};
 /// The above example should be written as follows in the REPL tool:
 /// >> repeat.20 pow2 end
 ///
+/// Each input is executed against the state of the VM left by the previous inputs, i.e., only the
+/// newly entered instructions are executed. Thus, the number of cycles needed to process an input
+/// does not depend on the number of previously entered instructions.
+///
+/// Imports and procedures
+/// Modules of the standard library can be imported using `use` statements, and local procedures
+/// can be defined using `proc` statements. As with control flow operations, a procedure must be
+/// defined in a single line. Imports and procedures are kept separately from the instructions
+/// executed so far, and are placed before the `begin` block of the program. Defining a procedure
+/// with the name of an existing procedure replaces the existing definition. For example, the
+/// following inputs leave values 3 and 3 at the top of the stack:
+/// >> use.std::math::u64
+/// >> proc.add3 push.3 add end
+/// >> push.1 push.0 push.2 push.0
+/// >> exec.u64::wrapping_add
+/// >> exec.add3
+///
 /// `!stack`
 /// The `!stack` command prints out the state of the stack at the last executed instruction. Since
 /// the stack always contains at least 16 elements, 16 or more elements will be printed out (even
@@ -61,10 +81,11 @@ use vm_core::{Felt, ProgramInputs, StarkField, Word};
 ///
 /// `!undo`
 /// The `!undo` command reverts to the previous state of the stack and memory by dropping off the
-/// last executed assembly instruction from the program. One could use `!undo` as often as they want
+/// last executed assembly instruction from the program. The previous state is restored from a
+/// snapshot taken before the instruction was executed. One could use `!undo` as often as they want
 /// to restore the state of a stack and memory $n$ instructions ago (provided there are $n$ instructions
 /// in the program). The `!undo` command will result in an error if no remaining instructions are left in
-///  the miden program.
+///  the miden program. Imports and procedure definitions are undone in the same way as instructions.
 /// >> push.1 push.2 push.3
 /// >> push.4
 /// >> !stack
@@ -77,6 +98,10 @@ use vm_core::{Felt, ProgramInputs, StarkField, Word};
 /// >> !undo
 /// 3 2 1 0 0 0 0 0 0 0 0 0 0 0 0 0
 ///
+/// `!procs`
+/// The `!procs` command prints out all procedures defined so far. In the above example, it would
+/// print out `proc.add3 push.3 add end`.
+///
 ///`!program`
 /// The `!program` command prints out the entire miden program getting executed. E.g., in the below scenario:
 /// >> push.1
@@ -96,6 +121,30 @@ use vm_core::{Felt, ProgramInputs, StarkField, Word};
 /// `!help`
 /// The `!help` command prints out all the available commands in the REPL tool.
 ///
+/// `!trace`
+/// The `!trace` command prints out the number of cycles it took to execute the last instruction,
+/// as well as the total number of cycles executed so far. The cycles of the `begin ... end` block
+/// wrapping each instruction (e.g., SPAN, END, and padding NOOPs) are not counted towards the
+/// instruction, but they are included in the total.
+///
+/// Inputs
+/// The REPL starts with an empty stack and no advice inputs. Initial stack values can be set with
+/// the `!stack_init v1 v2 ...` command (the last value ends up at the top of the stack), and values
+/// can be appended to the advice tape with the `!advice v1 v2 ...` command. The `!inputs path`
+/// command replaces all inputs with the ones read from a JSON inputs file (the same format as
+/// used by the `run` and `prove` commands, including advice map and advice sets). Whenever the
+/// inputs are changed, all lines entered so far are re-executed against the new inputs; if this
+/// fails, the inputs are left unchanged. For example, the following inputs leave values 4, 3, 2,
+/// and 1 at the top of the stack:
+/// >> !advice 3 4
+/// >> adv_push.2
+/// >> !stack_init 1 2
+///
+/// `!save path` and `!load path`
+/// The `!save` command writes the inputs and all lines entered so far into a session file at the
+/// specified path. The `!load` command replaces the current session with the one stored in the
+/// session file by re-executing all of its lines against its inputs.
+///
 /// `!mem`
 /// The `!mem` command prints out the contents of all initialized memory locations. For each such location,
 /// the address, along with its memory values, is printed. Recall that four elements are stored at each memory
@@ -123,64 +172,35 @@ use vm_core::{Felt, ProgramInputs, StarkField, Word};
 
 /// Initiates the Miden Repl tool.
 pub fn start_repl() {
-    let mut program_lines: Vec<String> = Vec::new();
+    let mut session =
+        ReplSession::new(InputFile::default()).expect("failed to initialize REPL session");
 
     println!("========================== Miden REPL ============================");
     println!();
     // prints out all the available commands in the Miden Repl tool.
     print_instructions();
 
-    // flag to determine if the stack should be printed or not post the execution of the
-    // last command.
-    let mut should_print_stack = false;
-
-    // state of the entire memory at the latest clock cycle.
-    let mut memory: Vec<(u64, Word)> = Vec::new();
-
     // initializing readline.
     let mut rl = Editor::<()>::new().expect("Readline couldn't be initialized");
     loop {
-        let program = format!(
-            "begin\n{}\nend",
-            program_lines
-                .iter()
-                .map(|l| format!("    {}", l))
-                .collect::<Vec<_>>()
-                .join("\n")
-        );
-
-        let result = execute(program.clone());
-
-        if !program_lines.is_empty() {
-            match result {
-                Ok((mem, stack_state)) => {
-                    if should_print_stack {
-                        print_stack(stack_state);
-                    }
-                    memory = mem;
-                }
-                Err(e) => {
-                    println!("{}", format!("Error running program: {:?}", e));
-                    program_lines.pop();
-                }
-            }
-        } else {
-            if should_print_stack {
-                println!("{}", str::repeat("0 ", 16));
-            }
-        }
         match rl.readline(">> ") {
             Ok(line) => {
                 if line == "!program" {
-                    println!("{}", program);
-                    should_print_stack = false;
+                    println!("{}", session.program());
+                } else if line == "!procs" {
+                    let procedures = session.procedures();
+                    if procedures.is_empty() {
+                        println!("No procedures have been defined yet");
+                    }
+                    for source in procedures {
+                        println!("{}", source);
+                    }
                 } else if line == "!help" {
                     // prints out all the available commands in the Miden Repl tool.
                     print_instructions();
-                    should_print_stack = false;
                 } else if line == "!mem" {
-                    should_print_stack = false;
-                    if memory.len() == 0 {
+                    let memory = session.process().get_memory_state(0);
+                    if memory.is_empty() {
                         println!("The memory has not been initialized yet");
                         continue;
                     }
@@ -192,47 +212,78 @@ pub fn start_repl() {
                     // if user wants to see the state of a particular address in a memory, the input should be atleast
                     // of length 5.
 
-                    // flag to determine if the memory at an address has been initialized or not
-                    let mut mem_at_addr_present = false;
-
                     // extracts the address from user input.
                     match read_mem_address(&line) {
-                        Ok(addr) => {
-                            for (i, memory_value) in &memory {
-                                if *i == addr {
-                                    // prints the address and memory value at that address.
-                                    print_mem_address(addr, memory_value);
-                                    // sets the flag to true as the address has been initialized.
-                                    mem_at_addr_present = true;
-                                    break;
-                                }
-                            }
-                            // incase the flag has not been initialized.
-                            if !mem_at_addr_present {
-                                println!("Memory at address {} is empty", addr);
-                            }
-                        }
+                        Ok(addr) => match session.process().get_memory_value(0, addr) {
+                            // prints the address and memory value at that address.
+                            Some(memory_value) => print_mem_address(addr, &memory_value),
+                            None => println!("Memory at address {} is empty", addr),
+                        },
                         Err(msg) => println!("{}", msg),
                     }
-
-                    should_print_stack = false;
                 } else if line == "!undo" {
-                    match program_lines.pop() {
-                        Some(last_line) => {
-                            println!("Undoing {}", last_line);
-                            should_print_stack = true;
+                    match session.undo() {
+                        Some(last_input) => {
+                            println!("Undoing {}", last_input.source());
+                            print_stack(session.process().get_stack_state());
                         }
                         None => {
                             println!("There's no previously executed command");
-                            should_print_stack = false;
                         }
                     };
                 } else if line == "!stack" {
-                    should_print_stack = true;
+                    print_stack(session.process().get_stack_state());
+                } else if line == "!trace" {
+                    match session.last_instruction_cycles() {
+                        Some((instruction, cycles)) => println!(
+                            "{} executed in {} cycles ({} cycles executed in total)",
+                            instruction,
+                            cycles,
+                            session.process().clk()
+                        ),
+                        None => println!("There's no previously executed command"),
+                    }
+                } else if let Some(values) = read_command_args(&line, "!stack_init") {
+                    let mut input_data = session.input_data().clone();
+                    input_data.stack_init = values.split_whitespace().map(String::from).collect();
+                    set_input_data(&mut session, input_data);
+                } else if let Some(values) = read_command_args(&line, "!advice") {
+                    let mut input_data = session.input_data().clone();
+                    input_data
+                        .advice_tape
+                        .get_or_insert_with(Vec::new)
+                        .extend(values.split_whitespace().map(String::from));
+                    set_input_data(&mut session, input_data);
+                } else if let Some(path) = read_command_args(&line, "!inputs") {
+                    match InputFile::read(&Some(PathBuf::from(path)), Path::new(path)) {
+                        Ok(input_data) => set_input_data(&mut session, input_data),
+                        Err(msg) => println!("{}", msg),
+                    }
+                } else if let Some(path) = read_command_args(&line, "!save") {
+                    match session.save(Path::new(path)) {
+                        Ok(()) => println!("Session saved to `{}`", path),
+                        Err(msg) => println!("{}", msg),
+                    }
+                } else if let Some(path) = read_command_args(&line, "!load") {
+                    match ReplSession::load(Path::new(path)) {
+                        Ok(loaded_session) => {
+                            session = loaded_session;
+                            println!("Session loaded from `{}`", path);
+                            print_stack(session.process().get_stack_state());
+                        }
+                        Err(msg) => println!("{}", msg),
+                    }
                 } else {
                     rl.add_history_entry(line.clone());
-                    program_lines.push(line.clone());
-                    should_print_stack = true;
+                    let input = ReplInput::parse(&line);
+                    let is_instruction = input.is_instruction();
+                    match session.add_input(input) {
+                        Ok(()) if is_instruction => {
+                            print_stack(session.process().get_stack_state())
+                        }
+                        Ok(()) => (),
+                        Err(msg) => println!("{}", msg),
+                    }
                 }
             }
             Err(ReadlineError::Interrupted) => {
@@ -256,30 +307,24 @@ pub fn start_repl() {
 /// HELPER METHODS
 /// --------------------------------------------------------------------------------------------
 
-/// Compiles and executes a compiled Miden program, returning the stack, memory and any Miden errors.
-/// The program is passed in as a String, passed to the Miden Assembler, and then passed into the Miden
-/// Processor to be executed.
-fn execute(program: String) -> Result<(Vec<(u64, Word)>, Vec<Felt>), ProgramError> {
-    let program = assembly::Assembler::new()
-        .compile(&program)
-        .map_err(ProgramError::AssemblyError)?;
-
-    let pub_inputs = vec![];
-    let inputs = ProgramInputs::new(&pub_inputs, &[], vec![]).unwrap();
-    let mut process = Process::new_debug(program.kernel(), inputs);
-    let _program_outputs = process
-        .execute(&program)
-        .map_err(ProgramError::ExecutionError);
-
-    let (sys, _, stack, _, chiplets) = process.to_components();
-
-    // loads the memory at the latest clock cycle.
-    let mem = chiplets.get_mem_state_at(0, sys.clk());
-
-    // loads the stack along with the overflow values at the latest clock cycle.
-    let stack_state = stack.get_state_at(sys.clk());
+/// Returns the arguments of the specified REPL command if the line is an invocation of this
+/// command, or None otherwise.
+fn read_command_args<'a>(line: &'a str, command: &str) -> Option<&'a str> {
+    let args = line.strip_prefix(command)?;
+    if args.is_empty() || args.starts_with(' ') {
+        Some(args.trim())
+    } else {
+        None
+    }
+}
 
-    Ok((mem, stack_state))
+/// Replaces the inputs of the session and prints out the resulting state of the stack. If the
+/// lines of the session cannot be re-executed against the new inputs, the session is not changed.
+fn set_input_data(session: &mut ReplSession, input_data: InputFile) {
+    match session.set_input_data(input_data) {
+        Ok(()) => print_stack(session.process().get_stack_state()),
+        Err(msg) => println!("{}", msg),
+    }
 }
 
 /// Parses the address in integer form from "!mem[addr]" command, otherwise throws an error.
@@ -311,8 +356,15 @@ fn print_instructions() {
     println!("!stack: displays the complete state of the stack");
     println!("!mem: displays the state of the entire memory");
     println!("!mem[i]: displays the state of the memory at address i");
-    println!("!undo: remove the last instruction");
+    println!("!undo: remove the last instruction, import, or procedure");
+    println!("!procs: displays all defined procedures");
     println!("!program: display the program");
+    println!("!trace: displays the number of cycles executed by the last instruction");
+    println!("!stack_init v1 v2 ...: sets the initial state of the stack");
+    println!("!advice v1 v2 ...: appends values to the advice tape");
+    println!("!inputs path: loads stack and advice inputs from a JSON file");
+    println!("!save path: saves the inputs and lines of the session to a file");
+    println!("!load path: loads a session from a file");
     println!("!help: prints out all the available commands");
     println!();
 }
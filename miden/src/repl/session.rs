@@ -0,0 +1,446 @@
+use super::ProgramError;
+use crate::cli::InputFile;
+use processor::FastProcess;
+use serde_derive::{Deserialize, Serialize};
+use std::{fs, path::Path};
+use stdlib::StdLibrary;
+use vm_core::{code_blocks::CodeBlock, Kernel, Program};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Body of a program which leaves the state of the VM unchanged. A program body cannot be empty,
+/// so this is used to check that imports and procedures compile before any instructions are
+/// entered.
+const EMPTY_BODY: &str = "push.0 drop";
+
+// REPL SESSION
+// ================================================================================================
+
+/// State of a REPL session.
+///
+/// A session consists of the inputs against which the instructions are executed (i.e., initial
+/// stack values and advice inputs), and of the lines entered into the REPL. Instructions are
+/// executed one by one against the state of the VM left by the previous instructions.
+pub struct ReplSession {
+    /// inputs against which the instructions are executed.
+    input_data: InputFile,
+    /// lines entered so far which were accepted by the REPL.
+    inputs: Vec<ReplInput>,
+    /// state of the VM after the execution of the last instruction.
+    process: FastProcess,
+    /// states of the VM prior to the execution of each accepted instruction; these are used to
+    /// revert the VM to the previous state on `!undo`.
+    snapshots: Vec<FastProcess>,
+    /// number of cycles executed by each accepted instruction, excluding the overhead of the
+    /// program wrapping it.
+    instruction_cycles: Vec<u32>,
+}
+
+impl ReplSession {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new session with no lines entered and with the VM initialized from the provided
+    /// inputs.
+    pub fn new(input_data: InputFile) -> Result<Self, String> {
+        let program_inputs = input_data.get_program_inputs()?;
+        Ok(Self {
+            input_data,
+            inputs: Vec::new(),
+            process: FastProcess::new(&Kernel::default(), program_inputs),
+            snapshots: Vec::new(),
+            instruction_cycles: Vec::new(),
+        })
+    }
+
+    /// Returns a new session with the VM initialized from the provided inputs, in which all of
+    /// the specified lines have been entered.
+    ///
+    /// # Errors
+    /// Returns an error if the inputs are invalid, or if any of the lines is not accepted.
+    pub fn replay(input_data: InputFile, lines: &[String]) -> Result<Self, String> {
+        let mut session = Self::new(input_data)?;
+        for line in lines {
+            session
+                .add_input(ReplInput::parse(line))
+                .map_err(|err| format!("Failed to replay `{}` - {}", line, err))?;
+        }
+        Ok(session)
+    }
+
+    /// Returns a session loaded from the session file at the specified path.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let session_file = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to open session file `{}` - {}", path.display(), err))?;
+        let session: SessionFile = serde_json::from_str(&session_file)
+            .map_err(|err| format!("Failed to deserialize session data - {}", err))?;
+
+        Self::replay(session.inputs, &session.lines)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the state of the VM after the execution of the last instruction.
+    pub fn process(&self) -> &FastProcess {
+        &self.process
+    }
+
+    /// Returns the inputs against which the instructions of this session are executed.
+    pub fn input_data(&self) -> &InputFile {
+        &self.input_data
+    }
+
+    /// Returns the number of cycles it took to execute the last instruction, or None if no
+    /// instructions have been executed yet.
+    ///
+    /// The cycles executed by the `begin ... end` block wrapping the instruction are not counted;
+    /// see [instruction_cycles()] for details.
+    pub fn last_instruction_cycles(&self) -> Option<(&str, u32)> {
+        let cycles = *self.instruction_cycles.last()?;
+        let instruction = self
+            .inputs
+            .iter()
+            .rev()
+            .find(|input| input.is_instruction())
+            .expect("no instruction for cycle count");
+        Some((instruction.source(), cycles))
+    }
+
+    /// Returns the sources of all procedures defined in this session. If a procedure was defined
+    /// more than once, only its latest definition is returned.
+    pub fn procedures(&self) -> Vec<&str> {
+        let mut procedures: Vec<(&str, &str)> = Vec::new();
+        for input in self.inputs.iter() {
+            if let ReplInput::Procedure(name, source) = input {
+                procedures.retain(|(existing, _)| existing != name);
+                procedures.push((name, source));
+            }
+        }
+        procedures.into_iter().map(|(_, source)| source).collect()
+    }
+
+    /// Returns the source code of the program consisting of all lines entered in this session.
+    pub fn program(&self) -> String {
+        let instructions = self
+            .inputs
+            .iter()
+            .filter(|input| input.is_instruction())
+            .map(ReplInput::source)
+            .collect::<Vec<_>>();
+        self.build_program(&instructions)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided input to this session.
+    ///
+    /// Instructions are compiled together with the imports and procedures entered so far, and
+    /// are executed against the current state of the VM. Imports and procedures are not executed,
+    /// but they must compile together with the previously entered lines.
+    ///
+    /// # Errors
+    /// Returns an error if the input could not be compiled or executed. In this case, the state
+    /// of the session is not changed.
+    pub fn add_input(&mut self, input: ReplInput) -> Result<(), String> {
+        match &input {
+            // importing the same module twice would make the program invalid
+            ReplInput::Import(import) if self.inputs.contains(&input) => {
+                return Err(format!("{} has already been imported", import));
+            }
+            ReplInput::Instruction(instruction) => {
+                let program = self.compile(&[instruction]).map_err(format_error)?;
+                let snapshot = self.process.clone();
+                if let Err(err) = self.process.execute_incremental(&program) {
+                    self.process = snapshot;
+                    return Err(format_error(ProgramError::ExecutionError(err)));
+                }
+                let executed_cycles = self.process.clk() - snapshot.clk();
+                self.instruction_cycles
+                    .push(instruction_cycles(&program, executed_cycles));
+                self.snapshots.push(snapshot);
+            }
+            _ => {
+                self.inputs.push(input);
+                if let Err(err) = self.compile(&[EMPTY_BODY]) {
+                    self.inputs.pop();
+                    return Err(format_error(err));
+                }
+                return Ok(());
+            }
+        }
+
+        self.inputs.push(input);
+        Ok(())
+    }
+
+    /// Removes the last line entered in this session and returns it. If the line was an
+    /// instruction, the VM is reverted to the state prior to its execution.
+    pub fn undo(&mut self) -> Option<ReplInput> {
+        let input = self.inputs.pop()?;
+        if input.is_instruction() {
+            self.process = self.snapshots.pop().expect("no snapshot for instruction");
+            self.instruction_cycles.pop();
+        }
+        Some(input)
+    }
+
+    /// Replaces the inputs of this session with the provided ones and re-executes all lines
+    /// entered so far against the new inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the new inputs are invalid, or if any of the lines fails against the
+    /// new inputs. In this case, the state of the session is not changed.
+    pub fn set_input_data(&mut self, input_data: InputFile) -> Result<(), String> {
+        let lines = self.lines();
+        *self = Self::replay(input_data, &lines)?;
+        Ok(())
+    }
+
+    /// Saves the inputs and the lines of this session into a session file at the specified path.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let session = SessionFile {
+            inputs: self.input_data.clone(),
+            lines: self.lines(),
+        };
+
+        let file = fs::File::create(path).map_err(|err| {
+            format!(
+                "Failed to create session file `{}` - {}",
+                path.display(),
+                err
+            )
+        })?;
+        serde_json::to_writer_pretty(file, &session)
+            .map_err(|err| format!("Failed to write session data - {}", err))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the sources of all lines entered in this session.
+    fn lines(&self) -> Vec<String> {
+        self.inputs
+            .iter()
+            .map(|input| input.source().to_string())
+            .collect()
+    }
+
+    /// Builds the source code of a Miden program with the specified instructions in its body. All
+    /// imports and procedure definitions of this session are placed before the `begin` block.
+    fn build_program(&self, instructions: &[&str]) -> String {
+        let mut program = String::new();
+        for input in self.inputs.iter() {
+            if let ReplInput::Import(source) = input {
+                program.push_str(source);
+                program.push('\n');
+            }
+        }
+        for source in self.procedures() {
+            program.push_str(source);
+            program.push('\n');
+        }
+
+        let body = instructions
+            .iter()
+            .map(|instruction| format!("    {}", instruction))
+            .collect::<Vec<_>>()
+            .join("\n");
+        program.push_str(&format!("begin\n{}\nend", body));
+        program
+    }
+
+    /// Compiles a program with the specified instructions in its body using the Miden Assembler
+    /// with the standard library available for imports.
+    fn compile(&self, instructions: &[&str]) -> Result<Program, ProgramError> {
+        assembly::Assembler::new()
+            .with_module_provider(StdLibrary::default())
+            .compile(self.build_program(instructions))
+            .map_err(ProgramError::AssemblyError)
+    }
+}
+
+// REPL INPUT
+// ================================================================================================
+
+/// A single line of Miden assembly entered into the REPL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplInput {
+    /// A `use` statement importing a module of the standard library.
+    Import(String),
+    /// A local procedure definition together with the name of the procedure.
+    Procedure(String, String),
+    /// One or more instructions to be executed.
+    Instruction(String),
+}
+
+impl ReplInput {
+    /// Classifies the provided line as an import, a procedure definition, or instructions.
+    pub fn parse(line: &str) -> Self {
+        let line = line.trim();
+        if line.starts_with("use.") {
+            Self::Import(line.to_string())
+        } else if let Some(signature) = line.strip_prefix("proc.") {
+            let name = signature
+                .split(|c: char| c == '.' || c.is_whitespace())
+                .next()
+                .unwrap_or_default();
+            Self::Procedure(name.to_string(), line.to_string())
+        } else {
+            Self::Instruction(line.to_string())
+        }
+    }
+
+    /// Returns the source code of this input.
+    pub fn source(&self) -> &str {
+        match self {
+            Self::Import(source) | Self::Procedure(_, source) | Self::Instruction(source) => source,
+        }
+    }
+
+    /// Returns true if this input contains instructions to be executed.
+    pub fn is_instruction(&self) -> bool {
+        matches!(self, Self::Instruction(_))
+    }
+}
+
+// SESSION FILE
+// ================================================================================================
+
+/// Session file struct
+#[derive(Deserialize, Serialize, Debug)]
+struct SessionFile {
+    inputs: InputFile,
+    lines: Vec<String>,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn format_error(err: ProgramError) -> String {
+    format!("Error running program: {:?}", err)
+}
+
+/// Returns the number of cycles spent on the instructions of the specified program, given the
+/// total number of cycles it took to execute the program.
+///
+/// The cycles spent on the `begin ... end` block wrapping the instructions are not counted. These
+/// are the operations starting and ending the root block of the program (e.g., SPAN and END). If
+/// the root block is a span block, the RESPAN operations and the NOOPs executed to align and pad
+/// its operation groups are not counted either, so that only the operations which the
+/// instructions compile to remain.
+fn instruction_cycles(program: &Program, executed_cycles: u32) -> u32 {
+    match program.root() {
+        CodeBlock::Span(block) => block
+            .op_batches()
+            .iter()
+            .map(|batch| batch.ops().len() as u32)
+            .sum(),
+        _ => executed_cycles - 2,
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{InputFile, ReplInput, ReplSession};
+    use vm_core::{Felt, StarkField};
+
+    fn stack_top(session: &ReplSession, n: usize) -> Vec<u64> {
+        let stack = session.process().get_stack_state();
+        stack[..n].iter().map(Felt::as_int).collect()
+    }
+
+    fn add_line(session: &mut ReplSession, line: &str) -> Result<(), String> {
+        session.add_input(ReplInput::parse(line))
+    }
+
+    #[test]
+    fn incremental_execution_and_undo() {
+        let mut session = ReplSession::new(InputFile::default()).unwrap();
+        add_line(&mut session, "use.std::math::u64").unwrap();
+        add_line(&mut session, "proc.add3 push.3 add end").unwrap();
+        add_line(&mut session, "push.1 push.0 push.2 push.0").unwrap();
+        add_line(&mut session, "exec.u64::wrapping_add exec.add3").unwrap();
+        assert_eq!(vec![3, 3], stack_top(&session, 2));
+        assert_eq!(vec!["proc.add3 push.3 add end"], session.procedures());
+
+        // failed inputs do not change the state of the session
+        let clk = session.process().clk();
+        assert!(add_line(&mut session, "use.std::math::u64").is_err());
+        assert!(add_line(&mut session, "proc.bad foo end").is_err());
+        assert!(add_line(&mut session, "push.0 div").is_err());
+        assert_eq!(clk, session.process().clk());
+        assert_eq!(vec![3, 3], stack_top(&session, 2));
+
+        let (instruction, cycles) = session.last_instruction_cycles().unwrap();
+        assert_eq!("exec.u64::wrapping_add exec.add3", instruction);
+        assert_eq!(9, cycles);
+
+        // undo restores the state prior to the last instruction
+        assert_eq!(
+            Some(ReplInput::Instruction(
+                "exec.u64::wrapping_add exec.add3".to_string()
+            )),
+            session.undo()
+        );
+        assert_eq!(vec![0, 2, 0, 1], stack_top(&session, 4));
+        assert!(session.undo().unwrap().is_instruction());
+        assert_eq!(vec![0, 0, 0, 0], stack_top(&session, 4));
+        assert!(session.last_instruction_cycles().is_none());
+    }
+
+    #[test]
+    fn instruction_cycles_exclude_wrapper() {
+        let mut session = ReplSession::new(InputFile::default()).unwrap();
+
+        // SPAN SWAP END
+        add_line(&mut session, "swap").unwrap();
+        assert_eq!(Some(("swap", 1)), session.last_instruction_cycles());
+        assert_eq!(3, session.process().clk());
+
+        // SPAN PUSH NOOP END; the NOOP follows the immediate value of PUSH
+        add_line(&mut session, "push.1 push.5").unwrap();
+        assert_eq!(
+            Some(("push.1 push.5", 3)),
+            session.last_instruction_cycles()
+        );
+        assert_eq!(9, session.process().clk());
+
+        // JOIN (SPAN PAD INCR END) (SPLIT SPAN SWAP DROP END END) END; only the outer JOIN and END
+        // belong to the wrapper
+        add_line(&mut session, "push.1 if.true swap drop else swap end").unwrap();
+        assert_eq!(
+            Some(("push.1 if.true swap drop else swap end", 10)),
+            session.last_instruction_cycles()
+        );
+    }
+
+    #[test]
+    fn changing_inputs_replays_lines() {
+        let mut session = ReplSession::new(InputFile::default()).unwrap();
+        assert!(add_line(&mut session, "adv_push.1").is_err());
+
+        let mut input_data = session.input_data().clone();
+        input_data.advice_tape = Some(vec!["5".to_string()]);
+        session.set_input_data(input_data).unwrap();
+        add_line(&mut session, "adv_push.1 push.1 add").unwrap();
+        assert_eq!(vec![6], stack_top(&session, 1));
+
+        // the line consumes an advice value; replaying it against an empty tape fails, and the
+        // session is left unchanged
+        assert!(session.set_input_data(InputFile::default()).is_err());
+        assert_eq!(vec![6], stack_top(&session, 1));
+
+        // the line is re-executed against the new stack inputs
+        let mut input_data = session.input_data().clone();
+        input_data.stack_init = vec!["10".to_string()];
+        session.set_input_data(input_data).unwrap();
+        assert_eq!(vec![6, 10], stack_top(&session, 2));
+        assert_eq!("begin\n    adv_push.1 push.1 add\nend", session.program());
+    }
+}
@@ -29,8 +29,9 @@ impl Analyze {
             std::fs::read_to_string(&self.assembly_file).expect("Could not read masm file");
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
-        let program_info: ProgramInfo = analyze(program.as_str(), input_data.get_program_inputs())
-            .expect("Could not retrieve program info");
+        let program_inputs = input_data.get_program_inputs()?;
+        let program_info: ProgramInfo =
+            analyze(program.as_str(), program_inputs).expect("Could not retrieve program info");
         println!("{}", program_info);
         Ok(())
     }
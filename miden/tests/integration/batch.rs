@@ -0,0 +1,37 @@
+use crate::build_test;
+use miden::{BatchVerifier, ProofOptions, VerificationError};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn batch_verification_reports_per_proof_results() {
+    let source = "begin push.3 mul push.5 add end";
+    let program = build_test!(source).compile();
+
+    let mut items = Vec::new();
+    for input in [1, 2, 3] {
+        let test = build_test!(source, &[input]);
+        let (outputs, proof) =
+            prover::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+        items.push((vec![input], outputs, proof));
+    }
+
+    // tamper with the outputs of the second proof
+    items[1].1.stack_mut()[0] += 1;
+
+    let results = BatchVerifier::new(program.hash()).verify(items.clone());
+    assert_eq!(3, results.len());
+    assert!(results[0].is_ok(), "error: {:?}", results[0]);
+    assert_eq!(Err(VerificationError::PublicInputsMismatch), results[1]);
+    assert!(results[2].is_ok(), "error: {:?}", results[2]);
+
+    // all proofs are rejected when they do not provide the required security level
+    let results = BatchVerifier::new(program.hash())
+        .with_min_security(128)
+        .verify(items);
+    assert!(results.iter().all(|result| matches!(
+        result,
+        Err(VerificationError::InsufficientSecurity { required: 128, .. })
+    )));
+}
@@ -0,0 +1,144 @@
+use crate::build_test;
+use miden::{ProofBundle, ProofOptions, VerificationError};
+use vm_core::ProgramOutputs;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn bundle_round_trip() {
+    let bundle = build_bundle();
+    let bytes = bundle.to_bytes();
+    assert_eq!(b"MVMP", &bytes[..4]);
+
+    let result = ProofBundle::from_bytes(&bytes).unwrap();
+    assert_eq!(bundle, result);
+    assert_eq!(ProofOptions::default(), result.options());
+
+    let verification = result.verify(bundle.program_hash(), None);
+    assert!(verification.is_ok(), "error: {:?}", verification);
+}
+
+#[test]
+fn bundle_with_wrong_outputs_fails_verification() {
+    let bundle = build_bundle();
+    let mut outputs = bundle.outputs().clone();
+    outputs.stack_mut()[0] += 1;
+
+    let bundle = ProofBundle::new(
+        bundle.program_hash(),
+        bundle.stack_inputs().to_vec(),
+        outputs,
+        bundle.proof().clone(),
+    );
+    let bundle = ProofBundle::from_bytes(&bundle.to_bytes()).unwrap();
+    assert_eq!(
+        Err(VerificationError::PublicInputsMismatch),
+        bundle.clone().verify(bundle.program_hash(), None)
+    );
+}
+
+#[test]
+fn bundle_for_other_program_fails_verification() {
+    let bundle = build_bundle();
+    let program_hash = bundle.program_hash();
+    let other_hash = build_test!("begin push.1 end").compile().hash();
+
+    let result = bundle.clone().verify(other_hash, None);
+    assert_eq!(
+        Err(VerificationError::ProgramHashMismatch {
+            expected: other_hash,
+            actual: program_hash,
+        }),
+        result
+    );
+    assert!(bundle.verify(program_hash, None).is_ok());
+}
+
+#[test]
+fn bundle_verification_checks_program_and_security() {
+    let bundle = build_bundle();
+    let program_hash = bundle.program_hash();
+    let security_level = bundle.security_level();
+    let other_hash = build_test!("begin push.1 end").compile().hash();
+
+    // the program hash is checked before the security level
+    let result = bundle.clone().verify(other_hash, Some(security_level + 1));
+    assert_eq!(
+        Err(VerificationError::ProgramHashMismatch {
+            expected: other_hash,
+            actual: program_hash,
+        }),
+        result
+    );
+
+    let result = bundle
+        .clone()
+        .verify(program_hash, Some(security_level + 1));
+    assert_eq!(
+        Err(VerificationError::InsufficientSecurity {
+            required: security_level + 1,
+            actual: security_level,
+        }),
+        result
+    );
+
+    assert!(bundle.verify(program_hash, Some(security_level)).is_ok());
+}
+
+#[test]
+fn bundle_with_malformed_outputs_fails_verification() {
+    let bundle = build_bundle();
+    let stack = bundle.outputs().stack()[..4].to_vec();
+
+    let bundle = ProofBundle::new(
+        bundle.program_hash(),
+        bundle.stack_inputs().to_vec(),
+        ProgramOutputs::new(stack, Vec::new()),
+        bundle.proof().clone(),
+    );
+    assert_eq!(
+        Err(VerificationError::NotEnoughStackOutputs(4)),
+        bundle.clone().verify(bundle.program_hash(), None)
+    );
+}
+
+#[test]
+fn malformed_bundle_is_rejected() {
+    let bytes = build_bundle().to_bytes();
+
+    // wrong magic bytes
+    let mut wrong_magic = bytes.clone();
+    wrong_magic[0] = b'X';
+    assert!(ProofBundle::from_bytes(&wrong_magic).is_err());
+
+    // unsupported version
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] += 1;
+    assert!(ProofBundle::from_bytes(&wrong_version).is_err());
+
+    // stack input which is not a valid field element; the stack inputs follow the magic bytes,
+    // the version, the program hash, and the number of inputs
+    assert_eq!(7u64.to_le_bytes(), bytes[41..49]);
+    let mut wrong_input = bytes.clone();
+    wrong_input[41..49].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(ProofBundle::from_bytes(&wrong_input).is_err());
+
+    // truncated bundle
+    assert!(ProofBundle::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // trailing bytes
+    let mut extra_bytes = bytes;
+    extra_bytes.push(0);
+    assert!(ProofBundle::from_bytes(&extra_bytes).is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_bundle() -> ProofBundle {
+    let test = build_test!("begin push.3 mul push.5 add end", &[7]);
+    let program = test.compile();
+    let (outputs, proof) = prover::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+    ProofBundle::new(program.hash(), vec![7], outputs, proof)
+}
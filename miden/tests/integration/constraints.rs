@@ -0,0 +1,51 @@
+use crate::build_test;
+use miden::{check_constraints, check_trace_constraints, ConstraintKind, Trace};
+use vm_core::Felt;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn valid_trace_satisfies_constraints() {
+    let source = "
+        begin
+            push.1 push.2 u32checked_and
+            push.3 mem_store.0 drop
+            push.1.2.3.4 push.5.6.7.8 push.9.10.11.12
+            rpperm
+            dropw dropw dropw
+            mem_load.0
+        end";
+    let test = build_test!(source, &[5, 7]);
+    let program = test.compile();
+
+    let outputs = check_constraints(&program, &test.inputs).unwrap();
+    assert_eq!(&[3, 7, 5, 0], &outputs.stack()[..4]);
+}
+
+#[test]
+fn wrong_outputs_fail_boundary_constraints() {
+    let test = build_test!("begin push.1 push.2 add end");
+    let mut trace = test.execute().unwrap();
+    let mut outputs = trace.program_outputs();
+    outputs.stack_mut()[0] += 1;
+
+    let failure = check_trace_constraints(&mut trace, &[], &outputs).unwrap_err();
+    assert_eq!(ConstraintKind::MainBoundary, failure.kind());
+    assert_eq!("stack", failure.component());
+    assert_eq!(trace.length() - 2, failure.step());
+}
+
+#[test]
+fn wrong_stack_inputs_fail_boundary_constraints() {
+    let test = build_test!("begin add end", &[1, 2]);
+    let mut trace = test.execute().unwrap();
+    let outputs = trace.program_outputs();
+
+    // the stack was initialized with [2, 1], so [2, 2] is not a valid initial state
+    let stack_inputs = [Felt::new(2), Felt::new(2)];
+    let failure = check_trace_constraints(&mut trace, &stack_inputs, &outputs).unwrap_err();
+    assert_eq!(ConstraintKind::MainBoundary, failure.kind());
+    assert_eq!("stack", failure.component());
+    assert_eq!(0, failure.step());
+}
@@ -0,0 +1,32 @@
+use crate::build_test;
+use miden::{estimate_proof, ProofOptions, Trace};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn estimate_matches_generated_proof() {
+    let test = build_test!("begin repeat.100 push.3 mul push.5 add end end", &[7]);
+    let program = test.compile();
+    let trace = test.execute().unwrap();
+
+    for options in [
+        ProofOptions::with_96_bit_security(),
+        ProofOptions::with_128_bit_security(),
+    ] {
+        let estimate = estimate_proof(&trace, &options);
+        assert_eq!(trace.length(), estimate.trace_length());
+        assert_eq!(
+            trace.length() * options.blowup_factor(),
+            estimate.lde_domain_size()
+        );
+
+        let (_, proof) = prover::prove(&program, &test.inputs, &options).unwrap();
+        assert_eq!(proof.security_level(true), estimate.security_level());
+
+        // the estimated proof size is an upper bound which should be reasonably tight
+        let proof_size = proof.to_bytes().len();
+        assert!(estimate.proof_size() >= proof_size);
+        assert!(estimate.proof_size() < proof_size * 3 / 2);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::build_test;
+use miden::{execute_fast, FastProcess};
+use processor::Process;
+use vm_core::{Felt, FieldElement, ProgramInputs, StarkField};
+
+// CONSTANTS
+// ================================================================================================
+
+/// A program which uses procedure locals, memory, stack overflow, calls, and loops, and which
+/// leaves values in the overflow table at the end of execution.
+const SOURCE: &str = "
+    proc.foo.1
+        dup loc_store.0
+        push.1 push.2 push.3 push.4 push.5 push.6 push.7 push.8 push.9
+        push.10 push.11 push.12 push.13 push.14 push.15 push.16 push.17
+        dropw dropw dropw dropw drop
+        dup mem_store.0
+        loc_load.0 dup.1 assert_eq
+    end
+
+    proc.bar
+        push.1 push.2 push.3 push.4 push.5 push.6 push.7 push.8 push.9
+        dropw dropw drop
+        sdepth push.16 assert_eq
+    end
+
+    begin
+        dup neq.0
+        while.true
+            exec.foo
+            call.bar
+            push.1 sub dup neq.0
+        end
+        push.3 push.4 push.5 push.6 push.7 push.8 push.9 push.10 push.11 push.12
+        dup.4 mem_storew.100 dropw
+    end";
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn fast_execution_matches_full_execution() {
+    let test = build_test!(
+        SOURCE,
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 10]
+    );
+    let program = test.compile();
+
+    // outputs must be the same, including addresses of the items left in the overflow table
+    let expected = test.execute().unwrap().program_outputs();
+    let outputs = execute_fast(&program, &test.inputs).unwrap();
+    assert_eq!(expected, outputs);
+    assert!(outputs.has_overflow());
+
+    // the number of executed cycles and the final state of memory must be the same as well
+    let mut process = Process::new(program.kernel(), test.inputs.clone());
+    process.execute(&program).unwrap();
+    let mut fast_process = FastProcess::new(program.kernel(), test.inputs.clone());
+    fast_process.execute(&program).unwrap();
+
+    for addr in [0, 1, 100] {
+        assert_eq!(
+            process.get_memory_value(0, addr),
+            fast_process.get_memory_value(0, addr)
+        );
+    }
+    assert_eq!(
+        Some([Felt::ONE, Felt::ZERO, Felt::ZERO, Felt::ZERO]),
+        fast_process.get_memory_value(0, 0)
+    );
+
+    let (system, ..) = process.to_components();
+    assert_eq!(system.clk(), fast_process.clk());
+}
+
+#[test]
+fn fast_execution_errors_match_full_execution() {
+    let sources = [
+        // failed assertion
+        "begin push.1 push.2 add push.4 assert end",
+        // division by zero inside a loop
+        "begin
+            push.5 push.1
+            while.true
+                push.1 sub dup eq.0 if.true push.0 inv drop end dup neq.0
+            end
+        end",
+        // invalid stack depth when returning from a call
+        "proc.foo push.1 end begin call.foo end",
+        // reading from an empty advice tape
+        "begin push.1 drop adv_push.1 end",
+        // non-binary loop condition
+        "begin push.2 while.true push.0 end end",
+    ];
+
+    for source in sources {
+        let test = build_test!(source);
+        let program = test.compile();
+        let expected = match test.execute() {
+            Err(err) => err,
+            Ok(_) => panic!("execution of `{}` should fail", source),
+        };
+        let err = execute_fast(&program, &test.inputs).unwrap_err();
+        assert_eq!(format!("{:?}", expected), format!("{:?}", err));
+    }
+}
+
+#[test]
+fn incremental_execution() {
+    let first = build_test!("begin push.1 push.2 push.3 mem_store.4 end").compile();
+    let second = build_test!("begin add push.5 mem_load.4 end").compile();
+    let whole = build_test!("begin push.1 push.2 push.3 mem_store.4 add push.5 mem_load.4 end");
+
+    // executing the programs one after another carries over the stack and memory
+    let mut process = FastProcess::new(first.kernel(), ProgramInputs::none());
+    process.execute_incremental(&first).unwrap();
+    let clk = process.clk();
+    let outputs = process.execute_incremental(&second).unwrap();
+    assert!(process.clk() > clk);
+
+    let expected = whole.execute().unwrap().program_outputs();
+    assert_eq!(expected.stack(), outputs.stack());
+    let stack_state = process.get_stack_state();
+    assert_eq!(
+        expected.stack(),
+        stack_state.iter().map(|v| v.as_int()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(4, [Felt::new(3), Felt::ZERO, Felt::ZERO, Felt::ZERO])],
+        process.get_memory_state(0)
+    );
+}
@@ -154,9 +154,29 @@ impl Test {
 
     /// Compiles the test's source to a Program and executes it with the tests inputs. Returns a
     /// resulting execution trace or error.
+    ///
+    /// The program is also executed without generating a trace, and the outputs (or the error)
+    /// of this execution are checked to be the same as the ones of the regular execution.
     pub fn execute(&self) -> Result<ExecutionTrace, ExecutionError> {
         let program = self.compile();
-        processor::execute(&program, &self.inputs)
+        let result = processor::execute(&program, &self.inputs);
+        let fast_result = processor::execute_fast(&program, &self.inputs);
+        match (&result, &fast_result) {
+            (Ok(trace), Ok(outputs)) => {
+                assert_eq!(&trace.program_outputs(), outputs, "fast execution mismatch")
+            }
+            (Err(err), Err(fast_err)) => assert_eq!(
+                format!("{:?}", err),
+                format!("{:?}", fast_err),
+                "fast execution mismatch"
+            ),
+            _ => panic!(
+                "fast execution mismatch: {:?} vs {:?}",
+                result.as_ref().map(|trace| trace.program_outputs()),
+                fast_result
+            ),
+        }
+        result
     }
 
     /// Compiles the test's code into a program, then generates and verifies a proof of execution
@@ -1,9 +1,15 @@
 mod helpers;
 
 mod air;
+mod batch;
+mod bundle;
+mod constraints;
+mod estimate;
 mod exec_iters;
+mod fast;
 mod flow_control;
 mod operations;
+mod security;
 mod stdlib;
 
 // TESTS
@@ -118,6 +118,29 @@ fn advice_inject_u64div_conditional_execution() {
     test.expect_stack(&[0, 0, 0, 0, 0, 4, 0, 8]);
 }
 
+#[test]
+fn advice_inject_u256div() {
+    let source = "begin adv.u256div adv_push.8 adv_push.8 end";
+
+    // get a random 128-bit dividend and a random 64-bit divisor, and split them into 32-bit limbs
+    let a = rand_value::<u128>();
+    let b = rand_value::<u64>() as u128;
+    let q = a / b;
+    let r = a % b;
+
+    // the limbs of each value are placed on the stack with the least significant limb deepest
+    let mut stack_inputs = u256_to_limbs(a).to_vec();
+    stack_inputs.extend_from_slice(&u256_to_limbs(b));
+
+    // inject a/b into the advice tape and then read the quotient and the remainder from the tape
+    let mut expected = u256_to_limbs(q).to_vec();
+    expected.extend_from_slice(&u256_to_limbs(r));
+    expected.reverse();
+
+    let test = build_test!(source, &stack_inputs);
+    test.expect_stack(&expected);
+}
+
 #[test]
 fn advice_inject_mem() {
     let source = "begin
@@ -173,3 +196,16 @@ fn advice_inject_mem() {
     let test = build_test!(source, &stack_inputs);
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Splits the provided value into eight 32-bit limbs of a 256-bit integer, the least significant
+/// limb first.
+fn u256_to_limbs(value: u128) -> [u64; 8] {
+    let mut limbs = [0; 8];
+    for (i, limb) in limbs.iter_mut().take(4).enumerate() {
+        *limb = (value >> (32 * i)) as u32 as u64;
+    }
+    limbs
+}
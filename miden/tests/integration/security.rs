@@ -0,0 +1,48 @@
+use crate::build_test;
+use miden::{
+    verify_with_min_security, FieldExtension, HashFunction, ProofOptions, VerificationError,
+};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn proof_with_weak_options_is_rejected() {
+    // a proof with only 4 queries provides well below 96 bits of security
+    let options = ProofOptions::new(
+        4,
+        8,
+        0,
+        HashFunction::Blake3_192,
+        FieldExtension::Quadratic,
+        8,
+        256,
+    );
+    let test = build_test!("begin push.3 mul push.5 add end", &[7]);
+    let program = test.compile();
+    let (outputs, proof) = prover::prove(&program, &test.inputs, &options).unwrap();
+    let security_level = proof.security_level(true);
+    assert!(security_level < 96);
+
+    let result = verify_with_min_security(program.hash(), &[7], &outputs, proof.clone(), 96);
+    assert_eq!(
+        Err(VerificationError::InsufficientSecurity {
+            required: 96,
+            actual: security_level
+        }),
+        result
+    );
+
+    let result = verify_with_min_security(program.hash(), &[7], &outputs, proof, security_level);
+    assert!(result.is_ok(), "error: {:?}", result);
+}
+
+#[test]
+fn proof_with_default_options_is_accepted() {
+    let test = build_test!("begin push.3 mul push.5 add end", &[7]);
+    let program = test.compile();
+    let (outputs, proof) = prover::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+
+    let result = verify_with_min_security(program.hash(), &[7], &outputs, proof, 96);
+    assert!(result.is_ok(), "error: {:?}", result);
+}
@@ -0,0 +1,193 @@
+use super::{build_test, TestError};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Memory address at which the array is placed by the tests.
+const ARR_PTR: u64 = 1000;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn array_init() {
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.5 exec.array::init
+            push.{ARR_PTR} exec.array::capacity
+            push.{ARR_PTR} exec.array::len
+        end"
+    );
+    build_test!(&source).expect_stack(&[0, 5]);
+
+    // the capacity must be a u32 value
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.4294967296 exec.array::init
+        end"
+    );
+    build_test!(&source).expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn array_push_and_get() {
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.3 exec.array::init
+            {}
+            push.{ARR_PTR} exec.array::len
+            push.{ARR_PTR} push.0 exec.array::get
+            push.{ARR_PTR} push.2 exec.array::get
+        end",
+        push_words(3)
+    );
+    build_test!(&source).expect_stack(&[23, 22, 21, 20, 3, 2, 1, 0, 3]);
+
+    // pushing into a full array fails
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.3 exec.array::init
+            {}
+        end",
+        push_words(4)
+    );
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // reading past the end of the array fails
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.3 exec.array::init
+            {}
+            push.{ARR_PTR} push.2 exec.array::get
+        end",
+        push_words(2)
+    );
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn array_set() {
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.3 exec.array::init
+            {}
+            push.{ARR_PTR} push.1 push.7.8.9.10 exec.array::set
+            push.{ARR_PTR} push.1 exec.array::get
+            push.{ARR_PTR} push.0 exec.array::get
+        end",
+        push_words(2)
+    );
+    build_test!(&source).expect_stack(&[3, 2, 1, 0, 10, 9, 8, 7]);
+
+    // writing past the end of the array fails
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.3 exec.array::init
+            {}
+            push.{ARR_PTR} push.2 push.7.8.9.10 exec.array::set
+        end",
+        push_words(2)
+    );
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn array_pop() {
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.3 exec.array::init
+            {}
+            push.{ARR_PTR} exec.array::pop
+            push.{ARR_PTR} exec.array::len
+            push.{ARR_PTR} exec.array::pop
+            push.{ARR_PTR} exec.array::len
+        end",
+        push_words(2)
+    );
+    build_test!(&source).expect_stack(&[0, 3, 2, 1, 0, 1, 13, 12, 11, 10]);
+
+    // popping from an empty array fails
+    let source = format!(
+        "
+        use.std::collections::array
+
+        begin
+            push.{ARR_PTR} push.3 exec.array::init
+            push.{ARR_PTR} exec.array::pop
+        end"
+    );
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn memcopy_and_memset() {
+    // fill 3 words with [1, 2, 3, 4], copy them 10 words further, and read the copies back
+    let source = "
+        use.std::collections::array
+
+        begin
+            push.100 push.3 push.4.3.2.1 exec.array::memset
+            push.110 push.100 push.3 exec.array::memcopy
+            padw push.110 mem_loadw
+            padw push.112 mem_loadw
+            padw push.113 mem_loadw
+        end";
+    build_test!(source).expect_stack(&[0, 0, 0, 0, 1, 2, 3, 4, 1, 2, 3, 4]);
+
+    // copying and setting zero words does nothing
+    let source = "
+        use.std::collections::array
+
+        begin
+            push.100 push.0 push.4.3.2.1 exec.array::memset
+            push.110 push.100 push.0 exec.array::memcopy
+            padw push.100 mem_loadw
+            padw push.110 mem_loadw
+        end";
+    build_test!(source).expect_stack(&[0, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the source code which pushes `n` words into the array located at [ARR_PTR]; the word
+/// at index `i` is [10 * i, 10 * i + 1, 10 * i + 2, 10 * i + 3].
+fn push_words(n: u64) -> String {
+    (0..n)
+        .map(|i| {
+            let v = 10 * i;
+            format!(
+                "push.{ARR_PTR} push.{}.{}.{}.{} exec.array::push",
+                v,
+                v + 1,
+                v + 2,
+                v + 3
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
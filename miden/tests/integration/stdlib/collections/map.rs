@@ -0,0 +1,183 @@
+use super::{build_map_test, build_test, push_word, word_to_stack, TestError};
+use std::collections::BTreeMap;
+use vm_core::{chiplets::hasher, utils::IntoBytes, Felt, Word};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Memory address at which the map is placed by the tests.
+const MAP_PTR: u64 = 1000;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn map_set_and_get() {
+    let entries = build_entries(3);
+
+    // the value of a key which is not in the map is a word of zeros
+    let source = format!(
+        "
+        use.std::collections::map
+
+        begin
+            push.{MAP_PTR} exec.map::init
+            {}
+            push.{MAP_PTR} exec.map::num_entries
+            push.{MAP_PTR} push.7.7.7.7 exec.map::contains
+            push.{MAP_PTR} push.7.7.7.7 exec.map::get
+            push.{MAP_PTR} {} exec.map::contains
+            push.{MAP_PTR} {} exec.map::get
+        end",
+        set_entries(&entries),
+        push_word(entries[1].0),
+        push_word(entries[1].0),
+    );
+
+    let mut expected = word_to_stack(entries[1].1);
+    expected.extend_from_slice(&[1, 0, 0, 0, 0, 0, 3]);
+    build_test!(&source).expect_stack(&expected);
+}
+
+#[test]
+fn map_update() {
+    let entries = build_entries(3);
+    let new_value = [Felt::new(7); 4];
+
+    // updating an existing key does not change the number of entries
+    let source = format!(
+        "
+        use.std::collections::map
+
+        begin
+            {}
+            push.{MAP_PTR} {} {} exec.map::set
+            push.{MAP_PTR} exec.map::num_entries
+            push.{MAP_PTR} {} exec.map::get
+            push.{MAP_PTR} {} exec.map::get
+        end",
+        set_entries(&entries),
+        push_word(entries[2].0),
+        push_word(new_value),
+        push_word(entries[2].0),
+        push_word(entries[0].0),
+    );
+
+    let mut expected = word_to_stack(entries[0].1);
+    expected.extend(word_to_stack(new_value));
+    expected.push(3);
+    build_test!(&source).expect_stack(&expected);
+}
+
+#[test]
+fn map_commit() {
+    for num_entries in [0, 1, 2, 5] {
+        let entries = build_entries(num_entries);
+        let (key, _) = advice_map_entry(&entries);
+
+        let source = format!(
+            "
+            use.std::collections::map
+
+            begin
+                push.{MAP_PTR} exec.map::init
+                {}
+                push.{MAP_PTR} exec.map::commit
+            end",
+            set_entries(&entries)
+        );
+
+        build_test!(&source).expect_stack(&word_to_stack(key));
+    }
+}
+
+#[test]
+fn map_load() {
+    let entries = build_entries(4);
+    let (key, value) = advice_map_entry(&entries);
+
+    // load the map, then read all of its values and compute its commitment again
+    let mut source = format!(
+        "
+        use.std::collections::map
+
+        begin
+            {} push.{MAP_PTR} movdn.4 exec.map::load
+            push.{MAP_PTR} exec.map::num_entries push.4 assert_eq",
+        push_word(key)
+    );
+    for (k, v) in entries.iter() {
+        source.push_str(&format!(
+            "
+            push.{MAP_PTR} {} exec.map::get {} eqw assert dropw dropw",
+            push_word(*k),
+            push_word(*v)
+        ));
+    }
+    source.push_str(&format!(
+        "
+            push.{MAP_PTR} exec.map::commit
+        end"
+    ));
+
+    let advice_map = BTreeMap::from([(key.into_bytes(), value.clone())]);
+    let test = build_map_test(&source, advice_map, vec![]);
+    test.expect_stack(&word_to_stack(key));
+
+    // the loaded data must match the commitment
+    let mut invalid_value = value.clone();
+    invalid_value[5] = Felt::new(100);
+    let advice_map = BTreeMap::from([(key.into_bytes(), invalid_value)]);
+    let test = build_map_test(&source, advice_map, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the number of entries is bound by the commitment
+    let mut invalid_value = value;
+    invalid_value[0] = Felt::new(3);
+    let advice_map = BTreeMap::from([(key.into_bytes(), invalid_value)]);
+    let test = build_map_test(&source, advice_map, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a list of entries with distinct keys.
+fn build_entries(num_entries: usize) -> Vec<(Word, Word)> {
+    (0..num_entries as u64)
+        .map(|i| {
+            let key = [i, i + 100, i + 200, i + 300].map(Felt::new);
+            let value = [i + 1000, i + 2000, i + 3000, i + 4000].map(Felt::new);
+            (key, value)
+        })
+        .collect()
+}
+
+/// Returns the source code which inserts the provided entries into the map located at [MAP_PTR].
+fn set_entries(entries: &[(Word, Word)]) -> String {
+    entries
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "push.{MAP_PTR} {} {} exec.map::set",
+                push_word(*key),
+                push_word(*value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the commitment to a map with the provided entries, and the value of the advice map
+/// entry required to load such a map via `map::load`.
+fn advice_map_entry(entries: &[(Word, Word)]) -> (Word, Vec<Felt>) {
+    let elements = entries
+        .iter()
+        .flat_map(|(key, value)| key.iter().chain(value.iter()).copied())
+        .collect::<Vec<_>>();
+    let commitment: Word = hasher::hash_elements(&elements).into();
+
+    let mut value = vec![Felt::new(entries.len() as u64)];
+    value.extend_from_slice(&elements);
+    (commitment, value)
+}
@@ -0,0 +1,141 @@
+use super::{build_map_test, build_test, push_word, word_to_stack, TestError};
+use std::collections::BTreeMap;
+use vm_core::{Felt, Mmr};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Memory address at which the MMR is placed by the tests.
+const MMR_PTR: u64 = 1000;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn mmr_add_and_pack() {
+    for num_leaves in [1, 2, 3, 4, 5, 7, 8, 11] {
+        let mmr = build_mmr(num_leaves);
+
+        let source = format!(
+            "
+            use.std::collections::mmr
+
+            begin
+                {}
+                push.{MMR_PTR} exec.mmr::num_leaves
+                push.{MMR_PTR} exec.mmr::pack
+            end",
+            add_leaves(&mmr)
+        );
+
+        let mut expected = word_to_stack(mmr.commitment());
+        expected.push(num_leaves as u64);
+        build_test!(&source).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn mmr_get() {
+    let mmr = build_mmr(7);
+
+    for pos in 0..mmr.num_leaves() {
+        let source = format!(
+            "
+            use.std::collections::mmr
+
+            begin
+                {}
+                push.{MMR_PTR} push.{pos} exec.mmr::get
+            end",
+            add_leaves(&mmr)
+        );
+
+        let expected = word_to_stack(mmr.get(pos).unwrap());
+        build_test!(&source, &[], &[], mmr.advice_sets()).expect_stack(&expected);
+    }
+
+    // reading a leaf past the end of the MMR fails
+    let source = format!(
+        "
+        use.std::collections::mmr
+
+        begin
+            {}
+            push.{MMR_PTR} push.7 exec.mmr::get
+        end",
+        add_leaves(&mmr)
+    );
+    build_test!(&source, &[], &[], mmr.advice_sets())
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn mmr_unpack() {
+    let mmr = build_mmr(6);
+    let (key, value) = mmr.advice_map_entry();
+
+    // unpack the MMR, then read all of its leaves and compute its commitment again
+    let mut source = format!(
+        "
+        use.std::collections::mmr
+
+        begin
+            {} push.{MMR_PTR} movdn.4 exec.mmr::unpack
+            push.{MMR_PTR} exec.mmr::num_leaves push.6 assert_eq",
+        push_word(mmr.commitment())
+    );
+    for pos in 0..mmr.num_leaves() {
+        source.push_str(&format!(
+            "
+            push.{MMR_PTR} push.{pos} exec.mmr::get {} eqw assert dropw dropw",
+            push_word(mmr.get(pos).unwrap())
+        ));
+    }
+    source.push_str(&format!(
+        "
+            push.{MMR_PTR} exec.mmr::pack
+        end"
+    ));
+
+    let advice_map = BTreeMap::from([(key, value.clone())]);
+    let test = build_map_test(&source, advice_map, mmr.advice_sets());
+    test.expect_stack(&word_to_stack(mmr.commitment()));
+
+    // the number of leaves in the advice map must match the unpacked data
+    let mut invalid_value = value;
+    invalid_value[0] = Felt::new(7);
+    let advice_map = BTreeMap::from([(key, invalid_value)]);
+    let test = build_map_test(&source, advice_map, mmr.advice_sets());
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the unpacked data must match the commitment
+    let other = build_mmr(5);
+    let (_, other_value) = other.advice_map_entry();
+    let advice_map = BTreeMap::from([(key, other_value)]);
+    let test = build_map_test(&source, advice_map, mmr.advice_sets());
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an MMR with the specified number of leaves.
+fn build_mmr(num_leaves: usize) -> Mmr {
+    let mut mmr = Mmr::new();
+    for i in 0..num_leaves as u64 {
+        mmr.add([i, i + 100, i + 200, i + 300].map(Felt::new));
+    }
+    mmr
+}
+
+/// Returns the source code which appends all leaves of the provided MMR to an empty MMR located
+/// at [MMR_PTR].
+fn add_leaves(mmr: &Mmr) -> String {
+    (0..mmr.num_leaves())
+        .map(|pos| {
+            let leaf = mmr.get(pos).unwrap();
+            format!("{} push.{MMR_PTR} movdn.4 exec.mmr::add", push_word(leaf))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
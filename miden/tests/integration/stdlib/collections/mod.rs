@@ -0,0 +1,43 @@
+use super::build_test;
+use crate::helpers::{Test, TestError};
+use std::collections::BTreeMap;
+use vm_core::{AdviceSet, Felt, ProgramInputs, StarkField, Word};
+
+mod array;
+mod map;
+mod mmr;
+mod smt;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an instruction which pushes the provided word onto the stack.
+fn push_word(word: Word) -> String {
+    format!(
+        "push.{}.{}.{}.{}",
+        word[0].as_int(),
+        word[1].as_int(),
+        word[2].as_int(),
+        word[3].as_int()
+    )
+}
+
+/// Returns the values of the provided word in the order in which they appear on the stack.
+fn word_to_stack(word: Word) -> Vec<u64> {
+    word.iter().rev().map(|value| value.as_int()).collect()
+}
+
+/// Returns a test which executes the provided source with an empty stack, and with the specified
+/// advice map and advice sets.
+fn build_map_test(
+    source: &str,
+    advice_map: BTreeMap<[u8; 32], Vec<Felt>>,
+    advice_sets: Vec<AdviceSet>,
+) -> Test {
+    Test {
+        source: source.to_string(),
+        kernel: None,
+        inputs: ProgramInputs::with_advice_map(&[], &[], advice_map, advice_sets).unwrap(),
+        in_debug_mode: false,
+    }
+}
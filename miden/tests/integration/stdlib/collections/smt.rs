@@ -0,0 +1,166 @@
+use super::{build_test, push_word, word_to_stack, TestError};
+use vm_core::{AdviceSet, Felt, FieldElement, Word};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Depth of the sparse Merkle trees used by the tests.
+const DEPTH: u32 = 10;
+
+/// Keys of the leaves of the sparse Merkle trees used by the tests.
+const KEYS: [u64; 3] = [3, 200, 1000];
+
+/// A key which is not set in the sparse Merkle trees used by the tests.
+const EMPTY_KEY: u64 = 5;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn smt_get() {
+    let smt = build_smt();
+    let root = smt.root();
+
+    for (key, value) in KEYS
+        .iter()
+        .zip(values())
+        .chain([(&EMPTY_KEY, [Felt::ZERO; 4])])
+    {
+        let source = format!(
+            "
+            use.std::collections::smt
+
+            begin
+                {} push.{DEPTH} push.{key} exec.smt::get
+            end",
+            push_word(root)
+        );
+
+        let mut expected = word_to_stack(value);
+        expected.extend(word_to_stack(root));
+        build_test!(&source, &[], &[], vec![smt.clone()]).expect_stack(&expected);
+    }
+
+    // reading a key outside of the tree fails
+    let source = format!(
+        "
+        use.std::collections::smt
+
+        begin
+            {} push.{DEPTH} push.1024 exec.smt::get
+        end",
+        push_word(root)
+    );
+    build_test!(&source, &[], &[], vec![smt])
+        .expect_error(TestError::ExecutionError("AdviceSetLookupFailed"));
+}
+
+#[test]
+fn smt_set() {
+    let new_value = [Felt::new(7); 4];
+
+    // update an existing value and remove an existing value, then set a new value
+    for (key, old_value, new_value) in [
+        (KEYS[1], values()[1], new_value),
+        (KEYS[2], values()[2], [Felt::ZERO; 4]),
+        (EMPTY_KEY, [Felt::ZERO; 4], new_value),
+    ] {
+        let smt = build_smt();
+        let mut expected_smt = smt.clone();
+        expected_smt.update_leaf(key, new_value).unwrap();
+        let new_root = expected_smt.root();
+
+        // set the value, and then read it back from the updated tree
+        let source = format!(
+            "
+            use.std::collections::smt
+
+            begin
+                {} push.{DEPTH} push.{key} {} exec.smt::set
+                swapw push.{DEPTH} push.{key} exec.smt::get
+            end",
+            push_word(smt.root()),
+            push_word(new_value)
+        );
+
+        let mut expected = word_to_stack(new_value);
+        expected.extend(word_to_stack(new_root));
+        expected.extend(word_to_stack(old_value));
+        build_test!(&source, &[], &[], vec![smt]).expect_stack(&expected);
+    }
+
+    // removing all values results in the root of an empty tree
+    let smt = build_smt();
+    let empty_smt = AdviceSet::new_sparse_merkle_tree(vec![], vec![], DEPTH).unwrap();
+    let mut source = format!(
+        "
+        use.std::collections::smt
+
+        begin
+            {}",
+        push_word(smt.root())
+    );
+    for key in KEYS {
+        source.push_str(&format!(
+            "
+            push.{DEPTH} push.{key} padw exec.smt::set dropw"
+        ));
+    }
+    source.push_str(
+        "
+        end",
+    );
+    build_test!(&source, &[], &[], vec![smt]).expect_stack(&word_to_stack(empty_smt.root()));
+}
+
+#[test]
+fn smt_insert() {
+    let smt = build_smt();
+    let value = [Felt::new(7); 4];
+    let mut expected_smt = smt.clone();
+    expected_smt.update_leaf(EMPTY_KEY, value).unwrap();
+
+    let source = format!(
+        "
+        use.std::collections::smt
+
+        begin
+            {} push.{DEPTH} push.{EMPTY_KEY} {} exec.smt::insert
+        end",
+        push_word(smt.root()),
+        push_word(value)
+    );
+    build_test!(&source, &[], &[], vec![smt.clone()])
+        .expect_stack(&word_to_stack(expected_smt.root()));
+
+    // inserting a value under a key which has been set fails
+    let source = format!(
+        "
+        use.std::collections::smt
+
+        begin
+            {} push.{DEPTH} push.{} {} exec.smt::insert
+        end",
+        push_word(smt.root()),
+        KEYS[0],
+        push_word(value)
+    );
+    build_test!(&source, &[], &[], vec![smt])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn values() -> [Word; 3] {
+    [
+        [1, 2, 3, 4].map(Felt::new),
+        [5, 6, 7, 8].map(Felt::new),
+        [9, 10, 11, 12].map(Felt::new),
+    ]
+}
+
+/// Returns a sparse Merkle tree with values set for [KEYS].
+fn build_smt() -> AdviceSet {
+    AdviceSet::new_sparse_merkle_tree(KEYS.to_vec(), values().to_vec(), DEPTH).unwrap()
+}
@@ -1,5 +1,5 @@
 use super::{build_test, Felt, STACK_TOP_SIZE};
-use std::convert::TryInto;
+use std::{convert::TryInto, fmt::Write};
 use vm_core::utils::IntoBytes;
 
 #[test]
@@ -52,3 +52,78 @@ fn blake3_2_to_1_hash() {
     let test = build_test!(source, &i_words);
     test.expect_stack(&digest_words);
 }
+
+#[test]
+fn blake3_hash_memory() {
+    for len in [
+        0, 1, 4, 63, 64, 65, 1000, 1023, 1024, 1025, 2048, 3073, 4097,
+    ] {
+        let msg = (0..len)
+            .map(|_| rand_utils::rand_value::<u64>() as u8)
+            .collect::<Vec<_>>();
+
+        // the bytes past the end of the message are set to a non-zero value, as they are expected
+        // to be ignored
+        let mut padded_msg = msg.clone();
+        padded_msg.resize((len / 64 + 1) * 64, 0xff);
+
+        // store the message in memory, four blake3 words per memory word, starting at address 100
+        let mut source = String::from("use.std::crypto::hashes::blake3 begin");
+        for (i, word) in to_words(&padded_msg).chunks(4).enumerate() {
+            let addr = 100 + i;
+            write!(
+                source,
+                " push.{}.{}.{}.{} push.{addr} mem_storew dropw",
+                word[0], word[1], word[2], word[3]
+            )
+            .unwrap();
+        }
+        write!(source, " push.{len} push.100 exec.blake3::hash_memory end").unwrap();
+
+        let digest = blake3::hash(&msg);
+        build_test!(&source).expect_stack(&to_words(digest.as_bytes()));
+    }
+}
+
+#[test]
+fn blake3_merge() {
+    let source = "
+    use.std::crypto::hashes::blake3
+
+    begin
+        # merge the first two leaves and keep the result in memory
+        exec.blake3::merge
+        push.0 mem_storew dropw
+        push.1 mem_storew dropw
+
+        # merge the last two leaves, and then merge both subtrees
+        exec.blake3::merge
+        padw push.1 mem_loadw
+        padw push.0 mem_loadw
+        exec.blake3::merge
+    end
+    ";
+
+    // build a Merkle tree with four leaves
+    let leaves = (0..4)
+        .map(|_| rand_utils::rand_array::<Felt, 4>().into_bytes())
+        .collect::<Vec<[u8; 32]>>();
+    let merge = |a: &[u8], b: &[u8]| *blake3::hash(&[a, b].concat()).as_bytes();
+    let root = merge(
+        &merge(&leaves[0], &leaves[1]),
+        &merge(&leaves[2], &leaves[3]),
+    );
+
+    let mut in_stack = to_words(&leaves.concat());
+    in_stack.reverse();
+
+    build_test!(source, &in_stack).expect_stack(&to_words(&root));
+}
+
+/// Converts each four consecutive little endian bytes into a blake3 word.
+fn to_words(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()) as u64)
+        .collect()
+}
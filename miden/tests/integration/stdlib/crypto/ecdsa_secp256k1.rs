@@ -0,0 +1,190 @@
+use super::build_test;
+use crate::helpers::{ProgramOutputs, Test, TestError};
+use miden::{execute_fast, ExecutionError};
+use std::fmt::Write;
+
+// TEST VECTORS
+// ================================================================================================
+
+/// Ethereum-style ECDSA signature, i.e. a signature over keccak256 digest of the message prefixed
+/// with "\x19Ethereum Signed Message:\n" and the message length.
+struct Signature {
+    hash: &'static str,
+    r: &'static str,
+    s: &'static str,
+    v: u64,
+    pk_x: &'static str,
+    pk_y: &'static str,
+}
+
+/// Signature of "Some data" from web3.js documentation, signed by
+/// 0x2c7536E3605D9C16a7a3D7b1898e529396a65c23.
+const SIG_SOME_DATA: Signature = Signature {
+    hash: "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655",
+    r: "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd",
+    s: "6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029",
+    v: 1,
+    pk_x: "4e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e",
+    pk_y: "47fd35c4215d1edf53e6f83de344615ce719bdb0fd878f6ed76f06dd277956de",
+};
+
+/// Signature of "miden", signed by 0x71562b71999873DB5b286dF957af199Ec94617F7.
+const SIG_MIDEN: Signature = Signature {
+    hash: "19c2bce6f398448840071224e30b7d17255f1ccfa1f81789f4dfce73ab7197b1",
+    r: "b46de1e8ba1fb9af581cf9a37104a3e5cee013f8f762665787fa01e5dd2d8623",
+    s: "3e2f877f159572d86baf09dde97cace407641ffe9ed249e71c0b6282faba04fb",
+    v: 0,
+    pk_x: "ca634cae0d49acb401d8a4c6b6fe8c55b70d115bf400769cc1400f3258cd3138",
+    pk_y: "7574077f301b421bc84df7266c44e9e6d569fc56be00812904767bf5ccd1fc7f",
+};
+
+// memory addresses of the inputs
+const PK_ADDR: u32 = 1000;
+const MSG_ADDR: u32 = 1004;
+const SIG_ADDR: u32 = 1006;
+
+// TESTS
+// ================================================================================================
+
+// verification executes two elliptic curve point multiplications (about 11M cycles); generating
+// the execution trace for this many cycles requires more memory than is available to the tests,
+// and thus, the programs are executed without generating a trace. The tests which also generate
+// the trace are ignored by default, similarly to the point multiplication tests of secp256k1.
+
+#[test]
+fn ecdsa_secp256k1_verify_fast() {
+    let source = verify_source(SIG_SOME_DATA.hash, &SIG_SOME_DATA);
+    let test = build_test!(&source);
+    execute_without_trace(&test).unwrap();
+}
+
+#[test]
+fn ecdsa_secp256k1_verify_wrong_message_fast() {
+    // signature of a different message must be rejected
+    let source = verify_source(SIG_MIDEN.hash, &SIG_SOME_DATA);
+    let test = build_test!(&source);
+    let err = execute_without_trace(&test).unwrap_err();
+    assert!(
+        matches!(err, ExecutionError::FailedAssertion(_)),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn ecdsa_secp256k1_recover_fast() {
+    let (test, expected) = recover_test(&SIG_MIDEN);
+    let outputs = execute_without_trace(&test).unwrap();
+    assert_eq!(&expected[..], &outputs.stack()[..expected.len()]);
+}
+
+#[test]
+#[ignore]
+fn ecdsa_secp256k1_verify() {
+    let source = verify_source(SIG_SOME_DATA.hash, &SIG_SOME_DATA);
+    let test = build_test!(&source);
+    test.execute().unwrap();
+}
+
+#[test]
+#[ignore]
+fn ecdsa_secp256k1_verify_wrong_message() {
+    // signature of a different message must be rejected
+    let source = verify_source(SIG_MIDEN.hash, &SIG_SOME_DATA);
+    let test = build_test!(&source);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+#[ignore]
+fn ecdsa_secp256k1_recover() {
+    let (test, expected) = recover_test(&SIG_MIDEN);
+    test.expect_stack(&expected);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the test without generating an execution trace.
+fn execute_without_trace(test: &Test) -> Result<ProgramOutputs, ExecutionError> {
+    execute_fast(&test.compile(), &test.inputs)
+}
+
+/// Returns a test which recovers the public key from the provided signature and leaves it on the
+/// stack, together with the expected stack (the x and y coordinates of the public key, the least
+/// significant limb first).
+fn recover_test(sig: &Signature) -> (Test, Vec<u64>) {
+    let mut source = String::from("use.std::crypto::dsa::ecdsa_secp256k1 begin");
+    store_u256(&mut source, MSG_ADDR, sig.hash);
+    store_u256(&mut source, SIG_ADDR, sig.r);
+    store_u256(&mut source, SIG_ADDR + 2, sig.s);
+    write!(
+        source,
+        " push.{PK_ADDR} push.{SIG_ADDR} push.{MSG_ADDR} push.{} exec.ecdsa_secp256k1::recover",
+        sig.v
+    )
+    .unwrap();
+
+    // read the recovered public key from memory
+    for addr in (PK_ADDR..PK_ADDR + 4).rev() {
+        write!(source, " padw push.{addr} mem_loadw").unwrap();
+    }
+    source.push_str(" end");
+
+    // public key is provided via advice tape, the most significant limb first
+    let mut tape = to_limbs(sig.pk_x);
+    tape.reverse();
+    let mut pk_y = to_limbs(sig.pk_y);
+    pk_y.reverse();
+    tape.extend_from_slice(&pk_y);
+
+    let mut expected = to_limbs(sig.pk_x);
+    expected.extend_from_slice(&to_limbs(sig.pk_y));
+
+    (build_test!(&source, &[], &tape, vec![]), expected)
+}
+
+/// Returns a program which stores the public key, message hash and signature in memory and
+/// verifies the signature.
+fn verify_source(hash: &str, sig: &Signature) -> String {
+    let mut source = String::from("use.std::crypto::dsa::ecdsa_secp256k1 begin");
+    store_u256(&mut source, PK_ADDR, sig.pk_x);
+    store_u256(&mut source, PK_ADDR + 2, sig.pk_y);
+    store_u256(&mut source, MSG_ADDR, hash);
+    store_u256(&mut source, SIG_ADDR, sig.r);
+    store_u256(&mut source, SIG_ADDR + 2, sig.s);
+    write!(
+        source,
+        " push.{SIG_ADDR} push.{MSG_ADDR} push.{PK_ADDR} exec.ecdsa_secp256k1::verify end"
+    )
+    .unwrap();
+    source
+}
+
+/// Appends instructions which store the provided 256 -bit number in two consecutive memory
+/// addresses, starting at `addr`.
+fn store_u256(source: &mut String, addr: u32, value: &str) {
+    let limbs = to_limbs(value);
+    for (i, word) in limbs.chunks(4).enumerate() {
+        write!(
+            source,
+            " push.{}.{}.{}.{} push.{} mem_storew dropw",
+            word[3],
+            word[2],
+            word[1],
+            word[0],
+            addr + i as u32
+        )
+        .unwrap();
+    }
+}
+
+/// Converts a big endian hex encoded 256 -bit number into eight 32 -bit limbs, the least
+/// significant limb first.
+fn to_limbs(value: &str) -> Vec<u64> {
+    assert_eq!(64, value.len());
+    (0..8)
+        .rev()
+        .map(|i| u64::from_str_radix(&value[i * 8..(i + 1) * 8], 16).unwrap())
+        .collect()
+}
@@ -1,5 +1,6 @@
 use super::{build_test, Felt, STACK_TOP_SIZE};
 use sha3::{Digest, Keccak256};
+use std::{convert::TryInto, fmt::Write};
 use vm_core::utils::IntoBytes;
 
 /// Equivalent to https://github.com/itzmeanjan/merklize-sha/blob/1d35aae/include/test_bit_interleaving.hpp#L12-L34
@@ -67,6 +68,86 @@ fn keccak256_2_to_1_hash() {
     test.expect_stack(&expected_stack);
 }
 
+#[test]
+fn keccak256_hash_memory() {
+    for len in [0, 1, 7, 8, 63, 64, 135, 136, 137, 271, 272, 300] {
+        let msg = (0..len)
+            .map(|_| rand_utils::rand_value::<u64>() as u8)
+            .collect::<Vec<_>>();
+
+        // pack message bytes into little endian 32 -bit words; the bytes past the end of the
+        // message are set to a non-zero value, as they are expected to be ignored
+        let mut padded_msg = msg.clone();
+        padded_msg.resize(((len / 136 + 1) * 136 + 15) / 16 * 16, 0xff);
+        let msg_words = padded_msg
+            .chunks(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        // store the message in memory, four 32 -bit words per memory word, starting at address 100
+        let mut source = String::from("use.std::crypto::hashes::keccak256 begin");
+        for (i, word) in msg_words.chunks(4).enumerate() {
+            let addr = 100 + i;
+            write!(
+                source,
+                " push.{}.{}.{}.{} push.{addr} mem_storew dropw",
+                word[0], word[1], word[2], word[3]
+            )
+            .unwrap();
+        }
+        write!(
+            source,
+            " push.{len} push.100 exec.keccak256::hash_memory end"
+        )
+        .unwrap();
+
+        let digest = Keccak256::digest(&msg);
+        let mut expected_stack = [0u64; STACK_TOP_SIZE >> 1];
+        to_stack(&digest, &mut expected_stack);
+
+        build_test!(&source).expect_stack(&expected_stack);
+    }
+}
+
+#[test]
+fn keccak256_merge() {
+    let source = "
+    use.std::crypto::hashes::keccak256
+
+    begin
+        # merge the first two leaves and keep the result in memory
+        exec.keccak256::merge
+        push.0 mem_storew dropw
+        push.1 mem_storew dropw
+
+        # merge the last two leaves, and then merge both subtrees
+        exec.keccak256::merge
+        padw push.1 mem_loadw
+        padw push.0 mem_loadw
+        exec.keccak256::merge
+    end
+    ";
+
+    // build a Merkle tree with four leaves
+    let leaves = (0..4)
+        .map(|_| rand_utils::rand_array::<Felt, 4>().into_bytes())
+        .collect::<Vec<[u8; 32]>>();
+    let merge = |a: &[u8], b: &[u8]| Keccak256::digest([a, b].concat());
+    let root = merge(
+        &merge(&leaves[0], &leaves[1]),
+        &merge(&leaves[2], &leaves[3]),
+    );
+
+    let mut expected_stack = [0u64; STACK_TOP_SIZE >> 1];
+    to_stack(&root, &mut expected_stack);
+
+    let mut in_stack = [0u64; STACK_TOP_SIZE << 1];
+    to_stack(&leaves.concat(), &mut in_stack);
+    in_stack.reverse();
+
+    build_test!(source, &in_stack).expect_stack(&expected_stack);
+}
+
 /// Given N -many bytes ( such that N % 8 == 0 ), this function considers
 /// each block of contiguous 8 -bytes as little endian 64 -bit unsigned
 /// integer word and converts each u64 into two u32s such that first one holds
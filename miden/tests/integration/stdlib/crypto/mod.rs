@@ -2,6 +2,8 @@ use super::build_test;
 use crate::helpers::{Felt, STACK_TOP_SIZE};
 
 mod blake3;
+mod ecdsa_secp256k1;
 mod falcon;
 mod keccak256;
+mod schnorr_ecgfp5;
 mod sha256;
@@ -0,0 +1,94 @@
+use super::{build_test, Felt};
+use crate::helpers::TestError;
+use num_bigint::BigUint;
+use rand_utils::rand_array;
+use stdlib::dsa::schnorr_ecgfp5::{PublicKey, SecretKey, Signature};
+use vm_core::{StarkField, Word};
+
+/// Order of the prime-order group of ecGFp5.
+const ORDER: &[u8] = b"1067993516717146951041484916571792702745057740581727230159139685185762082554198619328292418486241";
+
+const SOURCE: &str = "
+    use.std::crypto::dsa::schnorr_ecgfp5
+
+    begin
+        exec.schnorr_ecgfp5::verify
+    end";
+
+#[test]
+fn schnorr_ecgfp5_verify() {
+    let secret_key = SecretKey::from_seed(rand_array());
+    let message: Word = rand_array();
+    let signature = secret_key.sign(message);
+
+    let (stack, tape) = to_inputs(&secret_key.public_key(), message, &signature);
+    let test = build_test!(SOURCE, &stack, &tape, vec![]);
+    test.expect_stack(&[]);
+}
+
+#[test]
+fn schnorr_ecgfp5_verify_wrong_message() {
+    let secret_key = SecretKey::from_seed(rand_array());
+    let message: Word = rand_array();
+    let signature = secret_key.sign(message);
+
+    // signature of a different message must be rejected
+    let mut other_message = message;
+    other_message[0] += Felt::new(1);
+
+    let (stack, tape) = to_inputs(&secret_key.public_key(), other_message, &signature);
+    let test = build_test!(SOURCE, &stack, &tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn schnorr_ecgfp5_verify_wrong_key() {
+    let secret_key = SecretKey::from_seed(rand_array());
+    let message: Word = rand_array();
+    let signature = secret_key.sign(message);
+
+    // signature must be rejected for a different public key
+    let other_key = SecretKey::from_seed(rand_array()).public_key();
+
+    let (stack, tape) = to_inputs(&other_key, message, &signature);
+    let test = build_test!(SOURCE, &stack, &tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn schnorr_ecgfp5_verify_non_canonical_scalar() {
+    let secret_key = SecretKey::from_seed(rand_array());
+    let message: Word = rand_array();
+    let signature = secret_key.sign(message);
+    let (stack, mut tape) = to_inputs(&secret_key.public_key(), message, &signature);
+
+    // replace s with s + n; this is the same scalar modulo n, but it must be rejected
+    let s = BigUint::from_slice(
+        &tape[5..]
+            .iter()
+            .rev()
+            .map(|&limb| limb as u32)
+            .collect::<Vec<_>>(),
+    );
+    let mut limbs = (s + BigUint::parse_bytes(ORDER, 10).unwrap()).to_u32_digits();
+    limbs.resize(10, 0);
+    tape.truncate(5);
+    tape.extend(limbs.iter().rev().map(|&limb| limb as u64));
+
+    let test = build_test!(SOURCE, &stack, &tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns stack inputs and advice tape for verifying the signature of the message with the
+/// provided public key.
+fn to_inputs(public_key: &PublicKey, message: Word, signature: &Signature) -> (Vec<u64>, Vec<u64>) {
+    // the message is followed by the public key, such that pk0 ends up at the top of the stack
+    let mut stack = message.iter().map(|e| e.as_int()).collect::<Vec<_>>();
+    stack.extend(public_key.to_elements().iter().rev().map(|e| e.as_int()));
+
+    let tape = signature.to_elements().iter().map(|e| e.as_int()).collect();
+    (stack, tape)
+}
@@ -1,6 +1,6 @@
 use super::{build_test, Felt, STACK_TOP_SIZE};
 use sha2::{Digest, Sha256};
-use std::convert::TryInto;
+use std::{convert::TryInto, fmt::Write};
 use vm_core::utils::IntoBytes;
 
 #[test]
@@ -52,3 +52,42 @@ fn sha256_2_to_1_hash() {
     let test = build_test!(source, &i_words);
     test.expect_stack(&digest_words);
 }
+
+#[test]
+fn sha256_hash_memory() {
+    for len in [0, 1, 3, 4, 55, 56, 63, 64, 65, 100, 119, 120, 128, 200] {
+        let msg = (0..len)
+            .map(|_| rand_utils::rand_value::<u64>() as u8)
+            .collect::<Vec<_>>();
+
+        // pack message bytes into big endian sha256 words; the bytes past the end of the message
+        // are set to a non-zero value, as they are expected to be ignored
+        let mut padded_msg = msg.clone();
+        padded_msg.resize((len / 64 + 1) * 64, 0xff);
+        let msg_words = padded_msg
+            .chunks(4)
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        // store the message in memory, four sha256 words per memory word, starting at address 100
+        let mut source = String::from("use.std::crypto::hashes::sha256 begin");
+        for (i, word) in msg_words.chunks(4).enumerate() {
+            let addr = 100 + i;
+            write!(
+                source,
+                " push.{}.{}.{}.{} push.{addr} mem_storew dropw",
+                word[0], word[1], word[2], word[3]
+            )
+            .unwrap();
+        }
+        write!(source, " push.{len} push.100 exec.sha256::hash_memory end").unwrap();
+
+        let digest = Sha256::digest(&msg);
+        let digest_words = digest
+            .chunks(4)
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()) as u64)
+            .collect::<Vec<_>>();
+
+        build_test!(&source).expect_stack(&digest_words);
+    }
+}
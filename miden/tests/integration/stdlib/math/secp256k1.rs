@@ -1,4 +1,5 @@
 use super::{build_test, Felt};
+use num_bigint::BigUint;
 use test_case::test_case;
 
 #[test]
@@ -1071,6 +1072,150 @@ fn test_secp256k1_generator_multiplication(scalar: FieldElement, point: Point) {
     test.execute().unwrap();
 }
 
+#[test]
+fn test_u256_mod_reduce() {
+    let source = "
+    use.std::math::secp256k1
+
+    begin
+        exec.secp256k1::u256_mod_reduce
+    end";
+
+    let prime = secp256k1_prime();
+    let max = (BigUint::from(1u32) << 256u32) - 1u32;
+    for a in [
+        rand_u256(),
+        prime.clone(),
+        &prime - 1u32,
+        &prime + 5u32,
+        max,
+    ] {
+        let test = build_test!(source, &to_stack(&[&a]));
+        test.expect_stack(&to_limbs(&(a % &prime)));
+    }
+}
+
+#[test]
+fn test_u256_mod_inv() {
+    let source = "
+    use.std::math::secp256k1
+
+    begin
+        exec.secp256k1::to_mont
+        exec.secp256k1::u256_mod_inv
+        exec.secp256k1::from_mont
+        exec.secp256k1::u256_mod_reduce
+    end";
+
+    let prime = secp256k1_prime();
+    let a = rand_u256() % &prime;
+    let expected = a.modpow(&(&prime - 2u32), &prime);
+
+    let test = build_test!(source, &to_stack(&[&a]));
+    test.expect_stack(&to_limbs(&expected));
+}
+
+#[test]
+fn test_scalar_reduce() {
+    let source = "
+    use.std::math::secp256k1
+
+    begin
+        exec.secp256k1::scalar_reduce
+    end";
+
+    let order = secp256k1_order();
+    for a in [rand_u256(), order.clone(), &order - 1u32, &order + 5u32] {
+        let test = build_test!(source, &to_stack(&[&a]));
+        test.expect_stack(&to_limbs(&(a % &order)));
+    }
+}
+
+#[test]
+fn test_scalar_mod_mul() {
+    // multiplying a number in Montgomery form with a number in radix-2^32 form produces result
+    // in radix-2^32 form
+    let source = "
+    use.std::math::secp256k1
+
+    begin
+        exec.secp256k1::scalar_to_mont
+        exec.secp256k1::scalar_mod_mul
+    end";
+
+    let order = secp256k1_order();
+    for (a, b) in [
+        (rand_u256() % &order, rand_u256() % &order),
+        (&order - 1u32, &order - 1u32),
+        (BigUint::from(0u32), &order - 1u32),
+    ] {
+        let expected = (&a * &b) % &order;
+
+        let test = build_test!(source, &to_stack(&[&a, &b]));
+        test.expect_stack(&to_limbs(&expected));
+    }
+}
+
+#[test]
+fn test_scalar_mod_inv() {
+    let source = "
+    use.std::math::secp256k1
+
+    begin
+        exec.secp256k1::scalar_to_mont
+        exec.secp256k1::scalar_mod_inv
+        exec.secp256k1::scalar_from_mont
+    end";
+
+    let order = secp256k1_order();
+    let a = rand_u256() % &order;
+    let expected = a.modpow(&(&order - 2u32), &order);
+
+    let test = build_test!(source, &to_stack(&[&a]));
+    test.expect_stack(&to_limbs(&expected));
+}
+
+fn secp256k1_prime() -> BigUint {
+    (BigUint::from(1u32) << 256u32) - (BigUint::from(1u32) << 32u32) - 977u32
+}
+
+fn secp256k1_order() -> BigUint {
+    BigUint::parse_bytes(
+        b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        16,
+    )
+    .unwrap()
+}
+
+fn rand_u256() -> BigUint {
+    let limbs = (0..8)
+        .map(|_| rand_utils::rand_value::<u32>())
+        .collect::<Vec<_>>();
+    BigUint::from_slice(&limbs)
+}
+
+/// Returns eight 32 -bit limbs of a 256 -bit number, least significant limb first.
+fn to_limbs(a: &BigUint) -> Vec<u64> {
+    let mut limbs = a
+        .to_u32_digits()
+        .iter()
+        .map(|&limb| limb as u64)
+        .collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns stack inputs s.t. the provided 256 -bit numbers are placed on stack top in order, each
+/// number having its least significant limb on top.
+fn to_stack(values: &[&BigUint]) -> Vec<u64> {
+    let mut stack = values
+        .iter()
+        .flat_map(|value| to_limbs(value))
+        .collect::<Vec<_>>();
+    stack.reverse();
+    stack
+}
+
 fn mac(a: u32, b: u32, c: u32, carry: u32) -> (u32, u32) {
     let tmp = a as u64 + (b as u64 * c as u64) + carry as u64;
     ((tmp >> 32) as u32, tmp as u32)
@@ -1,6 +1,250 @@
-use super::build_test;
+use super::{build_test, TestError};
+use crate::helpers::U32_BOUND;
 use num_bigint::BigUint;
-use rand_utils::rand_vector;
+use rand_utils::{rand_value, rand_vector};
+
+// ADDITION
+// ================================================================================================
+
+#[test]
+fn overflowing_add() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_add
+        end";
+
+    // random values; the sum overflows with probability 1/2
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = &a + &b;
+    let overflow = (c >= two_pow_256()) as u64;
+
+    let mut expected = vec![overflow];
+    expected.extend(to_stack(&(c % two_pow_256())));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    // (2^256 - 1) + 1 = 0 with overflow
+    let max = two_pow_256() - 1u32;
+    let mut expected = vec![1];
+    expected.extend([0; 8]);
+    build_test!(source, &operands(&max, &BigUint::from(1u32))).expect_stack(&expected);
+}
+
+#[test]
+fn add_unsafe() {
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = (&a + &b) % two_pow_256();
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::add_unsafe
+        end";
+
+    build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&c));
+}
+
+#[test]
+fn checked_add() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_add
+        end";
+
+    // the values are smaller than 2^255, and thus, the sum does not overflow
+    let a = rand_u256() >> 1;
+    let b = rand_u256() >> 1;
+    let c = &a + &b;
+    build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&c));
+
+    // fails on overflow
+    let max = two_pow_256() - 1u32;
+    let test = build_test!(source, &operands(&max, &BigUint::from(1u32)));
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // fails if any of the limbs is not a u32 value
+    let mut stack_init = operands(&a, &b);
+    stack_init[11] = U32_BOUND;
+    let test = build_test!(source, &stack_init);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// SUBTRACTION
+// ================================================================================================
+
+#[test]
+fn overflowing_sub() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_sub
+        end";
+
+    // random values; the difference underflows with probability 1/2
+    let a = rand_u256();
+    let b = rand_u256();
+    let underflow = (a < b) as u64;
+    let c = (two_pow_256() + &a - &b) % two_pow_256();
+
+    let mut expected = vec![underflow];
+    expected.extend(to_stack(&c));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    // 0 - 1 = 2^256 - 1 with underflow; the borrow must propagate through all limbs
+    let max = two_pow_256() - 1u32;
+    let mut expected = vec![1];
+    expected.extend(to_stack(&max));
+    let test = build_test!(
+        source,
+        &operands(&BigUint::from(0u32), &BigUint::from(1u32))
+    );
+    test.expect_stack(&expected);
+
+    // 2^224 - (2^256 - 1) = 2^224 + 1 with underflow
+    let a = BigUint::from(1u32) << 224;
+    let mut expected = vec![1];
+    expected.extend(to_stack(&(&a + 1u32)));
+    build_test!(source, &operands(&a, &max)).expect_stack(&expected);
+}
+
+#[test]
+fn sub_unsafe() {
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = (two_pow_256() + &a - &b) % two_pow_256();
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::sub_unsafe
+        end";
+
+    build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&c));
+}
+
+#[test]
+fn checked_sub() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_sub
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let (a, b) = if a >= b { (a, b) } else { (b, a) };
+    let c = &a - &b;
+    build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&c));
+
+    // fails on underflow
+    let test = build_test!(source, &operands(&b, &(&a + 1u32)));
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// SHIFTS
+// ================================================================================================
+
+#[test]
+fn shl() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::shl
+        end";
+
+    let a = rand_u256();
+    for n in [0, 1, 31, 32, 33, 100, 224, 255, rand_value::<u64>() % 256] {
+        let c = (&a << n) % two_pow_256();
+
+        let mut stack_init = to_limbs(&a);
+        stack_init.push(n);
+        build_test!(source, &stack_init).expect_stack(&to_stack(&c));
+    }
+
+    // fails if the shift is too big
+    let mut stack_init = to_limbs(&a);
+    stack_init.push(256);
+    let test = build_test!(source, &stack_init);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn shr() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::shr
+        end";
+
+    let a = rand_u256();
+    for n in [0, 1, 31, 32, 33, 100, 224, 255, rand_value::<u64>() % 256] {
+        let c = &a >> n;
+
+        let mut stack_init = to_limbs(&a);
+        stack_init.push(n);
+        build_test!(source, &stack_init).expect_stack(&to_stack(&c));
+    }
+
+    // fails if the shift is too big
+    let mut stack_init = to_limbs(&a);
+    stack_init.push(256);
+    let test = build_test!(source, &stack_init);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // fails if any of the limbs is not a u32 value
+    let mut stack_init = to_limbs(&a);
+    stack_init[3] = U32_BOUND;
+    stack_init.push(1);
+    let test = build_test!(source, &stack_init);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn comparisons() {
+    let a = rand_u256();
+    let b = rand_u256();
+
+    // a pair of values which differ only in the least significant limb
+    let c = &a ^ BigUint::from(1u32);
+
+    for (x, y) in [(&a, &b), (&b, &a), (&a, &a), (&a, &c), (&c, &a)] {
+        for (op, expected) in [
+            ("lt", x < y),
+            ("gt", x > y),
+            ("lte", x <= y),
+            ("gte", x >= y),
+        ] {
+            let source = format!(
+                "
+                use.std::math::u256
+                begin
+                    exec.u256::{op}
+                end"
+            );
+            build_test!(&source, &operands(x, y)).expect_stack(&[expected as u64]);
+        }
+    }
+}
+
+#[test]
+fn lt_fail() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::lt
+        end";
+
+    let mut stack_init = operands(&rand_u256(), &rand_u256());
+    stack_init[0] = U32_BOUND;
+    let test = build_test!(source, &stack_init);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
 
 // MULTIPLICATION
 // ================================================================================================
@@ -33,6 +277,87 @@ fn mul_unsafe() {
     build_test!(source, &operands).expect_stack(&result);
 }
 
+// DIVISION
+// ================================================================================================
+
+#[test]
+fn divmod() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::divmod
+        end";
+
+    let a = rand_u256();
+    let max = two_pow_256() - 1u32;
+    let divisors = [
+        rand_u256(),
+        rand_u256() >> (rand_value::<u64>() % 256),
+        a.clone(),
+        BigUint::from(1u32),
+        BigUint::from(rand_value::<u32>() | 1),
+        max.clone(),
+    ];
+
+    for b in divisors.iter() {
+        for a in [&a, &max, b] {
+            let mut expected = to_stack(&(a % b));
+            expected.extend(to_stack(&(a / b)));
+            build_test!(source, &operands(a, b)).expect_stack(&expected);
+        }
+    }
+}
+
+#[test]
+fn div() {
+    let a = rand_u256();
+    let b = rand_u256() >> 128;
+    let c = &a / &b;
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div
+        end";
+
+    build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&c));
+}
+
+#[test]
+fn modulo() {
+    let a = rand_u256();
+    let b = rand_u256() >> 128;
+    let c = &a % &b;
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::mod
+        end";
+
+    build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&c));
+}
+
+#[test]
+fn divmod_fail() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::divmod
+        end";
+
+    // division by zero
+    let a = rand_u256();
+    let test = build_test!(source, &operands(&a, &BigUint::from(0u32)));
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+
+    // fails if any of the limbs is not a u32 value
+    let mut stack_init = operands(&a, &rand_u256());
+    stack_init[5] = U32_BOUND;
+    let test = build_test!(source, &stack_init);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -43,3 +368,33 @@ fn rand_u256() -> BigUint {
         .collect::<Vec<_>>();
     BigUint::new(limbs)
 }
+
+fn two_pow_256() -> BigUint {
+    BigUint::from(1u32) << 256
+}
+
+/// Returns eight 32-bit limbs of the value, the least significant limb first.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value
+        .to_u32_digits()
+        .iter()
+        .map(|&v| v as u64)
+        .collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns the limbs of the value in the order in which they are expected on the stack, i.e.,
+/// the most significant limb first.
+fn to_stack(value: &BigUint) -> Vec<u64> {
+    let mut limbs = to_limbs(value);
+    limbs.reverse();
+    limbs
+}
+
+/// Returns stack inputs which put b on top of a.
+fn operands(a: &BigUint, b: &BigUint) -> Vec<u64> {
+    let mut operands = to_limbs(a);
+    operands.extend(to_limbs(b));
+    operands
+}
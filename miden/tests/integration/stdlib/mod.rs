@@ -1,5 +1,6 @@
 use crate::build_test;
 
+mod collections;
 mod crypto;
 mod math;
 mod sys;
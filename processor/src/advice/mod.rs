@@ -19,6 +19,7 @@ use vm_core::{
 ///    trees and can be used to provide Merkle paths.
 ///
 /// An advice provider can be instantiated from [ProgramInputs].
+#[derive(Clone)]
 pub struct AdviceProvider {
     step: u32,
     tape: Vec<Felt>,
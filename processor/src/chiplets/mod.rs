@@ -2,7 +2,7 @@ use super::{
     BTreeMap, ChipletsTrace, Felt, FieldElement, RangeChecker, StarkField, TraceFragment, Vec,
     Word, CHIPLETS_WIDTH, ONE, ZERO,
 };
-use crate::{trace::LookupTableRow, ExecutionError};
+use crate::{trace::LookupTableRow, ChipletOps, ExecutionError};
 use vm_core::{
     chiplets::bitwise::{BITWISE_AND_LABEL, BITWISE_XOR_LABEL},
     chiplets::{
@@ -14,6 +14,7 @@ use vm_core::{
 };
 
 mod bitwise;
+pub use bitwise::assert_u32;
 use bitwise::{Bitwise, BitwiseLookup};
 
 mod hasher;
@@ -134,78 +135,6 @@ impl Chiplets {
         self.kernel_rom_start() + self.kernel_rom.trace_len()
     }
 
-    // HASH CHIPLET ACCESSORS FOR OPERATIONS
-    // --------------------------------------------------------------------------------------------
-
-    /// Requests a single permutation of the hash function to the provided state from the Hash
-    /// chiplet.
-    ///
-    /// The returned tuple contains the hasher state after the permutation and the row address of
-    /// the execution trace at which the permutation started.
-    pub fn permute(&mut self, state: HasherState) -> (Felt, HasherState) {
-        let mut lookups = Vec::new();
-        let (addr, return_state) = self.hasher.permute(state, &mut lookups);
-        self.bus.request_hasher_operation(&lookups, self.clk);
-
-        // provide the responses to the bus
-        self.bus.provide_hasher_lookups(&lookups);
-
-        (addr, return_state)
-    }
-
-    /// Requests a Merkle root computation from the Hash chiplet for the specified path and the node
-    /// with the specified value.
-    ///
-    /// The returned tuple contains the root of the Merkle path and the row address of the
-    /// execution trace at which the computation started.
-    ///
-    /// # Panics
-    /// Panics if:
-    /// - The provided path does not contain any nodes.
-    /// - The provided index is out of range for the specified path.
-    pub fn build_merkle_root(&mut self, value: Word, path: &[Word], index: Felt) -> (Felt, Word) {
-        let mut lookups = Vec::new();
-        let (addr, root) = self
-            .hasher
-            .build_merkle_root(value, path, index, &mut lookups);
-
-        self.bus.request_hasher_operation(&lookups, self.clk);
-
-        // provide the responses to the bus
-        self.bus.provide_hasher_lookups(&lookups);
-
-        (addr, root)
-    }
-
-    /// Requests a Merkle root update computation from the Hash chiplet.
-    ///
-    /// The returned tuple contains computed roots for the old value and the new value of the node
-    /// with the specified path, as well as the row address of the execution trace at which the
-    /// computation started.
-    ///
-    /// # Panics
-    /// Panics if:
-    /// - The provided path does not contain any nodes.
-    /// - The provided index is out of range for the specified path.
-    pub fn update_merkle_root(
-        &mut self,
-        old_value: Word,
-        new_value: Word,
-        path: &[Word],
-        index: Felt,
-    ) -> (Felt, Word, Word) {
-        let mut lookups = Vec::new();
-        let (addr, old_root, new_root) =
-            self.hasher
-                .update_merkle_root(old_value, new_value, path, index, &mut lookups);
-        self.bus.request_hasher_operation(&lookups, self.clk);
-
-        // provide the responses to the bus
-        self.bus.provide_hasher_lookups(&lookups);
-
-        (addr, old_root, new_root)
-    }
-
     // HASH CHIPLET ACCESSORS FOR CONTROL BLOCK DECODING
     // --------------------------------------------------------------------------------------------
 
@@ -288,133 +217,9 @@ impl Chiplets {
         self.bus.send_queued_hasher_request(self.clk);
     }
 
-    // BITWISE CHIPLET ACCESSORS
-    // --------------------------------------------------------------------------------------------
-
-    /// Requests a bitwise AND of `a` and `b` from the Bitwise chiplet and returns the result.
-    /// We assume that `a` and `b` are 32-bit values. If that's not the case, the result of the
-    /// computation is undefined.
-    pub fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let result = self.bitwise.u32and(a, b)?;
-
-        let bitwise_lookup = BitwiseLookup::new(BITWISE_AND_LABEL, a, b, result);
-        self.bus.request_bitwise_operation(bitwise_lookup, self.clk);
-
-        Ok(result)
-    }
-
-    /// Requests a bitwise XOR of `a` and `b` from the Bitwise chiplet and returns the result.
-    /// We assume that `a` and `b` are 32-bit values. If that's not the case, the result of the
-    /// computation is undefined.
-    pub fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let result = self.bitwise.u32xor(a, b)?;
-
-        let bitwise_lookup = BitwiseLookup::new(BITWISE_XOR_LABEL, a, b, result);
-        self.bus.request_bitwise_operation(bitwise_lookup, self.clk);
-
-        Ok(result)
-    }
-
-    // MEMORY CHIPLET ACCESSORS
+    // MEMORY ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a word located in memory at the specified context/address while recording the
-    /// memory access in the memory trace.
-    ///
-    /// If the specified address hasn't been previously written to, four ZERO elements are
-    /// returned. This effectively implies that memory is initialized to ZERO.
-    pub fn read_mem(&mut self, ctx: u32, addr: Felt) -> Word {
-        // read the word from memory
-        let value = self.memory.read(ctx, addr, self.clk);
-
-        // send the memory read request to the bus
-        let lookup = MemoryLookup::from_ints(MEMORY_READ_LABEL, ctx, addr, self.clk, value);
-        self.bus.request_memory_operation(&[lookup], self.clk);
-
-        value
-    }
-
-    /// Returns two words read from consecutive addresses started with `addr` in the specified
-    /// context while recording memory accesses in the memory trace.
-    ///
-    /// If either of the accessed addresses hasn't been previously written to, ZERO elements are
-    /// returned. This effectively implies that memory is initialized to ZERO.
-    pub fn read_mem_double(&mut self, ctx: u32, addr: Felt) -> [Word; 2] {
-        // read two words from memory: from addr and from addr + 1
-        let addr2 = addr + ONE;
-        let words = [
-            self.memory.read(ctx, addr, self.clk),
-            self.memory.read(ctx, addr2, self.clk),
-        ];
-
-        // create lookups for both memory reads
-        let lookups = [
-            MemoryLookup::from_ints(MEMORY_READ_LABEL, ctx, addr, self.clk, words[0]),
-            MemoryLookup::from_ints(MEMORY_READ_LABEL, ctx, addr2, self.clk, words[1]),
-        ];
-
-        // send lookups to the bus and return the result
-        self.bus.request_memory_operation(&lookups, self.clk);
-        words
-    }
-
-    /// Writes the provided word at the specified context/address.
-    ///
-    /// This also modifies the memory access trace and sends a memory lookup request to the bus.
-    pub fn write_mem(&mut self, ctx: u32, addr: Felt, word: Word) {
-        self.memory.write(ctx, addr, self.clk, word);
-
-        // send the memory write request to the bus
-        let lookup = MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr, self.clk, word);
-        self.bus.request_memory_operation(&[lookup], self.clk);
-    }
-
-    /// Writes the provided element into the specified context/address leaving the remaining 3
-    /// elements of the word previously stored at that address unchanged.
-    ///
-    /// This also modifies the memory access trace and sends a memory lookup request to the bus.
-    pub fn write_mem_element(&mut self, ctx: u32, addr: Felt, value: Felt) -> Word {
-        let old_word = self.memory.get_old_value(ctx, addr.as_int());
-        let new_word = [value, old_word[1], old_word[2], old_word[3]];
-
-        self.memory.write(ctx, addr, self.clk, new_word);
-
-        // send the memory write request to the bus
-        let lookup = MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr, self.clk, new_word);
-        self.bus.request_memory_operation(&[lookup], self.clk);
-
-        old_word
-    }
-
-    /// Writes the two provided words to two consecutive addresses in memory in the specified
-    /// context, starting at the specified address.
-    ///
-    /// This also modifies the memory access trace and sends two memory lookup requests to the bus.
-    pub fn write_mem_double(&mut self, ctx: u32, addr: Felt, words: [Word; 2]) {
-        let addr2 = addr + ONE;
-        // write two words to memory at addr and addr + 1
-        self.memory.write(ctx, addr, self.clk, words[0]);
-        self.memory.write(ctx, addr2, self.clk, words[1]);
-
-        // create lookups for both memory writes
-        let lookups = [
-            MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr, self.clk, words[0]),
-            MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr2, self.clk, words[1]),
-        ];
-
-        // send lookups to the bus
-        self.bus.request_memory_operation(&lookups, self.clk);
-    }
-
-    /// Returns a word located at the specified context/address, or None if the address hasn't
-    /// been accessed previously.
-    ///
-    /// Unlike mem_read() which modifies the memory access trace, this method returns the value at
-    /// the specified address (if one exists) without altering the memory access trace.
-    pub fn get_mem_value(&self, ctx: u32, addr: u64) -> Option<Word> {
-        self.memory.get_value(ctx, addr)
-    }
-
     /// Returns the entire memory state for the specified execution context at the specified cycle.
     /// The state is returned as a vector of (address, value) tuples, and includes addresses which
     /// have been accessed at least once.
@@ -558,13 +363,238 @@ impl Chiplets {
             }
         }
 
-        // fill the fragments with the execution trace from each chiplet
-        // TODO: this can be parallelized to fill the traces in multiple threads
-        let hasher_aux_builder = hasher.fill_trace(&mut hasher_fragment);
-        bitwise.fill_trace(&mut bitwise_fragment, &mut bus, bitwise_start);
-        memory.fill_trace(&mut memory_fragment, &mut bus, memory_start);
-        kernel_rom.fill_trace(&mut kernel_rom_fragment);
+        // fill the fragments with the execution trace from each chiplet. the bitwise and memory
+        // chiplets both provide their lookups to the chiplets bus, and the order in which they
+        // do so matters; so, these two chiplets are always processed one after the other.
+        #[cfg(not(feature = "concurrent"))]
+        let hasher_aux_builder = {
+            let hasher_aux_builder = hasher.fill_trace(&mut hasher_fragment);
+            bitwise.fill_trace(&mut bitwise_fragment, &mut bus, bitwise_start);
+            memory.fill_trace(&mut memory_fragment, &mut bus, memory_start);
+            kernel_rom.fill_trace(&mut kernel_rom_fragment);
+            hasher_aux_builder
+        };
+
+        // when the concurrent feature is enabled, the hasher, kernel ROM, and bitwise + memory
+        // segments are filled in separate threads
+        #[cfg(feature = "concurrent")]
+        let (hasher_aux_builder, _) = rayon::join(
+            || hasher.fill_trace(&mut hasher_fragment),
+            || {
+                rayon::join(
+                    || {
+                        bitwise.fill_trace(&mut bitwise_fragment, &mut bus, bitwise_start);
+                        memory.fill_trace(&mut memory_fragment, &mut bus, memory_start);
+                    },
+                    || kernel_rom.fill_trace(&mut kernel_rom_fragment),
+                )
+            },
+        );
 
         (hasher_aux_builder, bus.into_aux_builder())
     }
 }
+
+// CHIPLET OPERATIONS
+// ================================================================================================
+
+impl ChipletOps for Chiplets {
+    // HASH CHIPLET ACCESSORS FOR OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Requests a single permutation of the hash function to the provided state from the Hash
+    /// chiplet.
+    ///
+    /// The returned tuple contains the hasher state after the permutation and the row address of
+    /// the execution trace at which the permutation started.
+    fn permute(&mut self, state: HasherState) -> (Felt, HasherState) {
+        let mut lookups = Vec::new();
+        let (addr, return_state) = self.hasher.permute(state, &mut lookups);
+        self.bus.request_hasher_operation(&lookups, self.clk);
+
+        // provide the responses to the bus
+        self.bus.provide_hasher_lookups(&lookups);
+
+        (addr, return_state)
+    }
+
+    /// Requests a Merkle root computation from the Hash chiplet for the specified path and the node
+    /// with the specified value.
+    ///
+    /// The returned tuple contains the root of the Merkle path and the row address of the
+    /// execution trace at which the computation started.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - The provided path does not contain any nodes.
+    /// - The provided index is out of range for the specified path.
+    fn build_merkle_root(&mut self, value: Word, path: &[Word], index: Felt) -> (Felt, Word) {
+        let mut lookups = Vec::new();
+        let (addr, root) = self
+            .hasher
+            .build_merkle_root(value, path, index, &mut lookups);
+
+        self.bus.request_hasher_operation(&lookups, self.clk);
+
+        // provide the responses to the bus
+        self.bus.provide_hasher_lookups(&lookups);
+
+        (addr, root)
+    }
+
+    /// Requests a Merkle root update computation from the Hash chiplet.
+    ///
+    /// The returned tuple contains computed roots for the old value and the new value of the node
+    /// with the specified path, as well as the row address of the execution trace at which the
+    /// computation started.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - The provided path does not contain any nodes.
+    /// - The provided index is out of range for the specified path.
+    fn update_merkle_root(
+        &mut self,
+        old_value: Word,
+        new_value: Word,
+        path: &[Word],
+        index: Felt,
+    ) -> (Felt, Word, Word) {
+        let mut lookups = Vec::new();
+        let (addr, old_root, new_root) =
+            self.hasher
+                .update_merkle_root(old_value, new_value, path, index, &mut lookups);
+        self.bus.request_hasher_operation(&lookups, self.clk);
+
+        // provide the responses to the bus
+        self.bus.provide_hasher_lookups(&lookups);
+
+        (addr, old_root, new_root)
+    }
+
+    // BITWISE CHIPLET ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Requests a bitwise AND of `a` and `b` from the Bitwise chiplet and returns the result.
+    /// We assume that `a` and `b` are 32-bit values. If that's not the case, the result of the
+    /// computation is undefined.
+    fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        let result = self.bitwise.u32and(a, b)?;
+
+        let bitwise_lookup = BitwiseLookup::new(BITWISE_AND_LABEL, a, b, result);
+        self.bus.request_bitwise_operation(bitwise_lookup, self.clk);
+
+        Ok(result)
+    }
+
+    /// Requests a bitwise XOR of `a` and `b` from the Bitwise chiplet and returns the result.
+    /// We assume that `a` and `b` are 32-bit values. If that's not the case, the result of the
+    /// computation is undefined.
+    fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        let result = self.bitwise.u32xor(a, b)?;
+
+        let bitwise_lookup = BitwiseLookup::new(BITWISE_XOR_LABEL, a, b, result);
+        self.bus.request_bitwise_operation(bitwise_lookup, self.clk);
+
+        Ok(result)
+    }
+
+    // MEMORY CHIPLET ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a word located in memory at the specified context/address while recording the
+    /// memory access in the memory trace.
+    ///
+    /// If the specified address hasn't been previously written to, four ZERO elements are
+    /// returned. This effectively implies that memory is initialized to ZERO.
+    fn read_mem(&mut self, ctx: u32, addr: Felt) -> Word {
+        // read the word from memory
+        let value = self.memory.read(ctx, addr, self.clk);
+
+        // send the memory read request to the bus
+        let lookup = MemoryLookup::from_ints(MEMORY_READ_LABEL, ctx, addr, self.clk, value);
+        self.bus.request_memory_operation(&[lookup], self.clk);
+
+        value
+    }
+
+    /// Returns two words read from consecutive addresses started with `addr` in the specified
+    /// context while recording memory accesses in the memory trace.
+    ///
+    /// If either of the accessed addresses hasn't been previously written to, ZERO elements are
+    /// returned. This effectively implies that memory is initialized to ZERO.
+    fn read_mem_double(&mut self, ctx: u32, addr: Felt) -> [Word; 2] {
+        // read two words from memory: from addr and from addr + 1
+        let addr2 = addr + ONE;
+        let words = [
+            self.memory.read(ctx, addr, self.clk),
+            self.memory.read(ctx, addr2, self.clk),
+        ];
+
+        // create lookups for both memory reads
+        let lookups = [
+            MemoryLookup::from_ints(MEMORY_READ_LABEL, ctx, addr, self.clk, words[0]),
+            MemoryLookup::from_ints(MEMORY_READ_LABEL, ctx, addr2, self.clk, words[1]),
+        ];
+
+        // send lookups to the bus and return the result
+        self.bus.request_memory_operation(&lookups, self.clk);
+        words
+    }
+
+    /// Writes the provided word at the specified context/address.
+    ///
+    /// This also modifies the memory access trace and sends a memory lookup request to the bus.
+    fn write_mem(&mut self, ctx: u32, addr: Felt, word: Word) {
+        self.memory.write(ctx, addr, self.clk, word);
+
+        // send the memory write request to the bus
+        let lookup = MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr, self.clk, word);
+        self.bus.request_memory_operation(&[lookup], self.clk);
+    }
+
+    /// Writes the provided element into the specified context/address leaving the remaining 3
+    /// elements of the word previously stored at that address unchanged.
+    ///
+    /// This also modifies the memory access trace and sends a memory lookup request to the bus.
+    fn write_mem_element(&mut self, ctx: u32, addr: Felt, value: Felt) -> Word {
+        let old_word = self.memory.get_old_value(ctx, addr.as_int());
+        let new_word = [value, old_word[1], old_word[2], old_word[3]];
+
+        self.memory.write(ctx, addr, self.clk, new_word);
+
+        // send the memory write request to the bus
+        let lookup = MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr, self.clk, new_word);
+        self.bus.request_memory_operation(&[lookup], self.clk);
+
+        old_word
+    }
+
+    /// Writes the two provided words to two consecutive addresses in memory in the specified
+    /// context, starting at the specified address.
+    ///
+    /// This also modifies the memory access trace and sends two memory lookup requests to the bus.
+    fn write_mem_double(&mut self, ctx: u32, addr: Felt, words: [Word; 2]) {
+        let addr2 = addr + ONE;
+        // write two words to memory at addr and addr + 1
+        self.memory.write(ctx, addr, self.clk, words[0]);
+        self.memory.write(ctx, addr2, self.clk, words[1]);
+
+        // create lookups for both memory writes
+        let lookups = [
+            MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr, self.clk, words[0]),
+            MemoryLookup::from_ints(MEMORY_WRITE_LABEL, ctx, addr2, self.clk, words[1]),
+        ];
+
+        // send lookups to the bus
+        self.bus.request_memory_operation(&lookups, self.clk);
+    }
+
+    /// Returns a word located at the specified context/address, or None if the address hasn't
+    /// been accessed previously.
+    ///
+    /// Unlike mem_read() which modifies the memory access trace, this method returns the value at
+    /// the specified address (if one exists) without altering the memory access trace.
+    fn get_mem_value(&self, ctx: u32, addr: u64) -> Option<Word> {
+        self.memory.get_value(ctx, addr)
+    }
+}
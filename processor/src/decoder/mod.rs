@@ -1,6 +1,6 @@
 use super::{
-    Call, ExecutionError, Felt, FieldElement, Join, Loop, OpBatch, Operation, Process, Span, Split,
-    StarkField, Vec, Word, MIN_TRACE_LEN, ONE, OP_BATCH_SIZE, ZERO,
+    Call, ExecutionError, Felt, FieldElement, Join, Loop, OpBatch, OperandStack, Operation,
+    Process, Span, Split, StarkField, Vec, Word, MIN_TRACE_LEN, ONE, OP_BATCH_SIZE, ZERO,
 };
 use vm_core::{
     chiplets::hasher::DIGEST_LEN,
@@ -749,6 +749,12 @@ impl Decoder {
         self.debug_info.append_asmop(clk, asmop);
     }
 
+    /// Removes the list of asmop decorators recorded in debug mode from this decoder and returns
+    /// it.
+    pub fn take_assembly_ops(&mut self) -> Vec<(usize, AssemblyOp)> {
+        core::mem::take(&mut self.debug_info.assembly_ops)
+    }
+
     // TEST METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -1,4 +1,7 @@
-use super::{AdviceInjector, Decorator, ExecutionError, Felt, Process, StarkField};
+use super::{
+    utils::get_div_result_u256, AdviceInjector, AdviceProvider, ChipletOps, Decorator,
+    ExecutionError, Felt, OperandStack, Process, StarkField,
+};
 use vm_core::{utils::collections::Vec, WORD_LEN, ZERO};
 
 // DECORATORS
@@ -26,128 +29,196 @@ impl Process {
 
     /// Process the specified advice injector.
     pub fn dec_advice(&mut self, injector: &AdviceInjector) -> Result<(), ExecutionError> {
-        match injector {
-            AdviceInjector::MerkleNode => self.inject_merkle_node(),
-            AdviceInjector::DivResultU64 => self.inject_div_result_u64(),
-            AdviceInjector::MapValue => self.inject_map_value(),
-            AdviceInjector::Memory(start_addr, num_words) => {
-                self.inject_mem_values(*start_addr, *num_words)
-            }
-        }
+        inject_advice(
+            injector,
+            &self.stack,
+            &self.chiplets,
+            &mut self.advice,
+            self.system.clk(),
+            self.system.ctx(),
+        )
     }
+}
 
-    // INJECTOR HELPERS
-    // --------------------------------------------------------------------------------------------
-
-    /// Injects a node of the Merkle tree specified by the values on the stack at the head of the
-    /// advice tape. The stack is expected to be arranged as follows (from the top):
-    /// - depth of the node, 1 element
-    /// - index of the node, 1 element
-    /// - root of the tree, 4 elements
-    ///
-    /// # Errors
-    /// Returns an error if:
-    /// - Merkle tree for the specified root cannot be found in the advice provider.
-    /// - The specified depth is either zero or greater than the depth of the Merkle tree
-    ///   identified by the specified root.
-    /// - Value of the node at the specified depth and index is not known to the advice provider.
-    fn inject_merkle_node(&mut self) -> Result<(), ExecutionError> {
-        // read node depth, node index, and tree root from the stack
-        let depth = self.stack.get(0);
-        let index = self.stack.get(1);
-        let root = [
-            self.stack.get(5),
-            self.stack.get(4),
-            self.stack.get(3),
-            self.stack.get(2),
-        ];
+// ADVICE INJECTION
+// ================================================================================================
 
-        // look up the node in the advice provider
-        let node = self.advice.get_tree_node(root, depth, index)?;
+/// Processes the specified advice injector against the provided stack, memory (accessed via the
+/// chiplets), and advice provider.
+///
+/// This is shared by the processor and by the fast processor; `clk` and `ctx` are the current
+/// clock cycle and the current memory context of the VM.
+pub(crate) fn inject_advice<S: OperandStack, C: ChipletOps>(
+    injector: &AdviceInjector,
+    stack: &S,
+    chiplets: &C,
+    advice: &mut AdviceProvider,
+    clk: u32,
+    ctx: u32,
+) -> Result<(), ExecutionError> {
+    match injector {
+        AdviceInjector::MerkleNode => inject_merkle_node(stack, advice),
+        AdviceInjector::DivResultU64 => inject_div_result_u64(stack, advice, clk),
+        AdviceInjector::DivResultU256 => inject_div_result_u256(stack, advice, clk),
+        AdviceInjector::MapValue => inject_map_value(stack, advice),
+        AdviceInjector::Memory(start_addr, num_words) => {
+            inject_mem_values(stack, chiplets, advice, ctx, *start_addr, *num_words)
+        }
+    }
+}
 
-        // write the node into the advice tape with first element written last so that it can be
-        // removed first
-        self.advice.write_tape(node[3]);
-        self.advice.write_tape(node[2]);
-        self.advice.write_tape(node[1]);
-        self.advice.write_tape(node[0]);
+// INJECTOR HELPERS
+// ------------------------------------------------------------------------------------------------
+
+/// Injects a node of the Merkle tree specified by the values on the stack at the head of the
+/// advice tape. The stack is expected to be arranged as follows (from the top):
+/// - depth of the node, 1 element
+/// - index of the node, 1 element
+/// - root of the tree, 4 elements
+///
+/// # Errors
+/// Returns an error if:
+/// - Merkle tree for the specified root cannot be found in the advice provider.
+/// - The specified depth is either zero or greater than the depth of the Merkle tree
+///   identified by the specified root.
+/// - Value of the node at the specified depth and index is not known to the advice provider.
+fn inject_merkle_node<S: OperandStack>(
+    stack: &S,
+    advice: &mut AdviceProvider,
+) -> Result<(), ExecutionError> {
+    // read node depth, node index, and tree root from the stack
+    let depth = stack.get(0);
+    let index = stack.get(1);
+    let root = stack.get_word(2);
+
+    // look up the node in the advice provider
+    let node = advice.get_tree_node(root, depth, index)?;
+
+    // write the node into the advice tape with first element written last so that it can be
+    // removed first
+    advice.write_tape(node[3]);
+    advice.write_tape(node[2]);
+    advice.write_tape(node[1]);
+    advice.write_tape(node[0]);
+
+    Ok(())
+}
 
-        Ok(())
+/// Injects the result of u64 division (both the quotient and the remainder) at the head of
+/// the advice tape. The stack is expected to be arranged as follows (from the top):
+/// - divisor split into two 32-bit elements
+/// - dividend split into two 32-bit elements
+///
+/// The result is injected into the advice tape as follows: first the remainder is injected,
+/// then the quotient is injected. This guarantees that when reading values from the advice
+/// tape, first the quotient will be read, and then the remainder.
+///
+/// # Errors
+/// Returns an error if the divisor is ZERO.
+fn inject_div_result_u64<S: OperandStack>(
+    stack: &S,
+    advice: &mut AdviceProvider,
+    clk: u32,
+) -> Result<(), ExecutionError> {
+    let divisor_hi = stack.get(0).as_int();
+    let divisor_lo = stack.get(1).as_int();
+    let divisor = (divisor_hi << 32) + divisor_lo;
+
+    if divisor == 0 {
+        return Err(ExecutionError::DivideByZero(clk));
     }
 
-    /// Injects the result of u64 division (both the quotient and the remainder) at the head of
-    /// the advice tape. The stack is expected to be arranged as follows (from the top):
-    /// - divisor split into two 32-bit elements
-    /// - dividend split into two 32-bit elements
-    ///
-    /// The result is injected into the advice tape as follows: first the remainder is injected,
-    /// then the quotient is injected. This guarantees that when reading values from the advice
-    /// tape, first the quotient will be read, and then the remainder.
-    ///
-    /// # Errors
-    /// Returns an error if the divisor is ZERO.
-    fn inject_div_result_u64(&mut self) -> Result<(), ExecutionError> {
-        let divisor_hi = self.stack.get(0).as_int();
-        let divisor_lo = self.stack.get(1).as_int();
-        let divisor = (divisor_hi << 32) + divisor_lo;
-
-        if divisor == 0 {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
-        }
+    let dividend_hi = stack.get(2).as_int();
+    let dividend_lo = stack.get(3).as_int();
+    let dividend = (dividend_hi << 32) + dividend_lo;
 
-        let dividend_hi = self.stack.get(2).as_int();
-        let dividend_lo = self.stack.get(3).as_int();
-        let dividend = (dividend_hi << 32) + dividend_lo;
+    let quotient = dividend / divisor;
+    let remainder = dividend - quotient * divisor;
 
-        let quotient = dividend / divisor;
-        let remainder = dividend - quotient * divisor;
+    let (q_hi, q_lo) = u64_to_u32_elements(quotient);
+    let (r_hi, r_lo) = u64_to_u32_elements(remainder);
 
-        let (q_hi, q_lo) = u64_to_u32_elements(quotient);
-        let (r_hi, r_lo) = u64_to_u32_elements(remainder);
+    advice.write_tape(r_hi);
+    advice.write_tape(r_lo);
+    advice.write_tape(q_hi);
+    advice.write_tape(q_lo);
 
-        self.advice.write_tape(r_hi);
-        self.advice.write_tape(r_lo);
-        self.advice.write_tape(q_hi);
-        self.advice.write_tape(q_lo);
+    Ok(())
+}
 
-        Ok(())
+/// Injects the result of u256 division (both the quotient and the remainder) at the head of
+/// the advice tape. The stack is expected to be arranged as follows (from the top):
+/// - divisor split into eight 32-bit elements, the most significant limb first
+/// - dividend split into eight 32-bit elements, the most significant limb first
+///
+/// The result is injected into the advice tape as follows: first the remainder is injected,
+/// then the quotient is injected. Both values are injected with the most significant limb
+/// written first. This guarantees that when reading values from the advice tape, first the
+/// quotient will be read, and then the remainder, in both cases starting with the least
+/// significant limb.
+///
+/// # Errors
+/// Returns an error if the divisor is ZERO.
+fn inject_div_result_u256<S: OperandStack>(
+    stack: &S,
+    advice: &mut AdviceProvider,
+    clk: u32,
+) -> Result<(), ExecutionError> {
+    let mut stack_limbs = [ZERO; 16];
+    for (i, limb) in stack_limbs.iter_mut().enumerate() {
+        *limb = stack.get(i);
     }
 
-    /// Injects a list of field elements at the front of the advice tape. The list is looked up in
-    /// the key-value map maintained by the advice provider using the top 4 elements on the stack
-    /// as the key.
-    ///
-    /// # Errors
-    /// Returns an error if the required key was not found in the key-value map.
-    fn inject_map_value(&mut self) -> Result<(), ExecutionError> {
-        let top_word = self.stack.get_top_word();
-        self.advice.write_tape_from_map(top_word)?;
-
-        Ok(())
+    let result = get_div_result_u256(stack_limbs).ok_or(ExecutionError::DivideByZero(clk))?;
+    for &value in result.iter() {
+        advice.write_tape(value);
     }
 
-    /// Reads the specfied number of words from the memory starting at the given start address and
-    /// writes the vector of field elements to the advice map with the top 4 elements on the stack
-    /// as the key. This operation does not affect the state of the Memory chiplet and the VM in
-    /// general.
-    ///
-    /// # Errors
-    /// Returns an error if the key is already present in the advice map.
-    fn inject_mem_values(&mut self, start_addr: u32, num_words: u32) -> Result<(), ExecutionError> {
-        let ctx = self.system.ctx();
-        let mut values = Vec::with_capacity(num_words as usize * WORD_LEN);
-        for i in 0..num_words {
-            let mem_value = self
-                .chiplets
-                .get_mem_value(ctx, (start_addr + i) as u64)
-                .unwrap_or([ZERO; WORD_LEN]);
-            values.extend_from_slice(&mem_value);
-        }
-        let top_word = self.stack.get_top_word();
-        self.advice.insert_into_map(top_word, values)?;
+    Ok(())
+}
 
-        Ok(())
+/// Injects a list of field elements at the front of the advice tape. The list is looked up in
+/// the key-value map maintained by the advice provider using the top 4 elements on the stack
+/// as the key.
+///
+/// # Errors
+/// Returns an error if the required key was not found in the key-value map.
+fn inject_map_value<S: OperandStack>(
+    stack: &S,
+    advice: &mut AdviceProvider,
+) -> Result<(), ExecutionError> {
+    let top_word = stack.get_word(0);
+    advice.write_tape_from_map(top_word)?;
+
+    Ok(())
+}
+
+/// Reads the specfied number of words from the memory starting at the given start address and
+/// writes the vector of field elements to the advice map with the top 4 elements on the stack
+/// as the key. This operation does not affect the state of the Memory chiplet and the VM in
+/// general.
+///
+/// # Errors
+/// Returns an error if the key is already present in the advice map.
+fn inject_mem_values<S: OperandStack, C: ChipletOps>(
+    stack: &S,
+    chiplets: &C,
+    advice: &mut AdviceProvider,
+    ctx: u32,
+    start_addr: u32,
+    num_words: u32,
+) -> Result<(), ExecutionError> {
+    let mut values = Vec::with_capacity(num_words as usize * WORD_LEN);
+    for i in 0..num_words {
+        let mem_value = chiplets
+            .get_mem_value(ctx, (start_addr + i) as u64)
+            .unwrap_or([ZERO; WORD_LEN]);
+        values.extend_from_slice(&mem_value);
     }
+    let top_word = stack.get_word(0);
+    advice.insert_into_map(top_word, values)?;
+
+    Ok(())
 }
 
 // HELPER FUNCTIONS
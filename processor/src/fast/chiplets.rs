@@ -0,0 +1,142 @@
+use super::{BTreeMap, ChipletOps, ExecutionError, Felt, StarkField, Vec, Word, ONE, ZERO};
+use crate::chiplets::assert_u32;
+use vm_core::{
+    chiplets::hasher::{apply_permutation, get_digest, init_state_from_words, HasherState},
+    WORD_LEN,
+};
+
+// FAST CHIPLETS
+// ================================================================================================
+
+/// Chiplets of the [FastProcess](super::FastProcess).
+///
+/// Computations which operations delegate to the chiplets are performed directly, and the
+/// execution traces of the chiplets are not recorded. Thus, hasher trace addresses returned to
+/// the operations are always ZERO.
+///
+/// Memory is kept as a map of words per execution context; as in the memory chiplet, reading an
+/// address marks it as accessed.
+#[derive(Clone, Default)]
+pub struct FastChiplets {
+    memory: BTreeMap<u32, BTreeMap<u64, Word>>,
+}
+
+impl FastChiplets {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns all words which have been accessed in memory of the specified context, together
+    /// with their addresses, sorted by address.
+    pub fn get_mem_state(&self, ctx: u32) -> Vec<(u64, Word)> {
+        self.memory
+            .get(&ctx)
+            .map(|segment| segment.iter().map(|(&addr, &word)| (addr, word)).collect())
+            .unwrap_or_default()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a mutable reference to the word located at the specified context/address; the
+    /// word is initialized to ZEROs if the address hasn't been accessed previously.
+    fn mem_word_mut(&mut self, ctx: u32, addr: Felt) -> &mut Word {
+        self.memory
+            .entry(ctx)
+            .or_default()
+            .entry(addr.as_int())
+            .or_insert([ZERO; WORD_LEN])
+    }
+}
+
+impl ChipletOps for FastChiplets {
+    fn permute(&mut self, mut state: HasherState) -> (Felt, HasherState) {
+        apply_permutation(&mut state);
+        (ZERO, state)
+    }
+
+    fn build_merkle_root(&mut self, value: Word, path: &[Word], index: Felt) -> (Felt, Word) {
+        (ZERO, compute_merkle_root(value, path, index.as_int()))
+    }
+
+    fn update_merkle_root(
+        &mut self,
+        old_value: Word,
+        new_value: Word,
+        path: &[Word],
+        index: Felt,
+    ) -> (Felt, Word, Word) {
+        let old_root = compute_merkle_root(old_value, path, index.as_int());
+        let new_root = compute_merkle_root(new_value, path, index.as_int());
+        (ZERO, old_root, new_root)
+    }
+
+    fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a)?.as_int();
+        let b = assert_u32(b)?.as_int();
+        Ok(Felt::new(a & b))
+    }
+
+    fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a)?.as_int();
+        let b = assert_u32(b)?.as_int();
+        Ok(Felt::new(a ^ b))
+    }
+
+    fn read_mem(&mut self, ctx: u32, addr: Felt) -> Word {
+        *self.mem_word_mut(ctx, addr)
+    }
+
+    fn read_mem_double(&mut self, ctx: u32, addr: Felt) -> [Word; 2] {
+        [self.read_mem(ctx, addr), self.read_mem(ctx, addr + ONE)]
+    }
+
+    fn write_mem(&mut self, ctx: u32, addr: Felt, word: Word) {
+        *self.mem_word_mut(ctx, addr) = word;
+    }
+
+    fn write_mem_element(&mut self, ctx: u32, addr: Felt, value: Felt) -> Word {
+        let word = self.mem_word_mut(ctx, addr);
+        let old_word = *word;
+        word[0] = value;
+        old_word
+    }
+
+    fn write_mem_double(&mut self, ctx: u32, addr: Felt, words: [Word; 2]) {
+        self.write_mem(ctx, addr, words[0]);
+        self.write_mem(ctx, addr + ONE, words[1]);
+    }
+
+    fn get_mem_value(&self, ctx: u32, addr: u64) -> Option<Word> {
+        self.memory
+            .get(&ctx)
+            .and_then(|segment| segment.get(&addr))
+            .copied()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Computes the root of the provided Merkle path for a node with the specified value located at
+/// the specified index.
+///
+/// # Panics
+/// Panics if the path is empty or if the index is out of range for the path.
+fn compute_merkle_root(value: Word, path: &[Word], mut index: u64) -> Word {
+    assert!(!path.is_empty(), "path is empty");
+    assert!(index >> path.len() == 0, "invalid index for the path");
+
+    let mut root = value;
+    for sibling in path {
+        let mut state = if index & 1 == 0 {
+            init_state_from_words(&root, sibling)
+        } else {
+            init_state_from_words(sibling, &root)
+        };
+        apply_permutation(&mut state);
+        root = get_digest(&state);
+        index >>= 1;
+    }
+
+    root
+}
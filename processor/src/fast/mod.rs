@@ -0,0 +1,367 @@
+use super::{
+    decorators::inject_advice,
+    system::{FMP_MIN, SYSCALL_FMP_MIN},
+    AdviceInjector, AdviceProvider, BTreeMap, Call, ChipletOps, CodeBlock, CodeBlockTable,
+    Decorator, DecoratorIterator, ExecutionError, Felt, Join, Kernel, Loop, OpBatch, OperandStack,
+    Operation, Program, ProgramInputs, ProgramOutputs, Span, Split, StarkField, Vec, Word, ONE,
+    OP_GROUP_SIZE, ZERO,
+};
+use vm_core::stack::STACK_TOP_SIZE;
+
+mod operations;
+
+mod chiplets;
+use chiplets::FastChiplets;
+
+mod stack;
+use stack::FastStack;
+
+// FAST PROCESS
+// ================================================================================================
+
+/// A process which executes programs without generating an execution trace.
+///
+/// A fast process interprets a program's MAST in exactly the same way as the full [Process]
+/// does: it executes the same sequence of VM cycles (including the cycles needed to decode
+/// control blocks and to pad operation groups with NOOPs), and it fails with the same errors at
+/// the same clock cycles. However, it keeps track of the current VM state only, and does not
+/// record traces of the decoder, stack, range checker, or chiplets. Thus, the outputs of a fast
+/// process are identical to the outputs of the full process, but they cannot be used to generate
+/// a proof of execution.
+///
+/// This makes fast processes suitable for running programs when only their outputs are needed,
+/// e.g., in tests, simulations, and fuzzing.
+///
+/// [Process]: crate::Process
+#[derive(Clone)]
+pub struct FastProcess {
+    clk: u32,
+    ctx: u32,
+    fmp: Felt,
+    in_syscall: bool,
+    fn_hash: Word,
+    stack: FastStack,
+    chiplets: FastChiplets,
+    advice: AdviceProvider,
+    kernel: Kernel,
+}
+
+impl FastProcess {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Creates a new fast process with the provided inputs.
+    pub fn new(kernel: &Kernel, inputs: ProgramInputs) -> Self {
+        let stack = FastStack::new(inputs.stack_init());
+        Self {
+            clk: 0,
+            ctx: 0,
+            fmp: Felt::new(FMP_MIN),
+            in_syscall: false,
+            fn_hash: [ZERO; 4],
+            stack,
+            chiplets: FastChiplets::default(),
+            advice: AdviceProvider::new(inputs),
+            kernel: kernel.clone(),
+        }
+    }
+
+    // PROGRAM EXECUTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the provided [Program] in this process.
+    pub fn execute(&mut self, program: &Program) -> Result<ProgramOutputs, ExecutionError> {
+        assert_eq!(
+            self.clk, 0,
+            "a program has already been executed in this process"
+        );
+        self.execute_code_block(program.root(), program.cb_table())?;
+
+        Ok(self.stack.get_outputs())
+    }
+
+    /// Executes the provided [Program] starting from the current state of this process.
+    ///
+    /// Unlike [FastProcess::execute()], this can be called on a process which has already executed
+    /// other programs. The stack, memory, and advice provider left by the previously executed
+    /// programs are carried over, and the clock cycle keeps increasing. This can be used to
+    /// execute a program in parts, e.g., one instruction at a time.
+    ///
+    /// If execution fails, the process is left in the state at which the failure occurred.
+    pub fn execute_incremental(
+        &mut self,
+        program: &Program,
+    ) -> Result<ProgramOutputs, ExecutionError> {
+        self.execute_code_block(program.root(), program.cb_table())?;
+
+        Ok(self.stack.get_outputs())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of cycles executed by this process so far.
+    pub fn clk(&self) -> u32 {
+        self.clk
+    }
+
+    /// Returns the items currently on the stack, starting with the top of the stack. This includes
+    /// the items in the overflow table which are accessible from the current context.
+    pub fn get_stack_state(&self) -> Vec<Felt> {
+        self.stack.get_state()
+    }
+
+    /// Returns all words which have been accessed in memory of the specified context, together
+    /// with their addresses, sorted by address.
+    pub fn get_memory_state(&self, ctx: u32) -> Vec<(u64, Word)> {
+        self.chiplets.get_mem_state(ctx)
+    }
+
+    /// Returns a word located at the specified context/address, or None if the address hasn't
+    /// been accessed previously.
+    pub fn get_memory_value(&self, ctx: u32, addr: u64) -> Option<Word> {
+        self.chiplets.get_mem_value(ctx, addr)
+    }
+
+    // CODE BLOCK EXECUTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the specified [CodeBlock].
+    ///
+    /// # Errors
+    /// Returns an [ExecutionError] if executing the specified block fails for any reason.
+    fn execute_code_block(
+        &mut self,
+        block: &CodeBlock,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        match block {
+            CodeBlock::Join(block) => self.execute_join_block(block, cb_table),
+            CodeBlock::Split(block) => self.execute_split_block(block, cb_table),
+            CodeBlock::Loop(block) => self.execute_loop_block(block, cb_table),
+            CodeBlock::Call(block) => self.execute_call_block(block, cb_table),
+            CodeBlock::Span(block) => self.execute_span_block(block),
+            CodeBlock::Proxy(_) => Err(ExecutionError::UnexecutableCodeBlock(block.clone())),
+        }
+    }
+
+    /// Executes the specified [Join] block.
+    #[inline(always)]
+    fn execute_join_block(
+        &mut self,
+        block: &Join,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // JOIN operation does not change the state of the stack
+        self.execute_op(Operation::Noop)?;
+
+        self.execute_code_block(block.first(), cb_table)?;
+        self.execute_code_block(block.second(), cb_table)?;
+
+        // END operation does not change the state of the stack
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes the specified [Split] block.
+    #[inline(always)]
+    fn execute_split_block(
+        &mut self,
+        block: &Split,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // SPLIT operation pops the condition off the stack
+        let condition = self.stack.peek();
+        self.execute_op(Operation::Drop)?;
+
+        if condition == ONE {
+            self.execute_code_block(block.on_true(), cb_table)?;
+        } else if condition == ZERO {
+            self.execute_code_block(block.on_false(), cb_table)?;
+        } else {
+            return Err(ExecutionError::NotBinaryValue(condition));
+        }
+
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes the specified [Loop] block.
+    #[inline(always)]
+    fn execute_loop_block(
+        &mut self,
+        block: &Loop,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // LOOP operation pops the condition off the stack
+        let condition = self.stack.peek();
+        self.execute_op(Operation::Drop)?;
+
+        if condition == ONE {
+            self.execute_code_block(block.body(), cb_table)?;
+
+            // each subsequent iteration is preceded by a REPEAT operation which drops the
+            // condition from the stack
+            while self.stack.peek() == ONE {
+                self.execute_op(Operation::Drop)?;
+                self.execute_code_block(block.body(), cb_table)?;
+            }
+
+            // END operation drops the condition from the stack when exiting the loop
+            self.execute_op(Operation::Drop)
+        } else if condition == ZERO {
+            self.execute_op(Operation::Noop)
+        } else {
+            Err(ExecutionError::NotBinaryValue(condition))
+        }
+    }
+
+    /// Executes the specified [Call] block.
+    #[inline(always)]
+    fn execute_call_block(
+        &mut self,
+        block: &Call,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // if this is a syscall, make sure the call target exists in the kernel
+        if block.is_syscall() && !self.kernel.contains_proc(block.fn_hash()) {
+            return Err(ExecutionError::SyscallTargetNotInKernel(block.fn_hash()));
+        }
+
+        // start a new execution context; this resets stack depth to 16
+        let (parent_depth, parent_overflow_addr) = self.stack.start_context();
+        let (parent_ctx, parent_fmp, parent_fn_hash) = (self.ctx, self.fmp, self.fn_hash);
+
+        if block.is_syscall() {
+            debug_assert!(!self.in_syscall, "already in syscall");
+            self.ctx = 0;
+            self.fmp = Felt::new(SYSCALL_FMP_MIN);
+            self.in_syscall = true;
+        } else {
+            debug_assert!(!self.in_syscall, "call in syscall");
+            self.ctx = self.clk + 1;
+            self.fmp = Felt::new(FMP_MIN);
+            self.fn_hash = block.fn_hash().into();
+        }
+
+        // CALL and SYSCALL operations do not change the state of the stack
+        self.execute_op(Operation::Noop)?;
+
+        let fn_body = cb_table
+            .get(block.fn_hash())
+            .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
+        self.execute_code_block(fn_body, cb_table)?;
+
+        // when a CALL block ends, stack depth must be exactly 16
+        let stack_depth = self.stack.depth();
+        if stack_depth > STACK_TOP_SIZE {
+            return Err(ExecutionError::InvalidStackDepthOnReturn(stack_depth));
+        }
+
+        // restore the execution context of the caller
+        self.ctx = parent_ctx;
+        self.fmp = parent_fmp;
+        self.in_syscall = false;
+        self.fn_hash = parent_fn_hash;
+        self.stack
+            .restore_context(parent_depth, parent_overflow_addr);
+
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes the specified [Span] block.
+    #[inline(always)]
+    fn execute_span_block(&mut self, block: &Span) -> Result<(), ExecutionError> {
+        // SPAN operation does not change the state of the stack
+        self.execute_op(Operation::Noop)?;
+
+        let mut op_offset = 0;
+        let mut decorators = block.decorator_iter();
+
+        // each operation batch after the first one is preceded by a RESPAN operation, which does
+        // not change the state of the stack
+        for (i, op_batch) in block.op_batches().iter().enumerate() {
+            if i > 0 {
+                self.execute_op(Operation::Noop)?;
+            }
+            self.execute_op_batch(op_batch, &mut decorators, op_offset)?;
+            op_offset += op_batch.ops().len();
+        }
+
+        // END operation does not change the state of the stack
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes all operations in an [OpBatch], including the NOOPs which the full processor
+    /// executes to satisfy alignment rules of operation groups.
+    #[inline(always)]
+    fn execute_op_batch(
+        &mut self,
+        batch: &OpBatch,
+        decorators: &mut DecoratorIterator,
+        op_offset: usize,
+    ) -> Result<(), ExecutionError> {
+        let op_counts = batch.op_counts();
+        let mut op_idx = 0;
+        let mut group_idx = 0;
+        let mut next_group_idx = 1;
+
+        let num_batch_groups = batch.num_groups().next_power_of_two();
+
+        for (i, &op) in batch.ops().iter().enumerate() {
+            while let Some(decorator) = decorators.next(i + op_offset) {
+                self.execute_decorator(decorator)?;
+            }
+
+            self.execute_op(op)?;
+
+            // an operation with an immediate value occupies the next group
+            let has_imm = op.imm_value().is_some();
+            if has_imm {
+                next_group_idx += 1;
+            }
+
+            if op_idx == op_counts[group_idx] - 1 {
+                // an operation with an immediate value cannot be the last operation in a group;
+                // a NOOP is executed after it
+                if has_imm {
+                    debug_assert!(op_idx < OP_GROUP_SIZE - 1, "invalid op index");
+                    self.execute_op(Operation::Noop)?;
+                }
+
+                group_idx = next_group_idx;
+                next_group_idx += 1;
+                op_idx = 0;
+            } else {
+                op_idx += 1;
+            }
+        }
+
+        // pad the number of executed groups to the next power of two with NOOPs
+        for _ in group_idx..num_batch_groups {
+            self.execute_op(Operation::Noop)?;
+        }
+
+        Ok(())
+    }
+
+    // DECORATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the specified decorator.
+    fn execute_decorator(&mut self, decorator: &Decorator) -> Result<(), ExecutionError> {
+        match decorator {
+            Decorator::Advice(injector) => self.dec_advice(injector),
+            Decorator::AsmOp(_) => Ok(()),
+        }
+    }
+
+    /// Processes the specified advice injector in the same way as the full processor does.
+    fn dec_advice(&mut self, injector: &AdviceInjector) -> Result<(), ExecutionError> {
+        inject_advice(
+            injector,
+            &self.stack,
+            &self.chiplets,
+            &mut self.advice,
+            self.clk,
+            self.ctx,
+        )
+    }
+}
@@ -0,0 +1,163 @@
+use super::{ExecutionError, FastProcess, OperandStack, Operation};
+use crate::operations::{
+    crypto_ops::{op_mpverify, op_mrupdate, op_rpperm},
+    field_ops::{
+        op_add, op_and, op_eq, op_eqz, op_expacc, op_incr, op_inv, op_mul, op_neg, op_not, op_or,
+    },
+    io_ops::{
+        op_mload, op_mloadw, op_mstore, op_mstorew, op_mstream, op_pipe, op_push, op_read, op_readw,
+    },
+    stack_ops::{
+        op_cswap, op_cswapw, op_drop, op_dup, op_movdn, op_movup, op_pad, op_swap, op_swapdw,
+        op_swapw, op_swapw2, op_swapw3,
+    },
+    sys_ops::{op_assert, op_caller, op_fmpadd, op_fmpupdate, op_sdepth},
+    u32_ops::{
+        op_u32add, op_u32add3, op_u32and, op_u32assert2, op_u32div, op_u32madd, op_u32mul,
+        op_u32split, op_u32sub, op_u32xor,
+    },
+};
+
+// OPERATION DISPATCHER
+// ================================================================================================
+
+impl FastProcess {
+    /// Executes the specified operation.
+    ///
+    /// Operations are executed by the same functions as in the full processor. However, values
+    /// which the full processor places into helper registers or sends to the range checker are
+    /// discarded.
+    pub(super) fn execute_op(&mut self, op: Operation) -> Result<(), ExecutionError> {
+        let stack = &mut self.stack;
+        let chiplets = &mut self.chiplets;
+        let advice = &mut self.advice;
+        let clk = self.clk;
+        let ctx = self.ctx;
+
+        match op {
+            // ----- system operations ------------------------------------------------------------
+            Operation::Noop => stack.copy_state(0),
+            Operation::Assert => op_assert(stack, clk)?,
+
+            Operation::FmpAdd => op_fmpadd(stack, self.fmp)?,
+            Operation::FmpUpdate => self.fmp = op_fmpupdate(stack, self.fmp)?,
+
+            Operation::SDepth => op_sdepth(stack)?,
+            Operation::Caller => op_caller(stack, self.in_syscall, self.fn_hash)?,
+
+            // ----- flow control operations ------------------------------------------------------
+            // control flow operations are never executed directly
+            Operation::Join
+            | Operation::Split
+            | Operation::Loop
+            | Operation::Call
+            | Operation::SysCall
+            | Operation::Span
+            | Operation::Repeat
+            | Operation::Respan
+            | Operation::End
+            | Operation::Halt => unreachable!("control flow operation"),
+
+            // ----- field operations -------------------------------------------------------------
+            Operation::Add => op_add(stack)?,
+            Operation::Neg => op_neg(stack)?,
+            Operation::Mul => op_mul(stack)?,
+            Operation::Inv => op_inv(stack, clk)?,
+            Operation::Incr => op_incr(stack)?,
+
+            Operation::And => op_and(stack)?,
+            Operation::Or => op_or(stack)?,
+            Operation::Not => op_not(stack)?,
+
+            Operation::Eq => _ = op_eq(stack)?,
+            Operation::Eqz => _ = op_eqz(stack)?,
+
+            Operation::Expacc => _ = op_expacc(stack)?,
+
+            // ----- u32 operations ---------------------------------------------------------------
+            Operation::U32split => _ = op_u32split(stack)?,
+            Operation::U32add => _ = op_u32add(stack)?,
+            Operation::U32add3 => _ = op_u32add3(stack)?,
+            Operation::U32sub => _ = op_u32sub(stack)?,
+            Operation::U32mul => _ = op_u32mul(stack)?,
+            Operation::U32madd => _ = op_u32madd(stack)?,
+            Operation::U32div => _ = op_u32div(stack, clk)?,
+
+            Operation::U32and => op_u32and(stack, chiplets)?,
+            Operation::U32xor => op_u32xor(stack, chiplets)?,
+            Operation::U32assert2 => _ = op_u32assert2(stack)?,
+
+            // ----- stack manipulation -----------------------------------------------------------
+            Operation::Pad => op_pad(stack)?,
+            Operation::Drop => op_drop(stack)?,
+
+            Operation::Dup0 => op_dup(stack, 0)?,
+            Operation::Dup1 => op_dup(stack, 1)?,
+            Operation::Dup2 => op_dup(stack, 2)?,
+            Operation::Dup3 => op_dup(stack, 3)?,
+            Operation::Dup4 => op_dup(stack, 4)?,
+            Operation::Dup5 => op_dup(stack, 5)?,
+            Operation::Dup6 => op_dup(stack, 6)?,
+            Operation::Dup7 => op_dup(stack, 7)?,
+            Operation::Dup9 => op_dup(stack, 9)?,
+            Operation::Dup11 => op_dup(stack, 11)?,
+            Operation::Dup13 => op_dup(stack, 13)?,
+            Operation::Dup15 => op_dup(stack, 15)?,
+
+            Operation::Swap => op_swap(stack)?,
+            Operation::SwapW => op_swapw(stack)?,
+            Operation::SwapW2 => op_swapw2(stack)?,
+            Operation::SwapW3 => op_swapw3(stack)?,
+            Operation::SwapDW => op_swapdw(stack)?,
+
+            Operation::MovUp2 => op_movup(stack, 2)?,
+            Operation::MovUp3 => op_movup(stack, 3)?,
+            Operation::MovUp4 => op_movup(stack, 4)?,
+            Operation::MovUp5 => op_movup(stack, 5)?,
+            Operation::MovUp6 => op_movup(stack, 6)?,
+            Operation::MovUp7 => op_movup(stack, 7)?,
+            Operation::MovUp8 => op_movup(stack, 8)?,
+
+            Operation::MovDn2 => op_movdn(stack, 2)?,
+            Operation::MovDn3 => op_movdn(stack, 3)?,
+            Operation::MovDn4 => op_movdn(stack, 4)?,
+            Operation::MovDn5 => op_movdn(stack, 5)?,
+            Operation::MovDn6 => op_movdn(stack, 6)?,
+            Operation::MovDn7 => op_movdn(stack, 7)?,
+            Operation::MovDn8 => op_movdn(stack, 8)?,
+
+            Operation::CSwap => op_cswap(stack)?,
+            Operation::CSwapW => op_cswapw(stack)?,
+
+            // ----- input / output ---------------------------------------------------------------
+            Operation::Push(value) => op_push(stack, value)?,
+
+            Operation::Read => op_read(stack, advice)?,
+            Operation::ReadW => op_readw(stack, advice)?,
+
+            Operation::MLoadW => op_mloadw(stack, chiplets, ctx)?,
+            Operation::MStoreW => op_mstorew(stack, chiplets, ctx)?,
+
+            Operation::MLoad => _ = op_mload(stack, chiplets, ctx)?,
+            Operation::MStore => _ = op_mstore(stack, chiplets, ctx)?,
+
+            Operation::MStream => op_mstream(stack, chiplets, ctx)?,
+            Operation::Pipe => op_pipe(stack, chiplets, advice, ctx)?,
+
+            // ----- cryptographic operations -----------------------------------------------------
+            Operation::RpPerm => op_rpperm(stack, chiplets)?,
+            Operation::MpVerify => _ = op_mpverify(stack, chiplets, advice)?,
+            Operation::MrUpdate(copy) => _ = op_mrupdate(stack, chiplets, advice, copy)?,
+        }
+
+        self.advance_clock();
+        Ok(())
+    }
+
+    /// Increments the clock cycle.
+    fn advance_clock(&mut self) {
+        self.clk += 1;
+        self.stack.advance_clock();
+        self.advice.advance_clock();
+    }
+}
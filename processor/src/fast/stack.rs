@@ -0,0 +1,210 @@
+use super::{Felt, OperandStack, ProgramOutputs, Vec, ZERO};
+use core::cmp;
+use vm_core::stack::STACK_TOP_SIZE;
+
+// CONSTANTS
+// ================================================================================================
+
+const MAX_TOP_IDX: usize = STACK_TOP_SIZE - 1;
+
+// FAST STACK
+// ================================================================================================
+
+/// Operand stack of the [FastProcess](super::FastProcess).
+///
+/// The stack mirrors the semantics of the processor's stack without recording its trace. The top
+/// 16 items are kept in two buffers: the state at the current clock cycle, and the state at the
+/// next clock cycle which is being built by the currently executing operation. This way, the
+/// stack can be updated by the same operations as the processor's stack (see [OperandStack]).
+///
+/// Items which do not fit into the top 16 slots are kept in the overflow table together with the
+/// clock cycles at which they were added to the table. These addresses are needed to build
+/// [ProgramOutputs] which are identical to the ones produced by the full processor.
+#[derive(Clone)]
+pub struct FastStack {
+    clk: u32,
+    current: [Felt; STACK_TOP_SIZE],
+    next: [Felt; STACK_TOP_SIZE],
+    overflow: Vec<OverflowEntry>,
+    last_row_addr: Felt,
+    depth: usize,
+}
+
+impl FastStack {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a [FastStack] initialized with the specified values.
+    ///
+    /// Values in positions 16 and beyond are placed into the overflow table using the same
+    /// addresses as the ones assigned to them by the processor's stack.
+    pub fn new(init_values: &[Felt]) -> Self {
+        let mut current = [ZERO; STACK_TOP_SIZE];
+        let num_top_values = cmp::min(init_values.len(), STACK_TOP_SIZE);
+        current[..num_top_values].copy_from_slice(&init_values[..num_top_values]);
+
+        let mut stack = Self {
+            clk: 0,
+            current,
+            next: [ZERO; STACK_TOP_SIZE],
+            overflow: Vec::new(),
+            last_row_addr: ZERO,
+            depth: cmp::max(STACK_TOP_SIZE, init_values.len()),
+        };
+
+        if init_values.len() > STACK_TOP_SIZE {
+            let overflow_values = &init_values[STACK_TOP_SIZE..];
+            let mut clk = -Felt::new(overflow_values.len() as u64);
+            for &value in overflow_values.iter().rev() {
+                stack.push_overflow(value, clk);
+                clk += Felt::new(1);
+            }
+        }
+
+        stack
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value at the top of the stack.
+    pub fn peek(&self) -> Felt {
+        self.current[0]
+    }
+
+    /// Returns the items on the stack starting with the top of the stack. Items of the overflow
+    /// table are included only if they are accessible from the current context.
+    pub fn get_state(&self) -> Vec<Felt> {
+        let num_overflow_items = self.depth - STACK_TOP_SIZE;
+        let mut result = Vec::with_capacity(self.depth);
+        result.extend_from_slice(&self.current);
+        result.extend(
+            self.overflow
+                .iter()
+                .rev()
+                .take(num_overflow_items)
+                .map(|entry| entry.value),
+        );
+        result
+    }
+
+    /// Returns the outputs of a program which left this stack in its current state.
+    pub fn get_outputs(&self) -> ProgramOutputs {
+        let mut stack_items = Vec::with_capacity(self.depth);
+        stack_items.extend_from_slice(&self.current);
+        stack_items.extend(self.overflow.iter().rev().map(|entry| entry.value));
+
+        let mut overflow_addrs = Vec::new();
+        if let Some(first) = self.overflow.first() {
+            overflow_addrs.reserve(self.overflow.len() + 1);
+            overflow_addrs.push(first.prev);
+            overflow_addrs.extend(self.overflow.iter().map(|entry| entry.clk));
+        }
+
+        ProgramOutputs::from_elements(stack_items, overflow_addrs)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Makes the state built for the next clock cycle the current state of the stack.
+    pub fn advance_clock(&mut self) {
+        self.clk += 1;
+        self.current = self.next;
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts a new execution context by hiding all items in the overflow table. Returns the
+    /// stack depth and the overflow address of the context which was active prior to this.
+    pub fn start_context(&mut self) -> (usize, Felt) {
+        let parent_context = (self.depth, self.last_row_addr);
+        self.depth = STACK_TOP_SIZE;
+        self.last_row_addr = ZERO;
+        parent_context
+    }
+
+    /// Restores the execution context with the specified stack depth and overflow address.
+    pub fn restore_context(&mut self, depth: usize, last_row_addr: Felt) {
+        debug_assert_eq!(self.depth, STACK_TOP_SIZE, "overflow table not empty");
+        self.depth = depth;
+        self.last_row_addr = last_row_addr;
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn push_overflow(&mut self, value: Felt, clk: Felt) {
+        self.overflow.push(OverflowEntry {
+            value,
+            clk,
+            prev: self.last_row_addr,
+        });
+        self.last_row_addr = clk;
+    }
+}
+
+// OPERAND STACK
+// ================================================================================================
+
+impl OperandStack for FastStack {
+    fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn get(&self, pos: usize) -> Felt {
+        debug_assert!(pos < STACK_TOP_SIZE, "stack underflow");
+        self.current[pos]
+    }
+
+    fn set(&mut self, pos: usize, value: Felt) {
+        debug_assert!(pos < STACK_TOP_SIZE, "stack underflow");
+        self.next[pos] = value;
+    }
+
+    fn copy_state(&mut self, start_pos: usize) {
+        self.next[start_pos..].copy_from_slice(&self.current[start_pos..]);
+    }
+
+    fn shift_left(&mut self, start_pos: usize) {
+        debug_assert!(start_pos > 0, "start position must be greater than 0");
+        debug_assert!(
+            start_pos < STACK_TOP_SIZE,
+            "start position cannot exceed stack top size"
+        );
+
+        self.next[start_pos - 1..MAX_TOP_IDX].copy_from_slice(&self.current[start_pos..]);
+        self.next[MAX_TOP_IDX] = if self.depth > STACK_TOP_SIZE {
+            let entry = self.overflow.pop().expect("overflow table is empty");
+            self.last_row_addr = entry.prev;
+            self.depth -= 1;
+            entry.value
+        } else {
+            ZERO
+        };
+    }
+
+    fn shift_right(&mut self, start_pos: usize) {
+        debug_assert!(
+            start_pos < STACK_TOP_SIZE,
+            "start position cannot exceed stack top size"
+        );
+
+        // the value pushed out of the top 16 items is moved into the overflow table with the
+        // current clock cycle as its address
+        self.next[start_pos + 1..].copy_from_slice(&self.current[start_pos..MAX_TOP_IDX]);
+        self.push_overflow(self.current[MAX_TOP_IDX], Felt::from(self.clk));
+        self.depth += 1;
+    }
+}
+
+// OVERFLOW ENTRY
+// ================================================================================================
+
+/// An item in the overflow table of the [FastStack].
+#[derive(Clone)]
+struct OverflowEntry {
+    value: Felt,
+    clk: Felt,
+    prev: Felt,
+}
@@ -23,6 +23,7 @@ use winterfell::Matrix;
 
 mod decorators;
 mod operations;
+use operations::{ChipletOps, OperandStack};
 
 mod system;
 use system::System;
@@ -46,10 +47,18 @@ use chiplets::Chiplets;
 mod trace;
 pub use trace::ExecutionTrace;
 use trace::TraceFragment;
+#[cfg(feature = "std")]
+pub use trace::{
+    aux_trace_column_names, main_trace_column_names, TraceExportFormat, BINARY_TRACE_MAGIC,
+    BINARY_TRACE_VERSION,
+};
 
 mod errors;
 pub use errors::ExecutionError;
 
+mod fast;
+pub use fast::FastProcess;
+
 mod utils;
 
 mod debug;
@@ -101,6 +110,41 @@ pub fn execute(
     Ok(trace)
 }
 
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs in debug mode.
+///
+/// The trace is identical to the trace returned by [execute()], but in addition, it provides the
+/// assembly instructions executed by the VM via [ExecutionTrace::get_asmop_at()]. Assembly
+/// instructions are available only if the program was compiled in debug mode.
+pub fn execute_debug(
+    program: &Program,
+    inputs: &ProgramInputs,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let mut process = Process::new_debug(program.kernel(), inputs.clone());
+    let program_outputs = process.execute(program)?;
+    let trace = ExecutionTrace::new(process, program_outputs);
+    assert_eq!(
+        program.hash(),
+        trace.program_hash(),
+        "inconsistent program hash"
+    );
+    Ok(trace)
+}
+
+/// Returns the outputs resulting from executing the provided program against the provided inputs
+/// without generating an execution trace.
+///
+/// The program is executed by a [FastProcess], which executes the same VM cycles as the process
+/// used by [execute()], and thus, returns the same outputs and fails with the same errors, but is
+/// considerably faster. Since no execution trace is generated, the outputs cannot be proven.
+pub fn execute_fast(
+    program: &Program,
+    inputs: &ProgramInputs,
+) -> Result<ProgramOutputs, ExecutionError> {
+    let mut process = FastProcess::new(program.kernel(), inputs.clone());
+    process.execute(program)
+}
+
 /// Returns an iterator that allows callers to step through each execution and inspect
 /// vm state information along side.
 pub fn execute_iter(program: &Program, inputs: &ProgramInputs) -> VmStateIterator {
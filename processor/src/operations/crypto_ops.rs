@@ -1,199 +1,195 @@
-use vm_core::StarkField;
-
-use super::{ExecutionError, Operation, Process};
+use super::{ChipletOps, ExecutionError, Felt, OperandStack, Operation, Process, StarkField};
+use crate::AdviceProvider;
 
 // CRYPTOGRAPHIC OPERATIONS
 // ================================================================================================
 
-impl Process {
-    // HASHING OPERATIONS
-    // --------------------------------------------------------------------------------------------
-    /// Applies Rescue Prime permutation to the top 12 elements of the stack. The stack is assumed
-    /// to be arranged so that the 8 elements of the rate are at the top of the stack. The capacity
-    /// word follows, with the number of elements to be hashed at the deepest position in stack[11].
-    /// For a Rescue Prime permutation of [A, B, C] where A is the capacity, the stack should be
-    /// arranged (from the top) as [C, B, A, ...].
-    pub(super) fn op_rpperm(&mut self) -> Result<(), ExecutionError> {
-        let input_state = [
-            self.stack.get(11),
-            self.stack.get(10),
-            self.stack.get(9),
-            self.stack.get(8),
-            self.stack.get(7),
-            self.stack.get(6),
-            self.stack.get(5),
-            self.stack.get(4),
-            self.stack.get(3),
-            self.stack.get(2),
-            self.stack.get(1),
-            self.stack.get(0),
-        ];
-
-        let (_addr, output_state) = self.chiplets.permute(input_state);
-
-        for (i, &value) in output_state.iter().rev().enumerate() {
-            self.stack.set(i, value);
-        }
-        self.stack.copy_state(12);
-        Ok(())
+// HASHING OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Applies Rescue Prime permutation to the top 12 elements of the stack. The stack is assumed
+/// to be arranged so that the 8 elements of the rate are at the top of the stack. The capacity
+/// word follows, with the number of elements to be hashed at the deepest position in stack[11].
+/// For a Rescue Prime permutation of [A, B, C] where A is the capacity, the stack should be
+/// arranged (from the top) as [C, B, A, ...].
+pub(crate) fn op_rpperm<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+) -> Result<(), ExecutionError> {
+    let input_state = [
+        stack.get(11),
+        stack.get(10),
+        stack.get(9),
+        stack.get(8),
+        stack.get(7),
+        stack.get(6),
+        stack.get(5),
+        stack.get(4),
+        stack.get(3),
+        stack.get(2),
+        stack.get(1),
+        stack.get(0),
+    ];
+
+    let (_addr, output_state) = chiplets.permute(input_state);
+
+    for (i, &value) in output_state.iter().rev().enumerate() {
+        stack.set(i, value);
     }
+    stack.copy_state(12);
+    Ok(())
+}
 
-    // MERKLE TREES
-    // --------------------------------------------------------------------------------------------
-
-    /// Verifies that a Merkle path from the specified node resolves to the specified root. The
-    /// stack is expected to be arranged as follows (from the top):
-    /// - value of the node, 4 elements.
-    /// - depth of the node, 1 element; this is expected to be the depth of the Merkle tree
-    /// - index of the node, 1 element.
-    /// - root of the tree, 4 elements.
-    ///
-    /// To perform the operation we do the following:
-    /// 1. Look up the Merkle path in the advice provider for the specified tree root.
-    /// 2. Use the hasher to compute the root of the Merkle path for the specified node.
-    /// 3. Verify that the computed root is equal to the root provided via the stack.
-    /// 4. Copy the stack state over to the next clock cycle with no changes.
-    ///
-    /// # Errors
-    /// Returns an error if:
-    /// - Merkle tree for the specified root cannot be found in the advice provider.
-    /// - The specified depth is either zero or greater than the depth of the Merkle tree
-    ///   identified by the specified root.
-    /// - Path to the node at the specified depth and index is not known to the advice provider.
-    ///
-    /// # Panics
-    /// Panics if the computed root does not match the root provided via the stack.
-    pub(super) fn op_mpverify(&mut self) -> Result<(), ExecutionError> {
-        // read node value, depth, index and root value from the stack
-        let node = [
-            self.stack.get(3),
-            self.stack.get(2),
-            self.stack.get(1),
-            self.stack.get(0),
-        ];
-        let depth = self.stack.get(4);
-        let index = self.stack.get(5);
-        let provided_root = [
-            self.stack.get(9),
-            self.stack.get(8),
-            self.stack.get(7),
-            self.stack.get(6),
-        ];
+// MERKLE TREES
+// ------------------------------------------------------------------------------------------------
+
+/// Verifies that a Merkle path from the specified node resolves to the specified root. The
+/// stack is expected to be arranged as follows (from the top):
+/// - value of the node, 4 elements.
+/// - depth of the node, 1 element; this is expected to be the depth of the Merkle tree
+/// - index of the node, 1 element.
+/// - root of the tree, 4 elements.
+///
+/// To perform the operation we do the following:
+/// 1. Look up the Merkle path in the advice provider for the specified tree root.
+/// 2. Use the hasher to compute the root of the Merkle path for the specified node.
+/// 3. Verify that the computed root is equal to the root provided via the stack.
+/// 4. Copy the stack state over to the next clock cycle with no changes.
+///
+/// Returns the address of the hasher trace row at which the computation started.
+///
+/// # Errors
+/// Returns an error if:
+/// - Merkle tree for the specified root cannot be found in the advice provider.
+/// - The specified depth is either zero or greater than the depth of the Merkle tree
+///   identified by the specified root.
+/// - Path to the node at the specified depth and index is not known to the advice provider.
+///
+/// # Panics
+/// Panics if the computed root does not match the root provided via the stack.
+pub(crate) fn op_mpverify<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    advice: &mut AdviceProvider,
+) -> Result<Felt, ExecutionError> {
+    // read node value, depth, index and root value from the stack
+    let node = stack.get_word(0);
+    let depth = stack.get(4);
+    let index = stack.get(5);
+    let provided_root = stack.get_word(6);
+
+    // get a Merkle path from the advice provider for the specified root and node index.
+    // the path is expected to be of the specified depth.
+    let path = advice.get_merkle_path(provided_root, depth, index)?;
+
+    // use hasher to compute the Merkle root of the path
+    let (addr, computed_root) = chiplets.build_merkle_root(node, &path, index);
+
+    // Asserting the computed root of the merkle path from the advice provider is consistent with
+    // the input root.
+    assert_eq!(
+        provided_root, computed_root,
+        "inconsistent Merkle tree root"
+    );
+
+    // The same state is copied over to the next clock cycle with no changes.
+    stack.copy_state(0);
+    Ok(addr)
+}
 
-        // get a Merkle path from the advice provider for the specified root and node index.
-        // the path is expected to be of the specified depth.
-        let path = self.advice.get_merkle_path(provided_root, depth, index)?;
+/// Computes a new root of a Merkle tree where a leaf at the specified index is updated to
+/// the specified value. The stack is expected to be arranged as follows (from the top):
+/// - old value of the node, 4 elements.
+/// - depth of the node, 1 element; this is expected to be the depth of the Merkle tree.
+/// - index of the node, 1 element.
+/// - current root of the tree, 4 elements.
+/// - new value of the node, 4 elements.
+///
+/// To perform the operation we do the following:
+/// 1. Update the leaf node at the specified index in the advice provider with the specified
+///    root, and get the Merkle path to this leaf. If `copy` is set to true, we make a copy
+///    of the advice set before updating it.
+/// 2. Use the hasher to update the root of the Merkle path for the specified node. For this
+///    we need to provide the old and the new node value.
+/// 3. Verify that the computed old root is equal to the input root provided via the stack.
+/// 4. Replace the old node value with the computed new root.
+///
+/// The Merkle path for the node is expected to be provided by the prover non-deterministically
+/// (via advice sets). At the end of the operation, the old node value is replaced with the
+/// new root value computed based on the provided path. Everything else on the stack remains the
+/// same.
+///
+/// If `copy` is set to true, at the end of the operation the advice provide will keep both,
+/// the old and the new advice sets. Otherwise, the old advice set is removed from the
+/// provider.
+///
+/// Returns the address of the hasher trace row at which the computation started.
+///
+/// # Errors
+/// Returns an error if:
+/// - Merkle tree for the specified root cannot be found in the advice provider.
+/// - The specified depth is either zero or greater than the depth of the Merkle tree
+///   identified by the specified root.
+/// - Path to the node at the specified depth and index is not known to the advice provider.
+///
+/// # Panics
+/// Panics if the computed old root does not match the input root provided via the stack.
+pub(crate) fn op_mrupdate<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    advice: &mut AdviceProvider,
+    copy: bool,
+) -> Result<Felt, ExecutionError> {
+    // read old node value, depth, index, tree root and new node values from the stack
+    let old_node = stack.get_word(0);
+    let depth = stack.get(4);
+    let index = stack.get(5);
+    let old_root = stack.get_word(6);
+    let new_node = stack.get_word(10);
+
+    // update the leaf at the specified index in the advice set specified by the old root, and
+    // get a Merkle path to the specified leaf. the length of the returned path is expected to
+    // match the specified depth.
+    // TODO: in the future, we should be able to replace sub-trees and not just the leaves,
+    // and, thus, the assert on depth would not be needed.
+    let path = advice.update_merkle_leaf(old_root, index, new_node, copy)?;
+    assert_eq!(path.len(), depth.as_int() as usize);
+
+    // use hasher to update the Merkle root.
+    let (addr, computed_old_root, new_root) =
+        chiplets.update_merkle_root(old_node, new_node, &path, index);
+
+    // Asserts the computed old root of the merkle path from the advice provider is consistent
+    // with the input root provided via the stack. This will panic only if the advice provider
+    // returns a Merkle path inconsistent with the specified root.
+    assert_eq!(old_root, computed_old_root, "inconsistent Merkle tree root");
+
+    // Replace the old node value with computed new root; everything else remains the same.
+    stack.set_top_word(new_root);
+    stack.copy_state(4);
+
+    Ok(addr)
+}
 
-        // use hasher to compute the Merkle root of the path
-        let (addr, computed_root) = self.chiplets.build_merkle_root(node, &path, index);
+// HELPER VALUES
+// ================================================================================================
 
-        // save address(r) of the hasher trace from when the computation starts in the decoder
-        // helper registers.
+impl Process {
+    /// Executes [op_mpverify()] and saves the address of the hasher trace row at which the
+    /// computation started in the decoder helper registers.
+    pub(super) fn op_mpverify(&mut self) -> Result<(), ExecutionError> {
+        let addr = op_mpverify(&mut self.stack, &mut self.chiplets, &mut self.advice)?;
         self.decoder
             .set_user_op_helpers(Operation::MpVerify, &[addr]);
-
-        // Asserting the computed root of the merkle path from the advice provider is consistent with
-        // the input root.
-        assert_eq!(
-            provided_root, computed_root,
-            "inconsistent Merkle tree root"
-        );
-
-        // The same state is copied over to the next clock cycle with no changes.
-        self.stack.copy_state(0);
         Ok(())
     }
 
-    /// Computes a new root of a Merkle tree where a leaf at the specified index is updated to
-    /// the specified value. The stack is expected to be arranged as follows (from the top):
-    /// - old value of the node, 4 elements.
-    /// - depth of the node, 1 element; this is expected to be the depth of the Merkle tree.
-    /// - index of the node, 1 element.
-    /// - current root of the tree, 4 elements.
-    /// - new value of the node, 4 elements.
-    ///
-    /// To perform the operation we do the following:
-    /// 1. Update the leaf node at the specified index in the advice provider with the specified
-    ///    root, and get the Merkle path to this leaf. If `copy` is set to true, we make a copy
-    ///    of the advice set before updating it.
-    /// 2. Use the hasher to update the root of the Merkle path for the specified node. For this
-    ///    we need to provide the old and the new node value.
-    /// 3. Verify that the computed old root is equal to the input root provided via the stack.
-    /// 4. Replace the old node value with the computed new root.
-    ///
-    /// The Merkle path for the node is expected to be provided by the prover non-deterministically
-    /// (via advice sets). At the end of the operation, the old node value is replaced with the
-    /// new root value computed based on the provided path. Everything else on the stack remains the
-    /// same.
-    ///
-    /// If `copy` is set to true, at the end of the operation the advice provide will keep both,
-    /// the old and the new advice sets. Otherwise, the old advice set is removed from the
-    /// provider.
-    ///
-    ///
-    /// # Errors
-    /// Returns an error if:
-    /// - Merkle tree for the specified root cannot be found in the advice provider.
-    /// - The specified depth is either zero or greater than the depth of the Merkle tree
-    ///   identified by the specified root.
-    /// - Path to the node at the specified depth and index is not known to the advice provider.
-    ///
-    /// # Panics
-    /// Panics if the computed old root does not match the input root provided via the stack.
+    /// Executes [op_mrupdate()] and saves the address of the hasher trace row at which the
+    /// computation started in the decoder helper registers.
     pub(super) fn op_mrupdate(&mut self, copy: bool) -> Result<(), ExecutionError> {
-        // read old node value, depth, index, tree root and new node values from the stack
-        let old_node = [
-            self.stack.get(3),
-            self.stack.get(2),
-            self.stack.get(1),
-            self.stack.get(0),
-        ];
-        let depth = self.stack.get(4);
-        let index = self.stack.get(5);
-        let old_root = [
-            self.stack.get(9),
-            self.stack.get(8),
-            self.stack.get(7),
-            self.stack.get(6),
-        ];
-        let new_node = [
-            self.stack.get(13),
-            self.stack.get(12),
-            self.stack.get(11),
-            self.stack.get(10),
-        ];
-
-        // update the leaf at the specified index in the advice set specified by the old root, and
-        // get a Merkle path to the specified leaf. the length of the returned path is expected to
-        // match the specified depth.
-        // TODO: in the future, we should be able to replace sub-trees and not just the leaves,
-        // and, thus, the assert on depth would not be needed.
-        let path = self
-            .advice
-            .update_merkle_leaf(old_root, index, new_node, copy)?;
-        assert_eq!(path.len(), depth.as_int() as usize);
-
-        // use hasher to update the Merkle root.
-        let (addr, computed_old_root, new_root) = self
-            .chiplets
-            .update_merkle_root(old_node, new_node, &path, index);
-
-        // Asserts the computed old root of the merkle path from the advice provider is consistent
-        // with the input root provided via the stack. This will panic only if the advice provider
-        // returns a Merkle path inconsistent with the specified root.
-        assert_eq!(old_root, computed_old_root, "inconsistent Merkle tree root");
-
-        // save address(r) of the hasher trace from when the computation starts in the decoder
-        // helper registers.
+        let addr = op_mrupdate(&mut self.stack, &mut self.chiplets, &mut self.advice, copy)?;
         self.decoder
             .set_user_op_helpers(Operation::MrUpdate(copy), &[addr]);
-
-        // Replace the old node value with computed new root; everything else remains the same.
-        for (i, &value) in new_root.iter().rev().enumerate() {
-            self.stack.set(i, value);
-        }
-        self.stack.copy_state(4);
-
         Ok(())
     }
 }
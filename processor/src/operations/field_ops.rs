@@ -1,214 +1,239 @@
-use super::{utils::assert_binary, ExecutionError, Felt, FieldElement, Process};
-use vm_core::{Operation, StarkField, ZERO};
+use super::{
+    utils::assert_binary, ExecutionError, Felt, FieldElement, OperandStack, Operation, Process,
+};
+use vm_core::{StarkField, ZERO};
 
 // FIELD OPERATIONS
 // ================================================================================================
 
-impl Process {
-    // ARITHMETIC OPERATIONS
-    // --------------------------------------------------------------------------------------------
-    /// Pops two elements off the stack, adds them together, and pushes the result back onto the
-    /// stack.
-    pub(super) fn op_add(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0);
-        let a = self.stack.get(1);
-        self.stack.set(0, a + b);
-        self.stack.shift_left(2);
-        Ok(())
-    }
+// ARITHMETIC OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Pops two elements off the stack, adds them together, and pushes the result back onto the
+/// stack.
+pub(crate) fn op_add<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let b = stack.get(0);
+    let a = stack.get(1);
+    stack.set(0, a + b);
+    stack.shift_left(2);
+    Ok(())
+}
 
-    /// Pops an element off the stack, computes its additive inverse, and pushes the result back
-    /// onto the stack.
-    pub(super) fn op_neg(&mut self) -> Result<(), ExecutionError> {
-        let a = self.stack.get(0);
-        self.stack.set(0, -a);
-        self.stack.copy_state(1);
-        Ok(())
-    }
+/// Pops an element off the stack, computes its additive inverse, and pushes the result back
+/// onto the stack.
+pub(crate) fn op_neg<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let a = stack.get(0);
+    stack.set(0, -a);
+    stack.copy_state(1);
+    Ok(())
+}
 
-    /// Pops two elements off the stack, multiplies them, and pushes the result back onto the
-    /// stack.
-    pub(super) fn op_mul(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0);
-        let a = self.stack.get(1);
-        self.stack.set(0, a * b);
-        self.stack.shift_left(2);
-        Ok(())
+/// Pops two elements off the stack, multiplies them, and pushes the result back onto the
+/// stack.
+pub(crate) fn op_mul<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let b = stack.get(0);
+    let a = stack.get(1);
+    stack.set(0, a * b);
+    stack.shift_left(2);
+    Ok(())
+}
+
+/// Pops an element off the stack, computes its multiplicative inverse, and pushes the result
+/// back onto the stack.
+///
+/// # Errors
+/// Returns an error if the value on the top of the stack is ZERO.
+pub(crate) fn op_inv<S: OperandStack>(stack: &mut S, clk: u32) -> Result<(), ExecutionError> {
+    let a = stack.get(0);
+    if a == Felt::ZERO {
+        return Err(ExecutionError::DivideByZero(clk));
     }
 
-    /// Pops an element off the stack, computes its multiplicative inverse, and pushes the result
-    /// back onto the stack.
-    ///
-    /// # Errors
-    /// Returns an error if the value on the top of the stack is ZERO.
-    pub(super) fn op_inv(&mut self) -> Result<(), ExecutionError> {
-        let a = self.stack.get(0);
-        if a == Felt::ZERO {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
-        }
+    stack.set(0, a.inv());
+    stack.copy_state(1);
+    Ok(())
+}
 
-        self.stack.set(0, a.inv());
-        self.stack.copy_state(1);
-        Ok(())
+/// Pops an element off the stack, adds ONE to it, and pushes the result back onto the stack.
+pub(crate) fn op_incr<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let a = stack.get(0);
+    stack.set(0, a + Felt::ONE);
+    stack.copy_state(1);
+    Ok(())
+}
+
+// BOOLEAN OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Pops two elements off the stack, computes their boolean AND, and pushes the result back
+/// onto the stack.
+///
+/// # Errors
+/// Returns an error if either of the two elements on the top of the stack is not a binary
+/// value.
+pub(crate) fn op_and<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let b = assert_binary(stack.get(0))?;
+    let a = assert_binary(stack.get(1))?;
+    if a == Felt::ONE && b == Felt::ONE {
+        stack.set(0, Felt::ONE);
+    } else {
+        stack.set(0, Felt::ZERO);
     }
+    stack.shift_left(2);
+    Ok(())
+}
 
-    /// Pops an element off the stack, adds ONE to it, and pushes the result back onto the stack.
-    pub(super) fn op_incr(&mut self) -> Result<(), ExecutionError> {
-        let a = self.stack.get(0);
-        self.stack.set(0, a + Felt::ONE);
-        self.stack.copy_state(1);
-        Ok(())
+/// Pops two elements off the stack, computes their boolean OR, and pushes the result back
+/// onto the stack.
+///
+/// # Errors
+/// Returns an error if either of the two elements on the top of the stack is not a binary
+/// value.
+pub(crate) fn op_or<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let b = assert_binary(stack.get(0))?;
+    let a = assert_binary(stack.get(1))?;
+    if a == Felt::ONE || b == Felt::ONE {
+        stack.set(0, Felt::ONE);
+    } else {
+        stack.set(0, Felt::ZERO);
     }
+    stack.shift_left(2);
+    Ok(())
+}
 
-    // BOOLEAN OPERATIONS
-    // --------------------------------------------------------------------------------------------
+/// Pops an element off the stack, computes its boolean NOT, and pushes the result back onto
+/// the stack.
+///
+/// # Errors
+/// Returns an error if the value on the top of the stack is not a binary value.
+pub(crate) fn op_not<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let a = assert_binary(stack.get(0))?;
+    stack.set(0, Felt::ONE - a);
+    stack.copy_state(1);
+    Ok(())
+}
 
-    /// Pops two elements off the stack, computes their boolean AND, and pushes the result back
-    /// onto the stack.
-    ///
-    /// # Errors
-    /// Returns an error if either of the two elements on the top of the stack is not a binary
-    /// value.
-    pub(super) fn op_and(&mut self) -> Result<(), ExecutionError> {
-        let b = assert_binary(self.stack.get(0))?;
-        let a = assert_binary(self.stack.get(1))?;
-        if a == Felt::ONE && b == Felt::ONE {
-            self.stack.set(0, Felt::ONE);
-        } else {
-            self.stack.set(0, Felt::ZERO);
-        }
-        self.stack.shift_left(2);
-        Ok(())
+// COMPARISON OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Pops two elements off the stack and compares them. If the elements are equal, pushes ONE
+/// onto the stack, otherwise pushes ZERO onto the stack.
+///
+/// Returns the value of the helper variable which is provided by the prover.
+pub(crate) fn op_eq<S: OperandStack>(stack: &mut S) -> Result<Felt, ExecutionError> {
+    let b = stack.get(0);
+    let a = stack.get(1);
+
+    // helper variable provided by the prover. If top elements are same, then, it can be set to anything
+    // otherwise set it to the reciprocal of the difference between the top two elements.
+    let mut h0 = ZERO;
+
+    if a == b {
+        stack.set(0, Felt::ONE);
+    } else {
+        stack.set(0, Felt::ZERO);
+        // setting h0 to the inverse of the difference between the top two elements of the stack.
+        h0 = (b - a).inv();
     }
 
-    /// Pops two elements off the stack, computes their boolean OR, and pushes the result back
-    /// onto the stack.
-    ///
-    /// # Errors
-    /// Returns an error if either of the two elements on the top of the stack is not a binary
-    /// value.
-    pub(super) fn op_or(&mut self) -> Result<(), ExecutionError> {
-        let b = assert_binary(self.stack.get(0))?;
-        let a = assert_binary(self.stack.get(1))?;
-        if a == Felt::ONE || b == Felt::ONE {
-            self.stack.set(0, Felt::ONE);
-        } else {
-            self.stack.set(0, Felt::ZERO);
-        }
-        self.stack.shift_left(2);
-        Ok(())
-    }
+    stack.shift_left(2);
+    Ok(h0)
+}
 
-    /// Pops an element off the stack, computes its boolean NOT, and pushes the result back onto
-    /// the stack.
-    ///
-    /// # Errors
-    /// Returns an error if the value on the top of the stack is not a binary value.
-    pub(super) fn op_not(&mut self) -> Result<(), ExecutionError> {
-        let a = assert_binary(self.stack.get(0))?;
-        self.stack.set(0, Felt::ONE - a);
-        self.stack.copy_state(1);
-        Ok(())
+/// Pops an element off the stack and compares it to ZERO. If the element is ZERO, pushes ONE
+/// onto the stack, otherwise pushes ZERO onto the stack.
+///
+/// Returns the value of the helper variable which is provided by the prover.
+pub(crate) fn op_eqz<S: OperandStack>(stack: &mut S) -> Result<Felt, ExecutionError> {
+    let a = stack.get(0);
+
+    // helper variable provided by the prover. If the top element is zero, then, h0 can be set to anything
+    // otherwise set it to the inverse of the top element in the stack.
+    let mut h0 = ZERO;
+
+    if a == Felt::ZERO {
+        stack.set(0, Felt::ONE);
+    } else {
+        // setting h0 to the inverse of the top element of the stack.
+        h0 = a.inv();
+        stack.set(0, Felt::ZERO);
     }
 
-    // COMPARISON OPERATIONS
-    // --------------------------------------------------------------------------------------------
+    stack.copy_state(1);
+    Ok(h0)
+}
 
-    /// Pops two elements off the stack and compares them. If the elements are equal, pushes ONE
-    /// onto the stack, otherwise pushes ZERO onto the stack.
-    pub(super) fn op_eq(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0);
-        let a = self.stack.get(1);
-
-        // helper variable provided by the prover. If top elements are same, then, it can be set to anything
-        // otherwise set it to the reciprocal of the difference between the top two elements.
-        let mut h0 = ZERO;
-
-        if a == b {
-            self.stack.set(0, Felt::ONE);
-        } else {
-            self.stack.set(0, Felt::ZERO);
-            // setting h0 to the inverse of the difference between the top two elements of the stack.
-            h0 = (b - a).inv();
-        }
+/// Computes a single turn of exp accumulation for the given inputs. The top 4 elements in the
+/// stack is arranged as follows (from the top):
+/// - least significant bit of the exponent in the previous trace if there's an expacc call,
+/// otherwise ZERO
+/// - exponent of base for this turn
+/// - accumulated power of base so far
+/// - number which needs to be shifted to the right
+///
+/// To perform the operation we do the following:
+/// 1. Pops top three elements off the stack and calculate the least significant bit of the
+/// number `b`.
+/// 2. Use this bit to decide if the current `base` raise to the power exponent needs to be
+/// included in the accumulator.
+/// 3. Update exponent with its square and the number b with one right shift.
+/// 4. Pushes the calcuted new values to the stack in the mentioned order.
+///
+/// Returns the value which was incorporated into the accumulator.
+pub(crate) fn op_expacc<S: OperandStack>(stack: &mut S) -> Result<Felt, ExecutionError> {
+    let mut exp = stack.get(1);
+    let mut acc = stack.get(2);
+    let mut b = stack.get(3);
+
+    // least significant bit of the number b.
+    let bit = b.as_int() & 1;
+
+    // value which would be incorporated in the accumulator.
+    let value = Felt::new((exp.as_int() - 1) * bit + 1);
+
+    // current value of acc after including the value based on whether the bit is
+    // 1 or not.
+    acc *= value;
+
+    // number `b` shifted right by one bit.
+    b = Felt::new(b.as_int() >> 1);
+
+    // exponent updated with its square.
+    exp *= exp;
+
+    stack.set(0, Felt::new(bit));
+    stack.set(1, exp);
+    stack.set(2, acc);
+    stack.set(3, b);
+    stack.copy_state(4);
+
+    Ok(value)
+}
 
-        // save h0 in the decoder helper register.
-        self.decoder.set_user_op_helpers(Operation::Eq, &[h0]);
+// HELPER VALUES
+// ================================================================================================
 
-        self.stack.shift_left(2);
+impl Process {
+    /// Executes [op_eq()] and saves the helper variable in the decoder helper register.
+    pub(super) fn op_eq(&mut self) -> Result<(), ExecutionError> {
+        let h0 = op_eq(&mut self.stack)?;
+        self.decoder.set_user_op_helpers(Operation::Eq, &[h0]);
         Ok(())
     }
 
-    /// Pops an element off the stack and compares it to ZERO. If the element is ZERO, pushes ONE
-    /// onto the stack, otherwise pushes ZERO onto the stack.
+    /// Executes [op_eqz()] and saves the helper variable in the decoder helper register.
     pub(super) fn op_eqz(&mut self) -> Result<(), ExecutionError> {
-        let a = self.stack.get(0);
-
-        // helper variable provided by the prover. If the top element is zero, then, h0 can be set to anything
-        // otherwise set it to the inverse of the top element in the stack.
-        let mut h0 = ZERO;
-
-        if a == Felt::ZERO {
-            self.stack.set(0, Felt::ONE);
-        } else {
-            // setting h0 to the inverse of the top element of the stack.
-            h0 = a.inv();
-            self.stack.set(0, Felt::ZERO);
-        }
-
-        // save h0 in the decoder helper register.
+        let h0 = op_eqz(&mut self.stack)?;
         self.decoder.set_user_op_helpers(Operation::Eq, &[h0]);
-
-        self.stack.copy_state(1);
         Ok(())
     }
 
-    /// Computes a single turn of exp accumulation for the given inputs. The top 4 elements in the
-    /// stack is arranged as follows (from the top):
-    /// - least significant bit of the exponent in the previous trace if there's an expacc call,
-    /// otherwise ZERO
-    /// - exponent of base for this turn
-    /// - accumulated power of base so far
-    /// - number which needs to be shifted to the right
-    ///
-    /// To perform the operation we do the following:
-    /// 1. Pops top three elements off the stack and calculate the least significant bit of the
-    /// number `b`.
-    /// 2. Use this bit to decide if the current `base` raise to the power exponent needs to be
-    /// included in the accumulator.
-    /// 3. Update exponent with its square and the number b with one right shift.
-    /// 4. Pushes the calcuted new values to the stack in the mentioned order.
+    /// Executes [op_expacc()] and saves the value incorporated into the accumulator in the
+    /// decoder helper register.
     pub(super) fn op_expacc(&mut self) -> Result<(), ExecutionError> {
-        let mut exp = self.stack.get(1);
-        let mut acc = self.stack.get(2);
-        let mut b = self.stack.get(3);
-
-        // least significant bit of the number b.
-        let bit = b.as_int() & 1;
-
-        // value which would be incorporated in the accumulator.
-        let value = Felt::new((exp.as_int() - 1) * bit + 1);
-
-        // current value of acc after including the value based on whether the bit is
-        // 1 or not.
-        acc *= value;
-
-        // number `b` shifted right by one bit.
-        b = Felt::new(b.as_int() >> 1);
-
-        // exponent updated with its square.
-        exp *= exp;
-
-        // save val in the decoder helper register.
+        let value = op_expacc(&mut self.stack)?;
         self.decoder
             .set_user_op_helpers(Operation::Expacc, &[value]);
-
-        self.stack.set(0, Felt::new(bit));
-        self.stack.set(1, exp);
-        self.stack.set(2, acc);
-        self.stack.set(3, b);
-        self.stack.copy_state(4);
-
         Ok(())
     }
 }
@@ -220,7 +245,7 @@ impl Process {
 mod tests {
     use super::{
         super::{Felt, FieldElement, Operation, StarkField, STACK_TOP_SIZE},
-        Process,
+        OperandStack, Process,
     };
     use rand_utils::rand_value;
     use vm_core::{ProgramInputs, ONE, ZERO};
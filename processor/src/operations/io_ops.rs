@@ -1,4 +1,5 @@
-use super::{ExecutionError, Felt, Operation, Process};
+use super::{ChipletOps, ExecutionError, Felt, OperandStack, Operation, Process, Word};
+use crate::AdviceProvider;
 
 // CONSTANTS
 // ================================================================================================
@@ -8,254 +9,273 @@ const TWO: Felt = Felt::new(2);
 // INPUT / OUTPUT OPERATIONS
 // ================================================================================================
 
-impl Process {
-    // CONSTANT INPUTS
-    // --------------------------------------------------------------------------------------------
-
-    /// Pushes the provided value onto the stack.
-    ///
-    /// The original stack is shifted to the right by one item.
-    pub(super) fn op_push(&mut self, value: Felt) -> Result<(), ExecutionError> {
-        self.stack.set(0, value);
-        self.stack.shift_right(0);
-        Ok(())
-    }
+// CONSTANT INPUTS
+// ------------------------------------------------------------------------------------------------
 
-    // MEMORY READING AND WRITING
-    // --------------------------------------------------------------------------------------------
+/// Pushes the provided value onto the stack.
+///
+/// The original stack is shifted to the right by one item.
+pub(crate) fn op_push<S: OperandStack>(stack: &mut S, value: Felt) -> Result<(), ExecutionError> {
+    stack.set(0, value);
+    stack.shift_right(0);
+    Ok(())
+}
 
-    /// Loads a word (4 elements) from the specified memory address onto the stack.
-    ///
-    /// The operation works as follows:
-    /// - The memory address is popped off the stack.
-    /// - A word is retrieved from memory at the specified address. The memory is always
-    ///   initialized to ZEROs, and thus, if the specified address has never been written to,
-    ///   four ZERO elements are returned.
-    /// - The top four elements of the stack are overwritten with values retrieved from memory.
-    ///
-    /// Thus, the net result of the operation is that the stack is shifted left by one item.
-    pub(super) fn op_mloadw(&mut self) -> Result<(), ExecutionError> {
-        // get the address from the stack and read the word from current memory context
-        let ctx = self.system.ctx();
-        let addr = self.stack.get(0);
-        let word = self.chiplets.read_mem(ctx, addr);
-
-        // reverse the order of the memory word & update the stack state
-        for (i, &value) in word.iter().rev().enumerate() {
-            self.stack.set(i, value);
-        }
-        self.stack.shift_left(5);
+// MEMORY READING AND WRITING
+// ------------------------------------------------------------------------------------------------
+
+/// Loads a word (4 elements) from the specified memory address onto the stack.
+///
+/// The operation works as follows:
+/// - The memory address is popped off the stack.
+/// - A word is retrieved from memory at the specified address. The memory is always
+///   initialized to ZEROs, and thus, if the specified address has never been written to,
+///   four ZERO elements are returned.
+/// - The top four elements of the stack are overwritten with values retrieved from memory.
+///
+/// Thus, the net result of the operation is that the stack is shifted left by one item.
+pub(crate) fn op_mloadw<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    ctx: u32,
+) -> Result<(), ExecutionError> {
+    // get the address from the stack and read the word from current memory context
+    let addr = stack.get(0);
+    let word = chiplets.read_mem(ctx, addr);
+
+    // reverse the order of the memory word & update the stack state
+    stack.set_top_word(word);
+    stack.shift_left(5);
+
+    Ok(())
+}
 
-        Ok(())
-    }
+/// Loads the first element from the specified memory address onto the stack.
+///
+/// The operation works as follows:
+/// - The memory address is popped off the stack.
+/// - A word is retrieved from memory at the specified address. The memory is always
+///   initialized to ZEROs, and thus, if the specified address has never been written to,
+///   four ZERO elements are returned.
+/// - The first element of the word retrieved from memory is pushed to the top of the stack.
+///
+/// Returns the word retrieved from memory in stack order, i.e., with its last element first.
+pub(crate) fn op_mload<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    ctx: u32,
+) -> Result<Word, ExecutionError> {
+    // get the address from the stack and read the word from memory
+    let addr = stack.get(0);
+    let mut word = chiplets.read_mem(ctx, addr);
+    // put the retrieved word into stack order
+    word.reverse();
+
+    // update the stack state
+    stack.set(0, word[3]);
+    stack.copy_state(1);
+
+    Ok(word)
+}
 
-    /// Loads the first element from the specified memory address onto the stack.
-    ///
-    /// The operation works as follows:
-    /// - The memory address is popped off the stack.
-    /// - A word is retrieved from memory at the specified address. The memory is always
-    ///   initialized to ZEROs, and thus, if the specified address has never been written to,
-    ///   four ZERO elements are returned.
-    /// - The first element of the word retrieved from memory is pushed to the top of the stack.
-    ///
-    /// The first 3 helper registers are filled with the elements of the word which were not pushed
-    /// to the stack. They are stored in stack order, with the last element of the word in helper
-    /// register 0.
-    pub(super) fn op_mload(&mut self) -> Result<(), ExecutionError> {
-        // get the address from the stack and read the word from memory
-        let ctx = self.system.ctx();
-        let addr = self.stack.get(0);
-        let mut word = self.chiplets.read_mem(ctx, addr);
-        // put the retrieved word into stack order
-        word.reverse();
+/// Loads two words from memory and adds their contents to the top 8 elements of the stack.
+///
+/// The operation works as follows:
+/// - The memory address of the first word is retrieved from 13th stack element (position 12).
+/// - Two consecutive words, starting at this address, are loaded from memory.
+/// - Elements of these words are added to the top 8 elements of the stack (element-wise, in
+///   stack order).
+/// - Memory address (in position 12) is incremented by 2.
+/// - All other stack elements remain the same.
+pub(crate) fn op_mstream<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    ctx: u32,
+) -> Result<(), ExecutionError> {
+    // get the address from position 12 on the stack
+    let addr = stack.get(12);
+
+    // load two words from memory
+    let words = chiplets.read_mem_double(ctx, addr);
+
+    add_double_word(stack, words, addr);
+    Ok(())
+}
 
-        // update the stack state
-        self.stack.set(0, word[3]);
-        self.stack.copy_state(1);
+/// Stores a word (4 elements) from the stack into the specified memory address.
+///
+/// The operation works as follows:
+/// - The memory address is popped off the stack.
+/// - The top four stack items are saved into the specified memory address. The items are not
+///   removed from the stack.
+///
+/// Thus, the net result of the operation is that the stack is shifted left by one item.
+pub(crate) fn op_mstorew<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    ctx: u32,
+) -> Result<(), ExecutionError> {
+    // get the address from the stack and build the word to be saved from the stack values; the
+    // word is built in memory order (reverse of stack order)
+    let addr = stack.get(0);
+    let word = stack.get_word(1);
+
+    // write the word to memory
+    chiplets.write_mem(ctx, addr, word);
+
+    // reverse the order of the memory word & update the stack state
+    stack.set_top_word(word);
+    stack.shift_left(5);
+
+    Ok(())
+}
 
-        // write the 3 unused elements to the helpers so they're available for constraint evaluation
-        self.decoder
-            .set_user_op_helpers(Operation::MLoad, &word[..3]);
+/// Stores an element from the stack into the first slot at the specified memory address.
+///
+/// The operation works as follows:
+/// - The memory address is popped off the stack.
+/// - The top stack element is saved into the first element of the word located at the specified
+/// memory address. The remaining 3 elements of the word are not affected. The element is not
+/// removed from the stack.
+///
+/// Thus, the net result of the operation is that the stack is shifted left by one item.
+///
+/// Returns the word which was previously stored in memory in stack order, i.e., with its last
+/// element first.
+pub(crate) fn op_mstore<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    ctx: u32,
+) -> Result<Word, ExecutionError> {
+    // get the address and the value from the stack
+    let addr = stack.get(0);
+    let value = stack.get(1);
+
+    // write the value to the memory and get the previous word
+    let mut old_word = chiplets.write_mem_element(ctx, addr, value);
+    // put the retrieved word into stack order
+    old_word.reverse();
+
+    // update the stack state
+    stack.shift_left(1);
+
+    Ok(old_word)
+}
 
-        Ok(())
-    }
+/// Moves 8 elements from the head of the advice tape to memory via the stack.
+///
+/// The operation works as follows:
+/// - Two words are read from the head of the advice tape.
+/// - The destination memory address for the first word is retrieved from the 13th stack element
+///   (position 12).
+/// - The two words are written to memory consecutively, starting at this address.
+/// - Elements of these words are added to the top 8 elements of the stack (element-wise, in
+///   stack order).
+/// - Memory address (in position 12) is incremented by 2.
+/// - All other stack elements remain the same.
+pub(crate) fn op_pipe<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+    advice: &mut AdviceProvider,
+    ctx: u32,
+) -> Result<(), ExecutionError> {
+    // get the address from position 12 on the stack
+    let addr = stack.get(12);
+
+    // read two words from the advice tape
+    let words = advice.read_tape_double()?;
+
+    // write the words memory
+    chiplets.write_mem_double(ctx, addr, words);
+
+    add_double_word(stack, words, addr);
+    Ok(())
+}
 
-    /// Loads two words from memory and adds their contents to the top 8 elements of the stack.
-    ///
-    /// The operation works as follows:
-    /// - The memory address of the first word is retrieved from 13th stack element (position 12).
-    /// - Two consecutive words, starting at this address, are loaded from memory.
-    /// - Elements of these words are added to the top 8 elements of the stack (element-wise, in
-    ///   stack order).
-    /// - Memory address (in position 12) is incremented by 2.
-    /// - All other stack elements remain the same.
-    pub(super) fn op_mstream(&mut self) -> Result<(), ExecutionError> {
-        // get the address from position 12 on the stack
-        let ctx = self.system.ctx();
-        let addr = self.stack.get(12);
-
-        // load two words from memory
-        let words = self.chiplets.read_mem_double(ctx, addr);
-
-        // add word elements to the elements already on the stack (in stack order)
-        for (i, &mem_value) in words.iter().flat_map(|word| word.iter()).rev().enumerate() {
-            let stack_value = self.stack.get(i);
-            self.stack.set(i, stack_value + mem_value);
-        }
+// ADVICE INPUTS
+// ------------------------------------------------------------------------------------------------
+
+/// Removes the next element from the advice tape and pushes it onto the stack.
+///
+/// # Errors
+/// Returns an error if the advice tape is empty.
+pub(crate) fn op_read<S: OperandStack>(
+    stack: &mut S,
+    advice: &mut AdviceProvider,
+) -> Result<(), ExecutionError> {
+    let value = advice.read_tape()?;
+    stack.set(0, value);
+    stack.shift_right(0);
+    Ok(())
+}
 
-        // copy over the next 4 elements
-        for i in 8..12 {
-            let stack_value = self.stack.get(i);
-            self.stack.set(i, stack_value);
-        }
+/// Removes a word (4 elements) from the advice tape and overwrites the top four stack
+/// elements with it.
+///
+/// # Errors
+/// Returns an error if the advice tape contains fewer than four elements.
+pub(crate) fn op_readw<S: OperandStack>(
+    stack: &mut S,
+    advice: &mut AdviceProvider,
+) -> Result<(), ExecutionError> {
+    let word = advice.read_tapew()?;
+
+    stack.set_top_word(word);
+    stack.copy_state(4);
+
+    Ok(())
+}
 
-        // increment the address by 2
-        self.stack.set(12, addr + TWO);
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
 
-        // copy over the rest of the stack
-        self.stack.copy_state(13);
+/// Adds elements of the provided words to the top 8 elements of the stack (element-wise, in stack
+/// order), and replaces the memory address at position 12 with the address incremented by 2.
+fn add_double_word<S: OperandStack>(stack: &mut S, words: [Word; 2], addr: Felt) {
+    // add word elements to the elements already on the stack (in stack order)
+    for (i, &value) in words.iter().flat_map(|word| word.iter()).rev().enumerate() {
+        let stack_value = stack.get(i);
+        stack.set(i, stack_value + value);
+    }
 
-        Ok(())
+    // copy over the next 4 elements
+    for i in 8..12 {
+        let stack_value = stack.get(i);
+        stack.set(i, stack_value);
     }
 
-    /// Stores a word (4 elements) from the stack into the specified memory address.
-    ///
-    /// The operation works as follows:
-    /// - The memory address is popped off the stack.
-    /// - The top four stack items are saved into the specified memory address. The items are not
-    ///   removed from the stack.
-    ///
-    /// Thus, the net result of the operation is that the stack is shifted left by one item.
-    pub(super) fn op_mstorew(&mut self) -> Result<(), ExecutionError> {
-        // get the address from the stack and build the word to be saved from the stack values
-        let ctx = self.system.ctx();
-        let addr = self.stack.get(0);
-
-        // build the word in memory order (reverse of stack order)
-        let word = [
-            self.stack.get(4),
-            self.stack.get(3),
-            self.stack.get(2),
-            self.stack.get(1),
-        ];
-
-        // write the word to memory and get the previous word
-        self.chiplets.write_mem(ctx, addr, word);
-
-        // reverse the order of the memory word & update the stack state
-        for (i, &value) in word.iter().rev().enumerate() {
-            self.stack.set(i, value);
-        }
-        self.stack.shift_left(5);
+    // increment the address by 2
+    stack.set(12, addr + TWO);
 
-        Ok(())
-    }
+    // copy over the rest of the stack
+    stack.copy_state(13);
+}
 
-    /// Stores an element from the stack into the first slot at the specified memory address.
-    ///
-    /// The operation works as follows:
-    /// - The memory address is popped off the stack.
-    /// - The top stack element is saved into the first element of the word located at the specified
-    /// memory address. The remaining 3 elements of the word are not affected. The element is not
-    /// removed from the stack.
-    ///
-    /// Thus, the net result of the operation is that the stack is shifted left by one item.
-    ///
-    /// The first 3 helper registers are filled with the remaining elements of the word which were
-    /// previously stored in memory and not overwritten by the operation. They are stored in stack
-    /// order, with the last element at helper register 0.
-    pub(super) fn op_mstore(&mut self) -> Result<(), ExecutionError> {
-        // get the address and the value from the stack
-        let ctx = self.system.ctx();
-        let addr = self.stack.get(0);
-        let value = self.stack.get(1);
+// HELPER VALUES
+// ================================================================================================
 
-        // write the value to the memory and get the previous word
-        let mut old_word = self.chiplets.write_mem_element(ctx, addr, value);
-        // put the retrieved word into stack order
-        old_word.reverse();
+impl Process {
+    /// Executes [op_mload()] and writes the 3 elements of the word which were not pushed onto the
+    /// stack to the decoder helper registers, with the last element of the word in helper
+    /// register 0.
+    pub(super) fn op_mload(&mut self) -> Result<(), ExecutionError> {
+        let word = op_mload(&mut self.stack, &mut self.chiplets, self.system.ctx())?;
 
         // write the 3 unused elements to the helpers so they're available for constraint evaluation
         self.decoder
-            .set_user_op_helpers(Operation::MStore, &old_word[..3]);
-
-        // update the stack state
-        self.stack.shift_left(1);
-
-        Ok(())
-    }
-
-    /// Moves 8 elements from the head of the advice tape to memory via the stack.
-    ///
-    /// The operation works as follows:
-    /// - Two words are read from the head of the advice tape.
-    /// - The destination memory address for the first word is retrieved from the 13th stack element
-    ///   (position 12).
-    /// - The two words are written to memory consecutively, starting at this address.
-    /// - Elements of these words are added to the top 8 elements of the stack (element-wise, in
-    ///   stack order).
-    /// - Memory address (in position 12) is incremented by 2.
-    /// - All other stack elements remain the same.
-    pub(super) fn op_pipe(&mut self) -> Result<(), ExecutionError> {
-        // get the address from position 12 on the stack
-        let ctx = self.system.ctx();
-        let addr = self.stack.get(12);
-
-        // read two words from the advice tape
-        let words = self.advice.read_tape_double()?;
-
-        // write the words memory
-        self.chiplets.write_mem_double(ctx, addr, words);
-
-        // add word elements to the elements already on the stack (in stack order)
-        for (i, &adv_value) in words.iter().flat_map(|word| word.iter()).rev().enumerate() {
-            let stack_value = self.stack.get(i);
-            self.stack.set(i, stack_value + adv_value);
-        }
-
-        // copy over the next 4 elements
-        for i in 8..12 {
-            let stack_value = self.stack.get(i);
-            self.stack.set(i, stack_value);
-        }
-
-        // increment the address by 2
-        self.stack.set(12, addr + TWO);
-
-        // copy over the rest of the stack
-        self.stack.copy_state(13);
-
-        Ok(())
-    }
-
-    // ADVICE INPUTS
-    // --------------------------------------------------------------------------------------------
-
-    /// Removes the next element from the advice tape and pushes it onto the stack.
-    ///
-    /// # Errors
-    /// Returns an error if the advice tape is empty.
-    pub(super) fn op_read(&mut self) -> Result<(), ExecutionError> {
-        let value = self.advice.read_tape()?;
-        self.stack.set(0, value);
-        self.stack.shift_right(0);
+            .set_user_op_helpers(Operation::MLoad, &word[..3]);
         Ok(())
     }
 
-    /// Removes a word (4 elements) from the advice tape and overwrites the top four stack
-    /// elements with it.
-    ///
-    /// # Errors
-    /// Returns an error if the advice tape contains fewer than four elements.
-    pub(super) fn op_readw(&mut self) -> Result<(), ExecutionError> {
-        let word = self.advice.read_tapew()?;
-
-        self.stack.set(0, word[3]);
-        self.stack.set(1, word[2]);
-        self.stack.set(2, word[1]);
-        self.stack.set(3, word[0]);
-        self.stack.copy_state(4);
+    /// Executes [op_mstore()] and writes the 3 elements of the previously stored word which were
+    /// not overwritten to the decoder helper registers, with the last element of the word in
+    /// helper register 0.
+    pub(super) fn op_mstore(&mut self) -> Result<(), ExecutionError> {
+        let old_word = op_mstore(&mut self.stack, &mut self.chiplets, self.system.ctx())?;
 
+        // write the 3 unused elements to the helpers so they're available for constraint evaluation
+        self.decoder
+            .set_user_op_helpers(Operation::MStore, &old_word[..3]);
         Ok(())
     }
 }
@@ -267,7 +287,7 @@ impl Process {
 mod tests {
     use super::{
         super::{Operation, STACK_TOP_SIZE},
-        Felt, Process,
+        ChipletOps, Felt, OperandStack, Process,
     };
     use vm_core::{utils::ToElements, Word, ONE, ZERO};
 
@@ -1,14 +1,24 @@
-use super::{ExecutionError, Felt, FieldElement, Operation, Process, StarkField};
-use vm_core::stack::STACK_TOP_SIZE;
-
-mod crypto_ops;
-mod field_ops;
-mod io_ops;
-mod stack_ops;
-mod sys_ops;
-mod u32_ops;
+use super::{ExecutionError, Felt, FieldElement, Operation, Process, StarkField, Word};
+use vm_core::{chiplets::hasher::HasherState, stack::STACK_TOP_SIZE};
+
+pub(crate) mod crypto_ops;
+pub(crate) mod field_ops;
+pub(crate) mod io_ops;
+pub(crate) mod stack_ops;
+pub(crate) mod sys_ops;
+pub(crate) mod u32_ops;
 mod utils;
 
+use crypto_ops::op_rpperm;
+use field_ops::{op_add, op_and, op_incr, op_inv, op_mul, op_neg, op_not, op_or};
+use io_ops::{op_mloadw, op_mstorew, op_mstream, op_pipe, op_push, op_read, op_readw};
+use stack_ops::{
+    op_cswap, op_cswapw, op_drop, op_dup, op_movdn, op_movup, op_pad, op_swap, op_swapdw, op_swapw,
+    op_swapw2, op_swapw3,
+};
+use sys_ops::{op_assert, op_caller, op_fmpadd, op_sdepth};
+use u32_ops::{op_u32and, op_u32xor};
+
 #[cfg(test)]
 use super::Kernel;
 
@@ -17,21 +27,33 @@ use super::Kernel;
 
 impl Process {
     /// Executes the specified operation.
+    ///
+    /// Semantics of operations are defined by the functions in the `*_ops` modules, which are
+    /// shared with the [FastProcess](crate::FastProcess). Operations which provide values to the
+    /// decoder's helper registers or to the range checker are executed via methods of the process
+    /// which record these values.
     pub(super) fn execute_op(&mut self, op: Operation) -> Result<(), ExecutionError> {
         // make sure there is enough memory allocated to hold the execution trace
         self.ensure_trace_capacity();
 
+        let clk = self.system.clk();
+        let ctx = self.system.ctx();
+
         // execute the operation
         match op {
             // ----- system operations ------------------------------------------------------------
             Operation::Noop => self.stack.copy_state(0),
-            Operation::Assert => self.op_assert()?,
+            Operation::Assert => op_assert(&mut self.stack, clk)?,
 
-            Operation::FmpAdd => self.op_fmpadd()?,
+            Operation::FmpAdd => op_fmpadd(&mut self.stack, self.system.fmp())?,
             Operation::FmpUpdate => self.op_fmpupdate()?,
 
-            Operation::SDepth => self.op_sdepth()?,
-            Operation::Caller => self.op_caller()?,
+            Operation::SDepth => op_sdepth(&mut self.stack)?,
+            Operation::Caller => op_caller(
+                &mut self.stack,
+                self.system.in_syscall(),
+                self.system.fn_hash(),
+            )?,
 
             // ----- flow control operations ------------------------------------------------------
             // control flow operations are never executed directly
@@ -47,15 +69,15 @@ impl Process {
             Operation::Halt => unreachable!("control flow operation"),
 
             // ----- field operations -------------------------------------------------------------
-            Operation::Add => self.op_add()?,
-            Operation::Neg => self.op_neg()?,
-            Operation::Mul => self.op_mul()?,
-            Operation::Inv => self.op_inv()?,
-            Operation::Incr => self.op_incr()?,
+            Operation::Add => op_add(&mut self.stack)?,
+            Operation::Neg => op_neg(&mut self.stack)?,
+            Operation::Mul => op_mul(&mut self.stack)?,
+            Operation::Inv => op_inv(&mut self.stack, clk)?,
+            Operation::Incr => op_incr(&mut self.stack)?,
 
-            Operation::And => self.op_and()?,
-            Operation::Or => self.op_or()?,
-            Operation::Not => self.op_not()?,
+            Operation::And => op_and(&mut self.stack)?,
+            Operation::Or => op_or(&mut self.stack)?,
+            Operation::Not => op_not(&mut self.stack)?,
 
             Operation::Eq => self.op_eq()?,
             Operation::Eqz => self.op_eqz()?,
@@ -71,69 +93,69 @@ impl Process {
             Operation::U32madd => self.op_u32madd()?,
             Operation::U32div => self.op_u32div()?,
 
-            Operation::U32and => self.op_u32and()?,
-            Operation::U32xor => self.op_u32xor()?,
+            Operation::U32and => op_u32and(&mut self.stack, &mut self.chiplets)?,
+            Operation::U32xor => op_u32xor(&mut self.stack, &mut self.chiplets)?,
             Operation::U32assert2 => self.op_u32assert2()?,
 
             // ----- stack manipulation -----------------------------------------------------------
-            Operation::Pad => self.op_pad()?,
-            Operation::Drop => self.op_drop()?,
-
-            Operation::Dup0 => self.op_dup(0)?,
-            Operation::Dup1 => self.op_dup(1)?,
-            Operation::Dup2 => self.op_dup(2)?,
-            Operation::Dup3 => self.op_dup(3)?,
-            Operation::Dup4 => self.op_dup(4)?,
-            Operation::Dup5 => self.op_dup(5)?,
-            Operation::Dup6 => self.op_dup(6)?,
-            Operation::Dup7 => self.op_dup(7)?,
-            Operation::Dup9 => self.op_dup(9)?,
-            Operation::Dup11 => self.op_dup(11)?,
-            Operation::Dup13 => self.op_dup(13)?,
-            Operation::Dup15 => self.op_dup(15)?,
-
-            Operation::Swap => self.op_swap()?,
-            Operation::SwapW => self.op_swapw()?,
-            Operation::SwapW2 => self.op_swapw2()?,
-            Operation::SwapW3 => self.op_swapw3()?,
-            Operation::SwapDW => self.op_swapdw()?,
-
-            Operation::MovUp2 => self.op_movup(2)?,
-            Operation::MovUp3 => self.op_movup(3)?,
-            Operation::MovUp4 => self.op_movup(4)?,
-            Operation::MovUp5 => self.op_movup(5)?,
-            Operation::MovUp6 => self.op_movup(6)?,
-            Operation::MovUp7 => self.op_movup(7)?,
-            Operation::MovUp8 => self.op_movup(8)?,
-
-            Operation::MovDn2 => self.op_movdn(2)?,
-            Operation::MovDn3 => self.op_movdn(3)?,
-            Operation::MovDn4 => self.op_movdn(4)?,
-            Operation::MovDn5 => self.op_movdn(5)?,
-            Operation::MovDn6 => self.op_movdn(6)?,
-            Operation::MovDn7 => self.op_movdn(7)?,
-            Operation::MovDn8 => self.op_movdn(8)?,
-
-            Operation::CSwap => self.op_cswap()?,
-            Operation::CSwapW => self.op_cswapw()?,
+            Operation::Pad => op_pad(&mut self.stack)?,
+            Operation::Drop => op_drop(&mut self.stack)?,
+
+            Operation::Dup0 => op_dup(&mut self.stack, 0)?,
+            Operation::Dup1 => op_dup(&mut self.stack, 1)?,
+            Operation::Dup2 => op_dup(&mut self.stack, 2)?,
+            Operation::Dup3 => op_dup(&mut self.stack, 3)?,
+            Operation::Dup4 => op_dup(&mut self.stack, 4)?,
+            Operation::Dup5 => op_dup(&mut self.stack, 5)?,
+            Operation::Dup6 => op_dup(&mut self.stack, 6)?,
+            Operation::Dup7 => op_dup(&mut self.stack, 7)?,
+            Operation::Dup9 => op_dup(&mut self.stack, 9)?,
+            Operation::Dup11 => op_dup(&mut self.stack, 11)?,
+            Operation::Dup13 => op_dup(&mut self.stack, 13)?,
+            Operation::Dup15 => op_dup(&mut self.stack, 15)?,
+
+            Operation::Swap => op_swap(&mut self.stack)?,
+            Operation::SwapW => op_swapw(&mut self.stack)?,
+            Operation::SwapW2 => op_swapw2(&mut self.stack)?,
+            Operation::SwapW3 => op_swapw3(&mut self.stack)?,
+            Operation::SwapDW => op_swapdw(&mut self.stack)?,
+
+            Operation::MovUp2 => op_movup(&mut self.stack, 2)?,
+            Operation::MovUp3 => op_movup(&mut self.stack, 3)?,
+            Operation::MovUp4 => op_movup(&mut self.stack, 4)?,
+            Operation::MovUp5 => op_movup(&mut self.stack, 5)?,
+            Operation::MovUp6 => op_movup(&mut self.stack, 6)?,
+            Operation::MovUp7 => op_movup(&mut self.stack, 7)?,
+            Operation::MovUp8 => op_movup(&mut self.stack, 8)?,
+
+            Operation::MovDn2 => op_movdn(&mut self.stack, 2)?,
+            Operation::MovDn3 => op_movdn(&mut self.stack, 3)?,
+            Operation::MovDn4 => op_movdn(&mut self.stack, 4)?,
+            Operation::MovDn5 => op_movdn(&mut self.stack, 5)?,
+            Operation::MovDn6 => op_movdn(&mut self.stack, 6)?,
+            Operation::MovDn7 => op_movdn(&mut self.stack, 7)?,
+            Operation::MovDn8 => op_movdn(&mut self.stack, 8)?,
+
+            Operation::CSwap => op_cswap(&mut self.stack)?,
+            Operation::CSwapW => op_cswapw(&mut self.stack)?,
 
             // ----- input / output ---------------------------------------------------------------
-            Operation::Push(value) => self.op_push(value)?,
+            Operation::Push(value) => op_push(&mut self.stack, value)?,
 
-            Operation::Read => self.op_read()?,
-            Operation::ReadW => self.op_readw()?,
+            Operation::Read => op_read(&mut self.stack, &mut self.advice)?,
+            Operation::ReadW => op_readw(&mut self.stack, &mut self.advice)?,
 
-            Operation::MLoadW => self.op_mloadw()?,
-            Operation::MStoreW => self.op_mstorew()?,
+            Operation::MLoadW => op_mloadw(&mut self.stack, &mut self.chiplets, ctx)?,
+            Operation::MStoreW => op_mstorew(&mut self.stack, &mut self.chiplets, ctx)?,
 
             Operation::MLoad => self.op_mload()?,
             Operation::MStore => self.op_mstore()?,
 
-            Operation::MStream => self.op_mstream()?,
-            Operation::Pipe => self.op_pipe()?,
+            Operation::MStream => op_mstream(&mut self.stack, &mut self.chiplets, ctx)?,
+            Operation::Pipe => op_pipe(&mut self.stack, &mut self.chiplets, &mut self.advice, ctx)?,
 
             // ----- cryptographic operations -----------------------------------------------------
-            Operation::RpPerm => self.op_rpperm()?,
+            Operation::RpPerm => op_rpperm(&mut self.stack, &mut self.chiplets)?,
             Operation::MpVerify => self.op_mpverify()?,
             Operation::MrUpdate(copy) => self.op_mrupdate(copy)?,
         }
@@ -199,3 +221,155 @@ impl Process {
         process
     }
 }
+
+// OPERAND STACK
+// ================================================================================================
+
+/// Defines how operations read and update the operand stack.
+///
+/// Semantics of operations are expressed in terms of this trait, and thus, the same operations
+/// can be executed against the processor's stack (which records an execution trace) as well as
+/// against the stack of the fast processor (which keeps track of the current state only).
+///
+/// At every clock cycle, values are read from the current state of the stack, while the state at
+/// the next clock cycle is built via [OperandStack::set()], [OperandStack::copy_state()],
+/// [OperandStack::shift_left()], and [OperandStack::shift_right()].
+pub trait OperandStack {
+    /// Returns depth of the stack at the current clock cycle.
+    fn depth(&self) -> usize;
+
+    /// Returns the value located at the specified position on the stack at the current clock
+    /// cycle.
+    fn get(&self, pos: usize) -> Felt;
+
+    /// Sets the value at the specified position on the stack at the next clock cycle.
+    fn set(&mut self, pos: usize, value: Felt);
+
+    /// Copies stack values starting at the specified position at the current clock cycle to the
+    /// same position at the next clock cycle.
+    fn copy_state(&mut self, start_pos: usize);
+
+    /// Copies stack values starting at the specified position at the current clock cycle to
+    /// position - 1 at the next clock cycle.
+    ///
+    /// If the stack depth is greater than 16, an item is moved from the overflow table to the
+    /// "in-memory" portion of the stack. If the stack depth is 16, the 16th element of the
+    /// stack is set to ZERO.
+    fn shift_left(&mut self, start_pos: usize);
+
+    /// Copies stack values starting at the specified position at the current clock cycle to
+    /// position + 1 at the next clock cycle
+    ///
+    /// If stack depth grows beyond 16 items, the additional item is pushed into the overflow table.
+    fn shift_right(&mut self, start_pos: usize);
+
+    /// Returns the word located on the stack starting at the specified position at the current
+    /// clock cycle. The word is created in reverse order, so that the element at the specified
+    /// position will be at the last position in the word.
+    fn get_word(&self, pos: usize) -> Word {
+        [
+            self.get(pos + 3),
+            self.get(pos + 2),
+            self.get(pos + 1),
+            self.get(pos),
+        ]
+    }
+
+    /// Sets the top four stack items at the next clock cycle to the specified word. The word is
+    /// placed onto the stack in reverse order, so that the last element of the word will be at the
+    /// top of the stack.
+    fn set_top_word(&mut self, word: Word) {
+        for (i, &value) in word.iter().rev().enumerate() {
+            self.set(i, value);
+        }
+    }
+}
+
+// CHIPLET OPERATIONS
+// ================================================================================================
+
+/// Defines computations and memory accesses which operations delegate to the chiplets.
+///
+/// This is implemented by the processor's chiplets (which record the execution traces of these
+/// computations), and by the fast processor (which computes the results directly).
+pub trait ChipletOps {
+    /// Applies a single permutation of the hash function to the provided state.
+    ///
+    /// The returned tuple contains the row address of the hasher execution trace at which the
+    /// permutation started and the hasher state after the permutation.
+    fn permute(&mut self, state: HasherState) -> (Felt, HasherState);
+
+    /// Computes the root of the specified Merkle path for the node with the specified value
+    /// located at the specified index.
+    ///
+    /// The returned tuple contains the row address of the hasher execution trace at which the
+    /// computation started and the root of the Merkle path.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - The provided path does not contain any nodes.
+    /// - The provided index is out of range for the specified path.
+    fn build_merkle_root(&mut self, value: Word, path: &[Word], index: Felt) -> (Felt, Word);
+
+    /// Computes the roots of the specified Merkle path for the old value and the new value of the
+    /// node located at the specified index.
+    ///
+    /// The returned tuple contains the row address of the hasher execution trace at which the
+    /// computation started, and the roots computed for the old value and for the new value.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - The provided path does not contain any nodes.
+    /// - The provided index is out of range for the specified path.
+    fn update_merkle_root(
+        &mut self,
+        old_value: Word,
+        new_value: Word,
+        path: &[Word],
+        index: Felt,
+    ) -> (Felt, Word, Word);
+
+    /// Computes a bitwise AND of `a` and `b`.
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is not a 32-bit value.
+    fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError>;
+
+    /// Computes a bitwise XOR of `a` and `b`.
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is not a 32-bit value.
+    fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError>;
+
+    /// Returns a word located in memory at the specified context/address.
+    ///
+    /// If the specified address hasn't been previously written to, four ZERO elements are
+    /// returned. This effectively implies that memory is initialized to ZERO.
+    fn read_mem(&mut self, ctx: u32, addr: Felt) -> Word;
+
+    /// Returns two words read from consecutive addresses started with `addr` in the specified
+    /// context.
+    ///
+    /// If either of the accessed addresses hasn't been previously written to, ZERO elements are
+    /// returned. This effectively implies that memory is initialized to ZERO.
+    fn read_mem_double(&mut self, ctx: u32, addr: Felt) -> [Word; 2];
+
+    /// Writes the provided word at the specified context/address.
+    fn write_mem(&mut self, ctx: u32, addr: Felt, word: Word);
+
+    /// Writes the provided element into the specified context/address leaving the remaining 3
+    /// elements of the word previously stored at that address unchanged. Returns the word which
+    /// was previously stored at that address.
+    fn write_mem_element(&mut self, ctx: u32, addr: Felt, value: Felt) -> Word;
+
+    /// Writes the two provided words to two consecutive addresses in memory in the specified
+    /// context, starting at the specified address.
+    fn write_mem_double(&mut self, ctx: u32, addr: Felt, words: [Word; 2]);
+
+    /// Returns a word located at the specified context/address, or None if the address hasn't
+    /// been accessed previously.
+    ///
+    /// Unlike [ChipletOps::read_mem()], this does not count as a memory access (e.g., it is not
+    /// recorded in the memory trace).
+    fn get_mem_value(&self, ctx: u32, addr: u64) -> Option<Word>;
+}
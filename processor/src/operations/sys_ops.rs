@@ -1,92 +1,101 @@
 use super::{
     super::system::{FMP_MAX, FMP_MIN},
-    ExecutionError, Felt, FieldElement, Process, StarkField,
+    ExecutionError, Felt, FieldElement, OperandStack, Process, StarkField, Word,
 };
 
 // SYSTEM OPERATIONS
 // ================================================================================================
 
-impl Process {
-    /// Pops a value off the stack and asserts that it is equal to ONE.
-    ///
-    /// # Errors
-    /// Returns an error if the popped value is not ONE.
-    pub(super) fn op_assert(&mut self) -> Result<(), ExecutionError> {
-        if self.stack.get(0) != Felt::ONE {
-            return Err(ExecutionError::FailedAssertion(self.system.clk()));
-        }
-        self.stack.shift_left(1);
-        Ok(())
+/// Pops a value off the stack and asserts that it is equal to ONE.
+///
+/// # Errors
+/// Returns an error if the popped value is not ONE.
+pub(crate) fn op_assert<S: OperandStack>(stack: &mut S, clk: u32) -> Result<(), ExecutionError> {
+    if stack.get(0) != Felt::ONE {
+        return Err(ExecutionError::FailedAssertion(clk));
     }
+    stack.shift_left(1);
+    Ok(())
+}
 
-    // FREE MEMORY POINTER
-    // --------------------------------------------------------------------------------------------
+// FREE MEMORY POINTER
+// ------------------------------------------------------------------------------------------------
 
-    /// Pops an element off the stack, adds the current value of the `fmp` register to it, and
-    /// pushes the result back onto the stack.
-    pub(super) fn op_fmpadd(&mut self) -> Result<(), ExecutionError> {
-        let offset = self.stack.get(0);
-        let fmp = self.system.fmp();
+/// Pops an element off the stack, adds the current value of the `fmp` register to it, and
+/// pushes the result back onto the stack.
+pub(crate) fn op_fmpadd<S: OperandStack>(stack: &mut S, fmp: Felt) -> Result<(), ExecutionError> {
+    let offset = stack.get(0);
 
-        self.stack.set(0, fmp + offset);
-        self.stack.copy_state(1);
+    stack.set(0, fmp + offset);
+    stack.copy_state(1);
 
-        Ok(())
+    Ok(())
+}
+
+/// Pops an element off the stack and adds it to the current value of `fmp` register. Returns the
+/// new value of the `fmp` register.
+///
+/// # Errors
+/// Returns an error if the new value of `fmp` register is greater than or equal to 3 * 2^30.
+pub(crate) fn op_fmpupdate<S: OperandStack>(
+    stack: &mut S,
+    fmp: Felt,
+) -> Result<Felt, ExecutionError> {
+    let offset = stack.get(0);
+
+    let new_fmp = fmp + offset;
+    if new_fmp.as_int() < FMP_MIN || new_fmp.as_int() > FMP_MAX {
+        return Err(ExecutionError::InvalidFmpValue(fmp, new_fmp));
     }
 
-    /// Pops an element off the stack and adds it to the current value of `fmp` register.
-    ///
-    /// # Errors
-    /// Returns an error if the new value of `fmp` register is greater than or equal to 3 * 2^30.
-    pub(super) fn op_fmpupdate(&mut self) -> Result<(), ExecutionError> {
-        let offset = self.stack.get(0);
-        let fmp = self.system.fmp();
+    stack.shift_left(1);
 
-        let new_fmp = fmp + offset;
-        if new_fmp.as_int() < FMP_MIN || new_fmp.as_int() > FMP_MAX {
-            return Err(ExecutionError::InvalidFmpValue(fmp, new_fmp));
-        }
+    Ok(new_fmp)
+}
 
-        self.system.set_fmp(new_fmp);
-        self.stack.shift_left(1);
+// STACK DEPTH
+// ------------------------------------------------------------------------------------------------
 
-        Ok(())
-    }
-
-    // STACK DEPTH
-    // --------------------------------------------------------------------------------------------
+/// Pushes the current depth of the stack (the depth before this operation is executed) onto
+/// the stack.
+pub(crate) fn op_sdepth<S: OperandStack>(stack: &mut S) -> Result<(), ExecutionError> {
+    let stack_depth = stack.depth();
+    stack.set(0, Felt::new(stack_depth as u64));
+    stack.shift_right(0);
+    Ok(())
+}
 
-    /// Pushes the current depth of the stack (the depth before this operation is executed) onto
-    /// the stack.
-    pub(super) fn op_sdepth(&mut self) -> Result<(), ExecutionError> {
-        let stack_depth = self.stack.depth();
-        self.stack.set(0, Felt::new(stack_depth as u64));
-        self.stack.shift_right(0);
-        Ok(())
+// CALLER
+// ------------------------------------------------------------------------------------------------
+
+/// Overwrites the top four stack items with the hash of a function which initiated the current
+/// SYSCALL.
+///
+/// # Errors
+/// Returns an error if the VM is not currently executing a SYSCALL block.
+pub(crate) fn op_caller<S: OperandStack>(
+    stack: &mut S,
+    in_syscall: bool,
+    fn_hash: Word,
+) -> Result<(), ExecutionError> {
+    if !in_syscall {
+        return Err(ExecutionError::CallerNotInSyscall);
     }
 
-    // CALLER
-    // --------------------------------------------------------------------------------------------
-
-    /// Overwrites the top four stack items with the hash of a function which initiated the current
-    /// SYSCALL.
-    ///
-    /// # Errors
-    /// Returns an error if the VM is not currently executing a SYSCALL block.
-    pub(super) fn op_caller(&mut self) -> Result<(), ExecutionError> {
-        if !self.system.in_syscall() {
-            return Err(ExecutionError::CallerNotInSyscall);
-        }
+    stack.set_top_word(fn_hash);
+    stack.copy_state(4);
 
-        let fn_hash = self.system.fn_hash();
-
-        self.stack.set(0, fn_hash[3]);
-        self.stack.set(1, fn_hash[2]);
-        self.stack.set(2, fn_hash[1]);
-        self.stack.set(3, fn_hash[0]);
+    Ok(())
+}
 
-        self.stack.copy_state(4);
+// FREE MEMORY POINTER UPDATES
+// ================================================================================================
 
+impl Process {
+    /// Executes [op_fmpupdate()] and sets the `fmp` register to the new value.
+    pub(super) fn op_fmpupdate(&mut self) -> Result<(), ExecutionError> {
+        let new_fmp = op_fmpupdate(&mut self.stack, self.system.fmp())?;
+        self.system.set_fmp(new_fmp);
         Ok(())
     }
 }
@@ -97,7 +106,8 @@ impl Process {
 #[cfg(test)]
 mod tests {
     use super::{
-        super::Operation, super::STACK_TOP_SIZE, Felt, FieldElement, Process, FMP_MAX, FMP_MIN,
+        super::Operation, super::STACK_TOP_SIZE, Felt, FieldElement, OperandStack, Process,
+        FMP_MAX, FMP_MIN,
     };
 
     const MAX_PROC_LOCALS: u64 = 2_u64.pow(31) - 1;
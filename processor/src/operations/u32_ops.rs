@@ -1,188 +1,253 @@
-use super::{ExecutionError, Felt, FieldElement, Operation, Process, StarkField};
+use super::{
+    ChipletOps, ExecutionError, Felt, FieldElement, OperandStack, Operation, Process, StarkField,
+};
 use crate::utils::{split_element, split_u32_into_u16};
 
-impl Process {
-    // CASTING OPERATIONS
-    // --------------------------------------------------------------------------------------------
+// U32 OPERATIONS
+// ================================================================================================
+//
+// Operations which range-check their results return the low and the high values which need to be
+// range-checked (see [Process::add_range_checks()]).
+
+// CASTING OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Pops the top element off the stack, splits it into low and high 32-bit values, and pushes
+/// these values back onto the stack.
+pub(crate) fn op_u32split<S: OperandStack>(stack: &mut S) -> Result<(Felt, Felt), ExecutionError> {
+    let a = stack.get(0);
+    let (hi, lo) = split_element(a);
+
+    stack.set(0, hi);
+    stack.set(1, lo);
+    stack.shift_right(1);
+    Ok((lo, hi))
+}
 
-    /// Pops the top element off the stack, splits it into low and high 32-bit values, and pushes
-    /// these values back onto the stack.
-    pub(super) fn op_u32split(&mut self) -> Result<(), ExecutionError> {
-        let a = self.stack.get(0);
-        let (hi, lo) = split_element(a);
+/// Pops top two element off the stack, splits both into low and high 32-bit values, checks if both
+/// high are equal to 0, if it passes, put both of them onto the stack, else throws an execution error
+pub(crate) fn op_u32assert2<S: OperandStack>(
+    stack: &mut S,
+) -> Result<(Felt, Felt), ExecutionError> {
+    let a = stack.get(0);
+    let b = stack.get(1);
 
-        self.add_range_checks(Operation::U32split, lo, hi, true);
+    if a.as_int() >> 32 != 0 {
+        return Err(ExecutionError::NotU32Value(a));
+    }
+    if b.as_int() >> 32 != 0 {
+        return Err(ExecutionError::NotU32Value(b));
+    }
 
-        self.stack.set(0, hi);
-        self.stack.set(1, lo);
-        self.stack.shift_right(1);
-        Ok(())
+    stack.copy_state(0);
+    Ok((a, b))
+}
+
+// ARITHMETIC OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Pops two elements off the stack, adds them, splits the result into low and high 32-bit
+/// values, and pushes these values back onto the stack.
+pub(crate) fn op_u32add<S: OperandStack>(stack: &mut S) -> Result<(Felt, Felt), ExecutionError> {
+    let b = stack.get(0);
+    let a = stack.get(1);
+    let result = a + b;
+    let (hi, lo) = split_element(result);
+
+    stack.set(0, hi);
+    stack.set(1, lo);
+    stack.copy_state(2);
+    Ok((lo, hi))
+}
+
+/// Pops three elements off the stack, adds them, splits the result into low and high 32-bit
+/// values, and pushes these values back onto the stack.
+pub(crate) fn op_u32add3<S: OperandStack>(stack: &mut S) -> Result<(Felt, Felt), ExecutionError> {
+    let c = stack.get(0).as_int();
+    let b = stack.get(1).as_int();
+    let a = stack.get(2).as_int();
+    let result = Felt::new(a + b + c);
+    let (hi, lo) = split_element(result);
+
+    stack.set(0, hi);
+    stack.set(1, lo);
+    stack.shift_left(3);
+    Ok((lo, hi))
+}
+
+/// Pops two elements off the stack, subtracts the top element from the second element, and
+/// pushes the result as well as a flag indicating whether there was underflow back onto the
+/// stack.
+pub(crate) fn op_u32sub<S: OperandStack>(stack: &mut S) -> Result<(Felt, Felt), ExecutionError> {
+    let b = stack.get(0).as_int();
+    let a = stack.get(1).as_int();
+    let result = a.wrapping_sub(b);
+    let d = Felt::new(result >> 63);
+    let c = Felt::new((result as u32) as u64);
+
+    stack.set(0, d);
+    stack.set(1, c);
+    stack.copy_state(2);
+
+    // Force this operation to consume 4 range checks, even though only `lo` is needed.
+    // This is required for making the constraints more uniform and grouping the opcodes of
+    // operations requiring range checks under a common degree-4 prefix.
+    Ok((c, Felt::ZERO))
+}
+
+/// Pops two elements off the stack, multiplies them, splits the result into low and high
+/// 32-bit values, and pushes these values back onto the stack.
+pub(crate) fn op_u32mul<S: OperandStack>(stack: &mut S) -> Result<(Felt, Felt), ExecutionError> {
+    let b = stack.get(0).as_int();
+    let a = stack.get(1).as_int();
+    let result = Felt::new(a * b);
+    let (hi, lo) = split_element(result);
+
+    stack.set(0, hi);
+    stack.set(1, lo);
+    stack.copy_state(2);
+    Ok((lo, hi))
+}
+
+/// Pops three elements off the stack, multiplies the first two and adds the third element to
+/// the result, splits the result into low and high 32-bit values, and pushes these values
+/// back onto the stack.
+pub(crate) fn op_u32madd<S: OperandStack>(stack: &mut S) -> Result<(Felt, Felt), ExecutionError> {
+    let b = stack.get(0).as_int();
+    let a = stack.get(1).as_int();
+    let c = stack.get(2).as_int();
+    let result = Felt::new(a * b + c);
+    let (hi, lo) = split_element(result);
+
+    stack.set(0, hi);
+    stack.set(1, lo);
+    stack.shift_left(3);
+    Ok((lo, hi))
+}
+
+/// Pops two elements off the stack, divides the second element by the top element, and pushes
+/// the quotient and the remainder back onto the stack.
+///
+/// # Errors
+/// Returns an error if the divisor is ZERO.
+pub(crate) fn op_u32div<S: OperandStack>(
+    stack: &mut S,
+    clk: u32,
+) -> Result<(Felt, Felt), ExecutionError> {
+    let b = stack.get(0).as_int();
+    let a = stack.get(1).as_int();
+
+    if b == 0 {
+        return Err(ExecutionError::DivideByZero(clk));
     }
 
-    /// Pops top two element off the stack, splits both into low and high 32-bit values, checks if both
-    /// high are equal to 0, if it passes, put both of them onto the stack, else throws an execution error
-    pub(super) fn op_u32assert2(&mut self) -> Result<(), ExecutionError> {
-        let a = self.stack.get(0);
-        let b = self.stack.get(1);
+    let q = a / b;
+    let r = a - q * b;
 
-        if a.as_int() >> 32 != 0 {
-            return Err(ExecutionError::NotU32Value(a));
-        }
-        if b.as_int() >> 32 != 0 {
-            return Err(ExecutionError::NotU32Value(b));
-        }
+    stack.set(0, Felt::new(r));
+    stack.set(1, Felt::new(q));
+    stack.copy_state(2);
 
-        self.add_range_checks(Operation::U32assert2, a, b, false);
+    // These range checks help enforce that q <= a.
+    let lo = Felt::new(a - q);
+    // These range checks help enforce that r < b.
+    let hi = Felt::new(b - r - 1);
+    Ok((lo, hi))
+}
+
+// BITWISE OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Pops two elements off the stack, computes their bitwise AND, and pushes the result back
+/// onto the stack.
+pub(crate) fn op_u32and<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+) -> Result<(), ExecutionError> {
+    let b = stack.get(0);
+    let a = stack.get(1);
+    let result = chiplets.u32and(a, b)?;
+
+    stack.set(0, result);
+    stack.shift_left(2);
+
+    Ok(())
+}
+
+/// Pops two elements off the stack, computes their bitwise XOR, and pushes the result back onto
+/// the stack.
+pub(crate) fn op_u32xor<S: OperandStack, C: ChipletOps>(
+    stack: &mut S,
+    chiplets: &mut C,
+) -> Result<(), ExecutionError> {
+    let b = stack.get(0);
+    let a = stack.get(1);
+    let result = chiplets.u32xor(a, b)?;
+
+    stack.set(0, result);
+    stack.shift_left(2);
+
+    Ok(())
+}
+
+// RANGE CHECKS
+// ================================================================================================
 
-        self.stack.copy_state(0);
+impl Process {
+    /// Executes [op_u32split()] and range-checks its results.
+    pub(super) fn op_u32split(&mut self) -> Result<(), ExecutionError> {
+        let (lo, hi) = op_u32split(&mut self.stack)?;
+        self.add_range_checks(Operation::U32split, lo, hi, true);
         Ok(())
     }
 
-    // ARITHMETIC OPERATIONS
-    // --------------------------------------------------------------------------------------------
+    /// Executes [op_u32assert2()] and range-checks its inputs.
+    pub(super) fn op_u32assert2(&mut self) -> Result<(), ExecutionError> {
+        let (a, b) = op_u32assert2(&mut self.stack)?;
+        self.add_range_checks(Operation::U32assert2, a, b, false);
+        Ok(())
+    }
 
-    /// Pops two elements off the stack, adds them, splits the result into low and high 32-bit
-    /// values, and pushes these values back onto the stack.
+    /// Executes [op_u32add()] and range-checks its results.
     pub(super) fn op_u32add(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0);
-        let a = self.stack.get(1);
-        let result = a + b;
-        let (hi, lo) = split_element(result);
-
+        let (lo, hi) = op_u32add(&mut self.stack)?;
         self.add_range_checks(Operation::U32add, lo, hi, false);
-
-        self.stack.set(0, hi);
-        self.stack.set(1, lo);
-        self.stack.copy_state(2);
         Ok(())
     }
 
-    /// Pops three elements off the stack, adds them, splits the result into low and high 32-bit
-    /// values, and pushes these values back onto the stack.
+    /// Executes [op_u32add3()] and range-checks its results.
     pub(super) fn op_u32add3(&mut self) -> Result<(), ExecutionError> {
-        let c = self.stack.get(0).as_int();
-        let b = self.stack.get(1).as_int();
-        let a = self.stack.get(2).as_int();
-        let result = Felt::new(a + b + c);
-        let (hi, lo) = split_element(result);
-
+        let (lo, hi) = op_u32add3(&mut self.stack)?;
         self.add_range_checks(Operation::U32add3, lo, hi, false);
-
-        self.stack.set(0, hi);
-        self.stack.set(1, lo);
-        self.stack.shift_left(3);
         Ok(())
     }
 
-    /// Pops two elements off the stack, subtracts the top element from the second element, and
-    /// pushes the result as well as a flag indicating whether there was underflow back onto the
-    /// stack.
+    /// Executes [op_u32sub()] and range-checks its result.
     pub(super) fn op_u32sub(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0).as_int();
-        let a = self.stack.get(1).as_int();
-        let result = a.wrapping_sub(b);
-        let d = Felt::new(result >> 63);
-        let c = Felt::new((result as u32) as u64);
-
-        // Force this operation to consume 4 range checks, even though only `lo` is needed.
-        // This is required for making the constraints more uniform and grouping the opcodes of
-        // operations requiring range checks under a common degree-4 prefix.
-        self.add_range_checks(Operation::U32sub, c, Felt::ZERO, false);
-
-        self.stack.set(0, d);
-        self.stack.set(1, c);
-        self.stack.copy_state(2);
+        let (lo, hi) = op_u32sub(&mut self.stack)?;
+        self.add_range_checks(Operation::U32sub, lo, hi, false);
         Ok(())
     }
 
-    /// Pops two elements off the stack, multiplies them, splits the result into low and high
-    /// 32-bit values, and pushes these values back onto the stack.
+    /// Executes [op_u32mul()] and range-checks its results.
     pub(super) fn op_u32mul(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0).as_int();
-        let a = self.stack.get(1).as_int();
-        let result = Felt::new(a * b);
-        let (hi, lo) = split_element(result);
-
+        let (lo, hi) = op_u32mul(&mut self.stack)?;
         self.add_range_checks(Operation::U32mul, lo, hi, true);
-
-        self.stack.set(0, hi);
-        self.stack.set(1, lo);
-        self.stack.copy_state(2);
         Ok(())
     }
 
-    /// Pops three elements off the stack, multiplies the first two and adds the third element to
-    /// the result, splits the result into low and high 32-bit values, and pushes these values
-    /// back onto the stack.
+    /// Executes [op_u32madd()] and range-checks its results.
     pub(super) fn op_u32madd(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0).as_int();
-        let a = self.stack.get(1).as_int();
-        let c = self.stack.get(2).as_int();
-        let result = Felt::new(a * b + c);
-        let (hi, lo) = split_element(result);
-
+        let (lo, hi) = op_u32madd(&mut self.stack)?;
         self.add_range_checks(Operation::U32madd, lo, hi, true);
-
-        self.stack.set(0, hi);
-        self.stack.set(1, lo);
-        self.stack.shift_left(3);
         Ok(())
     }
 
-    /// Pops two elements off the stack, divides the second element by the top element, and pushes
-    /// the quotient and the remainder back onto the stack.
-    ///
-    /// # Errors
-    /// Returns an error if the divisor is ZERO.
+    /// Executes [op_u32div()] and range-checks the values which enforce that the quotient and the
+    /// remainder are valid.
     pub(super) fn op_u32div(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0).as_int();
-        let a = self.stack.get(1).as_int();
-
-        if b == 0 {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
-        }
-
-        let q = a / b;
-        let r = a - q * b;
-
-        // These range checks help enforce that q <= a.
-        let lo = Felt::new(a - q);
-        // These range checks help enforce that r < b.
-        let hi = Felt::new(b - r - 1);
+        let (lo, hi) = op_u32div(&mut self.stack, self.system.clk())?;
         self.add_range_checks(Operation::U32div, lo, hi, false);
-
-        self.stack.set(0, Felt::new(r));
-        self.stack.set(1, Felt::new(q));
-        self.stack.copy_state(2);
-        Ok(())
-    }
-
-    // BITWISE OPERATIONS
-    // --------------------------------------------------------------------------------------------
-
-    /// Pops two elements off the stack, computes their bitwise AND, and pushes the result back
-    /// onto the stack.
-    pub(super) fn op_u32and(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0);
-        let a = self.stack.get(1);
-        let result = self.chiplets.u32and(a, b)?;
-
-        self.stack.set(0, result);
-        self.stack.shift_left(2);
-
-        Ok(())
-    }
-
-    /// Pops two elements off the stack, computes their bitwise XOR, and pushes the result back onto
-    /// the stack.
-    pub(super) fn op_u32xor(&mut self) -> Result<(), ExecutionError> {
-        let b = self.stack.get(0);
-        let a = self.stack.get(1);
-        let result = self.chiplets.u32xor(a, b)?;
-
-        self.stack.set(0, result);
-        self.stack.shift_left(2);
-
         Ok(())
     }
 
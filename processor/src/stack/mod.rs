@@ -1,9 +1,9 @@
 use super::{
-    BTreeMap, Felt, FieldElement, ProgramInputs, ProgramOutputs, Vec, ONE, STACK_TRACE_WIDTH, ZERO,
+    BTreeMap, Felt, FieldElement, OperandStack, ProgramInputs, ProgramOutputs, Vec, ONE,
+    STACK_TRACE_WIDTH, ZERO,
 };
 use core::cmp;
 use vm_core::stack::STACK_TOP_SIZE;
-use vm_core::Word;
 
 mod trace;
 use trace::StackTrace;
@@ -103,11 +103,6 @@ impl Stack {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns depth of the stack at the current clock cycle.
-    pub fn depth(&self) -> usize {
-        self.active_depth
-    }
-
     /// Returns the current clock cycle of the execution trace.
     pub fn current_clk(&self) -> u32 {
         self.clk
@@ -147,98 +142,6 @@ impl Stack {
         ProgramOutputs::from_elements(stack_items, self.overflow.get_addrs())
     }
 
-    // TRACE ACCESSORS AND MUTATORS
-    // --------------------------------------------------------------------------------------------
-
-    /// Returns the value located at the specified position on the stack at the current clock cycle.
-    pub fn get(&self, pos: usize) -> Felt {
-        debug_assert!(pos < STACK_TOP_SIZE, "stack underflow");
-        self.trace.get_stack_value_at(self.clk, pos)
-    }
-
-    /// Returns four values located at the top of the stack. The word is created in reverse order,
-    /// so that the top element of the stack will be at the last position in the word. Creating a
-    /// word does not change the state of the stack.
-    pub fn get_top_word(&self) -> Word {
-        [self.get(3), self.get(2), self.get(1), self.get(0)]
-    }
-
-    /// Sets the value at the specified position on the stack at the next clock cycle.
-    pub fn set(&mut self, pos: usize, value: Felt) {
-        debug_assert!(pos < STACK_TOP_SIZE, "stack underflow");
-        self.trace.set_stack_value_at(self.clk + 1, pos, value);
-    }
-
-    /// Copies stack values starting at the specified position at the current clock cycle to the
-    /// same position at the next clock cycle.
-    pub fn copy_state(&mut self, start_pos: usize) {
-        self.trace.copy_stack_state_at(
-            self.clk,
-            start_pos,
-            Felt::from(self.active_depth as u64),
-            self.overflow.last_row_addr(),
-        );
-    }
-
-    /// Copies stack values starting at the specified position at the current clock cycle to
-    /// position - 1 at the next clock cycle.
-    ///
-    /// If the stack depth is greater than 16, an item is moved from the overflow table to the
-    /// "in-memory" portion of the stack. If the stack depth is 16, the 16th element of the
-    /// stack is set to ZERO.
-    pub fn shift_left(&mut self, start_pos: usize) {
-        debug_assert!(start_pos > 0, "start position must be greater than 0");
-        debug_assert!(
-            start_pos < STACK_TOP_SIZE,
-            "start position cannot exceed stack top size"
-        );
-
-        match self.active_depth {
-            0..=MAX_TOP_IDX => unreachable!("stack underflow"),
-            STACK_TOP_SIZE => {
-                // Shift in a ZERO, to prevent depth shrinking below the minimum stack depth.
-                self.trace
-                    .stack_shift_left_at(self.clk, start_pos, ZERO, None);
-            }
-            _ => {
-                // Update the stack & overflow table.
-                let from_overflow = self.overflow.pop(self.clk as u64);
-                self.trace.stack_shift_left_at(
-                    self.clk,
-                    start_pos,
-                    from_overflow,
-                    Some(self.overflow.last_row_addr()),
-                );
-
-                // Stack depth only decreases when it is greater than the minimum stack depth.
-                self.active_depth -= 1;
-                self.full_depth -= 1;
-            }
-        }
-    }
-
-    /// Copies stack values starting at the specified position at the current clock cycle to
-    /// position + 1 at the next clock cycle
-    ///
-    /// If stack depth grows beyond 16 items, the additional item is pushed into the overflow table.
-    pub fn shift_right(&mut self, start_pos: usize) {
-        debug_assert!(
-            start_pos < STACK_TOP_SIZE,
-            "start position cannot exceed stack top size"
-        );
-
-        // Update the stack.
-        self.trace.stack_shift_right_at(self.clk, start_pos);
-
-        // Update the overflow table.
-        let to_overflow = self.trace.get_stack_value_at(self.clk, MAX_TOP_IDX);
-        self.overflow.push(to_overflow, self.clk as u64);
-
-        // Stack depth always increases on right shift.
-        self.active_depth += 1;
-        self.full_depth += 1;
-    }
-
     // CONTEXT MANAGEMENT
     // --------------------------------------------------------------------------------------------
 
@@ -342,3 +245,80 @@ impl Stack {
         self.trace.get_helpers_state_at(self.clk)
     }
 }
+
+// OPERAND STACK
+// ================================================================================================
+
+impl OperandStack for Stack {
+    fn depth(&self) -> usize {
+        self.active_depth
+    }
+
+    fn get(&self, pos: usize) -> Felt {
+        debug_assert!(pos < STACK_TOP_SIZE, "stack underflow");
+        self.trace.get_stack_value_at(self.clk, pos)
+    }
+
+    fn set(&mut self, pos: usize, value: Felt) {
+        debug_assert!(pos < STACK_TOP_SIZE, "stack underflow");
+        self.trace.set_stack_value_at(self.clk + 1, pos, value);
+    }
+
+    fn copy_state(&mut self, start_pos: usize) {
+        self.trace.copy_stack_state_at(
+            self.clk,
+            start_pos,
+            Felt::from(self.active_depth as u64),
+            self.overflow.last_row_addr(),
+        );
+    }
+
+    fn shift_left(&mut self, start_pos: usize) {
+        debug_assert!(start_pos > 0, "start position must be greater than 0");
+        debug_assert!(
+            start_pos < STACK_TOP_SIZE,
+            "start position cannot exceed stack top size"
+        );
+
+        match self.active_depth {
+            0..=MAX_TOP_IDX => unreachable!("stack underflow"),
+            STACK_TOP_SIZE => {
+                // Shift in a ZERO, to prevent depth shrinking below the minimum stack depth.
+                self.trace
+                    .stack_shift_left_at(self.clk, start_pos, ZERO, None);
+            }
+            _ => {
+                // Update the stack & overflow table.
+                let from_overflow = self.overflow.pop(self.clk as u64);
+                self.trace.stack_shift_left_at(
+                    self.clk,
+                    start_pos,
+                    from_overflow,
+                    Some(self.overflow.last_row_addr()),
+                );
+
+                // Stack depth only decreases when it is greater than the minimum stack depth.
+                self.active_depth -= 1;
+                self.full_depth -= 1;
+            }
+        }
+    }
+
+    fn shift_right(&mut self, start_pos: usize) {
+        debug_assert!(
+            start_pos < STACK_TOP_SIZE,
+            "start position cannot exceed stack top size"
+        );
+
+        // Update the stack.
+        self.trace.stack_shift_right_at(self.clk, start_pos);
+
+        // Update the overflow table.
+        let to_overflow = self.trace.get_stack_value_at(self.clk, MAX_TOP_IDX);
+        self.overflow.push(to_overflow, self.clk as u64);
+
+        // Stack depth always increases on right shift.
+        self.active_depth += 1;
+        self.full_depth += 1;
+    }
+}
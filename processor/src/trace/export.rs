@@ -0,0 +1,211 @@
+use super::{ExecutionTrace, Felt, RandomCoin, Trace, Vec};
+use std::{
+    io::{self, Write},
+    string::String,
+};
+use vm_core::{
+    decoder::{NUM_HASHER_COLUMNS, NUM_OP_BATCH_FLAGS, NUM_OP_BITS},
+    stack::STACK_TOP_SIZE,
+    utils::Serializable,
+    StarkField, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, CHIPLETS_WIDTH, TRACE_WIDTH, ZERO,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes at the start of every binary trace file.
+pub const BINARY_TRACE_MAGIC: [u8; 4] = *b"MVMT";
+
+/// Version of the binary trace file format.
+pub const BINARY_TRACE_VERSION: u8 = 1;
+
+// TRACE EXPORT FORMAT
+// ================================================================================================
+
+/// Format in which an execution trace can be exported.
+///
+/// - `Csv`: a header row with column names followed by one line per trace row; values are
+///   written as canonical integers.
+/// - `Binary`: the magic bytes `MVMT`, a version byte, the number of rows (u64), the number of
+///   main and auxiliary columns (u32 each), column names (each as a u16 length followed by UTF-8
+///   bytes), and then all values in row-major order as canonical u64 integers. All integers are
+///   little-endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceExportFormat {
+    Csv,
+    Binary,
+}
+
+// TRACE EXPORT
+// ================================================================================================
+
+impl ExecutionTrace {
+    /// Writes this execution trace into the provided target in the specified format.
+    ///
+    /// If `include_aux` is set to true, the auxiliary trace segment is built and written after the
+    /// main trace columns. Random elements required to build the auxiliary segment are drawn from
+    /// a random coin seeded with the program hash, and are taken from the base field. Thus, the
+    /// auxiliary columns are deterministic for a given program and inputs, but they differ from
+    /// the columns built by the prover.
+    pub fn export<W: Write>(
+        &mut self,
+        target: &mut W,
+        format: TraceExportFormat,
+        include_aux: bool,
+    ) -> io::Result<()> {
+        let aux_columns = if include_aux {
+            self.build_aux_columns_for_export()
+        } else {
+            Vec::new()
+        };
+
+        let mut column_names = main_trace_column_names();
+        if include_aux {
+            column_names.extend(aux_trace_column_names());
+        }
+
+        match format {
+            TraceExportFormat::Csv => self.write_csv(target, &column_names, &aux_columns),
+            TraceExportFormat::Binary => self.write_binary(target, &column_names, &aux_columns),
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the auxiliary trace segment using base field random elements drawn from a random
+    /// coin seeded with the program hash.
+    fn build_aux_columns_for_export(&mut self) -> Vec<Vec<Felt>> {
+        let mut rng = RandomCoin::new(&self.program_hash.to_bytes());
+        let rand_elements = (0..AUX_TRACE_RAND_ELEMENTS)
+            .map(|_| rng.draw().expect("failed to draw a random value"))
+            .collect::<Vec<Felt>>();
+
+        let aux_segment = self
+            .build_aux_segment(&[], &rand_elements)
+            .expect("failed to build auxiliary trace segment");
+
+        (0..aux_segment.num_cols())
+            .map(|i| aux_segment.get_column(i).to_vec())
+            .collect()
+    }
+
+    /// Writes the trace as comma-separated values with a header row.
+    fn write_csv<W: Write>(
+        &self,
+        target: &mut W,
+        column_names: &[String],
+        aux_columns: &[Vec<Felt>],
+    ) -> io::Result<()> {
+        writeln!(target, "{}", column_names.join(","))?;
+
+        let mut row = [ZERO; TRACE_WIDTH];
+        for i in 0..self.length() {
+            self.main_trace.read_row_into(i, &mut row);
+            let values = row
+                .iter()
+                .chain(aux_columns.iter().map(|column| &column[i]))
+                .map(|value| value.as_int().to_string())
+                .collect::<Vec<_>>();
+            writeln!(target, "{}", values.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the trace in the binary format described in [TraceExportFormat].
+    fn write_binary<W: Write>(
+        &self,
+        target: &mut W,
+        column_names: &[String],
+        aux_columns: &[Vec<Felt>],
+    ) -> io::Result<()> {
+        target.write_all(&BINARY_TRACE_MAGIC)?;
+        target.write_all(&[BINARY_TRACE_VERSION])?;
+        target.write_all(&(self.length() as u64).to_le_bytes())?;
+        target.write_all(&(TRACE_WIDTH as u32).to_le_bytes())?;
+        target.write_all(&(aux_columns.len() as u32).to_le_bytes())?;
+
+        for name in column_names {
+            target.write_all(&(name.len() as u16).to_le_bytes())?;
+            target.write_all(name.as_bytes())?;
+        }
+
+        let mut row = [ZERO; TRACE_WIDTH];
+        for i in 0..self.length() {
+            self.main_trace.read_row_into(i, &mut row);
+            for value in row
+                .iter()
+                .chain(aux_columns.iter().map(|column| &column[i]))
+            {
+                target.write_all(&value.as_int().to_le_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// COLUMN NAMES
+// ================================================================================================
+
+/// Returns names of all columns in the main segment of the execution trace.
+///
+/// Names are prefixed with the name of the component to which the column belongs (i.e., `sys`,
+/// `decoder`, `stack`, `range`, or `chiplets`).
+pub fn main_trace_column_names() -> Vec<String> {
+    let mut names = Vec::with_capacity(TRACE_WIDTH);
+
+    // system columns
+    names.extend(["sys.clk", "sys.fmp", "sys.ctx", "sys.in_syscall"].map(String::from));
+    names.extend((0..4).map(|i| format!("sys.fn_hash_{}", i)));
+
+    // decoder columns
+    names.push("decoder.addr".into());
+    names.extend((0..NUM_OP_BITS).map(|i| format!("decoder.op_bit_{}", i)));
+    names.extend((0..NUM_HASHER_COLUMNS).map(|i| format!("decoder.hasher_{}", i)));
+    names.extend(["decoder.in_span", "decoder.group_count", "decoder.op_idx"].map(String::from));
+    names.extend((0..NUM_OP_BATCH_FLAGS).map(|i| format!("decoder.op_batch_flag_{}", i)));
+    names.push("decoder.op_bit_extra".into());
+
+    // stack columns
+    names.extend((0..STACK_TOP_SIZE).map(|i| format!("stack.s{}", i)));
+    names.extend(["stack.b0", "stack.b1", "stack.h0"].map(String::from));
+
+    // range checker columns
+    names.extend(["range.t", "range.s0", "range.s1", "range.v"].map(String::from));
+
+    // chiplets columns
+    names.extend((0..CHIPLETS_WIDTH).map(|i| format!("chiplets.c{}", i)));
+
+    debug_assert_eq!(
+        TRACE_WIDTH,
+        names.len(),
+        "inconsistent number of column names"
+    );
+    names
+}
+
+/// Returns names of all columns in the auxiliary segment of the execution trace.
+pub fn aux_trace_column_names() -> Vec<String> {
+    let names = [
+        "decoder.p1",
+        "decoder.p2",
+        "decoder.p3",
+        "stack.p1",
+        "range.p0",
+        "range.p1",
+        "range.q",
+        "hasher.p1",
+        "chiplets.b_chip",
+    ]
+    .map(String::from)
+    .to_vec();
+
+    debug_assert_eq!(
+        AUX_TRACE_WIDTH,
+        names.len(),
+        "inconsistent number of column names"
+    );
+    names
+}
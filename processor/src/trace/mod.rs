@@ -3,13 +3,13 @@ use super::{
     decoder::AuxTraceHints as DecoderAuxTraceHints,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
     stack::AuxTraceBuilder as StackAuxTraceBuilder,
-    Digest, Felt, FieldElement, Process, StackTopState, Vec,
+    AsmOpInfo, Digest, Felt, FieldElement, Process, StackTopState, Vec,
 };
 use vm_core::{
     decoder::{NUM_USER_OP_HELPERS, USER_OP_HELPERS_OFFSET},
     stack::STACK_TOP_SIZE,
-    ProgramOutputs, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
-    STACK_TRACE_OFFSET, TRACE_WIDTH, ZERO,
+    AssemblyOp, ProgramOutputs, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET,
+    MIN_TRACE_LEN, STACK_TRACE_OFFSET, TRACE_WIDTH, ZERO,
 };
 use winterfell::{EvaluationFrame, Matrix, Serializable, Trace, TraceLayout};
 
@@ -21,6 +21,14 @@ pub use utils::{build_lookup_table_row_values, AuxColumnBuilder, LookupTableRow,
 
 mod decoder;
 
+#[cfg(feature = "std")]
+mod export;
+#[cfg(feature = "std")]
+pub use export::{
+    aux_trace_column_names, main_trace_column_names, TraceExportFormat, BINARY_TRACE_MAGIC,
+    BINARY_TRACE_VERSION,
+};
+
 #[cfg(test)]
 mod tests;
 
@@ -53,6 +61,7 @@ pub struct AuxTraceHints {
 ///   components.
 /// - Hints used during auxiliary trace segment construction.
 /// - Metadata needed by the STARK prover.
+/// - Assembly instructions executed by the VM, if the program was executed in debug mode.
 pub struct ExecutionTrace {
     meta: Vec<u8>,
     layout: TraceLayout,
@@ -60,6 +69,7 @@ pub struct ExecutionTrace {
     aux_trace_hints: AuxTraceHints,
     program_hash: Digest,
     program_outputs: ProgramOutputs,
+    assembly_ops: Vec<(usize, AssemblyOp)>,
 }
 
 impl ExecutionTrace {
@@ -72,13 +82,14 @@ impl ExecutionTrace {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
-    pub(super) fn new(process: Process, program_outputs: ProgramOutputs) -> Self {
+    pub(super) fn new(mut process: Process, program_outputs: ProgramOutputs) -> Self {
         // use program hash to initialize random element generator; this generator will be used
         // to inject random values at the end of the trace; using program hash here is OK because
         // we are using random values only to stabilize constraint degrees, and not to achieve
         // perfect zero knowledge.
         let program_hash: Digest = process.decoder.program_hash().into();
         let rng = RandomCoin::new(&program_hash.to_bytes());
+        let assembly_ops = process.decoder.take_assembly_ops();
         let (main_trace, aux_trace_hints) = finalize_trace(process, rng);
 
         Self {
@@ -88,6 +99,7 @@ impl ExecutionTrace {
             aux_trace_hints,
             program_hash,
             program_outputs,
+            assembly_ops,
         }
     }
 
@@ -134,6 +146,27 @@ impl ExecutionTrace {
         result
     }
 
+    /// Returns the assembly instruction which the operation executed at the specified `clk` of
+    /// the VM belongs to.
+    ///
+    /// Assembly instructions are available only if the program was compiled and executed in
+    /// debug mode; otherwise, None is returned.
+    pub fn get_asmop_at(&self, clk: u32) -> Option<AsmOpInfo> {
+        let clk = clk as usize;
+        let num_started = self
+            .assembly_ops
+            .partition_point(|(start, _)| *start <= clk);
+        self.assembly_ops[..num_started]
+            .iter()
+            .rev()
+            .find(|(_, asmop)| asmop.num_cycles() > 0)
+            .filter(|(start, asmop)| clk - start < asmop.num_cycles() as usize)
+            .map(|(start, asmop)| {
+                let cycle_idx = (clk - start + 1) as u8;
+                AsmOpInfo::new(asmop.op().clone(), asmop.num_cycles(), cycle_idx)
+            })
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -193,38 +226,14 @@ impl Trace for ExecutionTrace {
             return None;
         }
 
-        // TODO: build auxiliary columns in multiple threads
-
-        // add decoder's running product columns
-        let decoder_aux_columns = decoder::build_aux_columns(
-            &self.main_trace,
-            &self.aux_trace_hints.decoder,
-            rand_elements,
-        );
-
-        // add stack's running product columns
-        let stack_aux_columns = self
-            .aux_trace_hints
-            .stack
-            .build_aux_columns(&self.main_trace, rand_elements);
-
-        // add the range checker's running product columns
-        let range_aux_columns = self
-            .aux_trace_hints
-            .range
-            .build_aux_columns(&self.main_trace, rand_elements);
-
-        // add hasher's running product columns
-        let hasher_aux_columns = self
-            .aux_trace_hints
-            .hasher
-            .build_aux_columns(&self.main_trace, rand_elements);
-
-        // add running product columns for the chiplets module
-        let chiplets_aux_columns = self
-            .aux_trace_hints
-            .chiplets
-            .build_aux_columns(&self.main_trace, rand_elements);
+        // build auxiliary columns of the decoder, stack, range checker, hasher and chiplets
+        let (
+            decoder_aux_columns,
+            stack_aux_columns,
+            range_aux_columns,
+            hasher_aux_columns,
+            chiplets_aux_columns,
+        ) = build_aux_columns(&self.main_trace, &self.aux_trace_hints, rand_elements);
 
         // combine all auxiliary columns into a single vector
         let mut aux_columns = decoder_aux_columns
@@ -257,6 +266,66 @@ impl Trace for ExecutionTrace {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Auxiliary columns built by each of the VM components, in the order in which they appear in
+/// the auxiliary trace segment.
+type AuxColumns<E> = (
+    Vec<Vec<E>>,
+    Vec<Vec<E>>,
+    Vec<Vec<E>>,
+    Vec<Vec<E>>,
+    Vec<Vec<E>>,
+);
+
+/// Builds running product columns for the decoder, stack, range checker, hasher, and chiplets
+/// components of the auxiliary trace segment.
+#[cfg(not(feature = "concurrent"))]
+fn build_aux_columns<E: FieldElement<BaseField = Felt>>(
+    main_trace: &Matrix<Felt>,
+    hints: &AuxTraceHints,
+    rand_elements: &[E],
+) -> AuxColumns<E> {
+    (
+        decoder::build_aux_columns(main_trace, &hints.decoder, rand_elements),
+        hints.stack.build_aux_columns(main_trace, rand_elements),
+        hints.range.build_aux_columns(main_trace, rand_elements),
+        hints.hasher.build_aux_columns(main_trace, rand_elements),
+        hints.chiplets.build_aux_columns(main_trace, rand_elements),
+    )
+}
+
+/// Builds running product columns for the decoder, stack, range checker, hasher, and chiplets
+/// components of the auxiliary trace segment.
+///
+/// Columns of each component depend only on the main trace and on the hints of that component;
+/// thus, they are built in separate threads.
+#[cfg(feature = "concurrent")]
+fn build_aux_columns<E: FieldElement<BaseField = Felt>>(
+    main_trace: &Matrix<Felt>,
+    hints: &AuxTraceHints,
+    rand_elements: &[E],
+) -> AuxColumns<E> {
+    let ((decoder, stack), ((range, hasher), chiplets)) = rayon::join(
+        || {
+            rayon::join(
+                || decoder::build_aux_columns(main_trace, &hints.decoder, rand_elements),
+                || hints.stack.build_aux_columns(main_trace, rand_elements),
+            )
+        },
+        || {
+            rayon::join(
+                || {
+                    rayon::join(
+                        || hints.range.build_aux_columns(main_trace, rand_elements),
+                        || hints.hasher.build_aux_columns(main_trace, rand_elements),
+                    )
+                },
+                || hints.chiplets.build_aux_columns(main_trace, rand_elements),
+            )
+        },
+    );
+    (decoder, stack, range, hasher, chiplets)
+}
+
 /// Converts a process into a set of execution trace columns for each component of the trace.
 ///
 /// The process includes:
@@ -0,0 +1,78 @@
+use super::{build_trace_from_ops, Trace};
+use crate::trace::{
+    aux_trace_column_names, main_trace_column_names, TraceExportFormat, BINARY_TRACE_MAGIC,
+    BINARY_TRACE_VERSION,
+};
+use vm_core::{Operation, AUX_TRACE_WIDTH, CLK_COL_IDX, TRACE_WIDTH};
+
+#[test]
+fn export_csv() {
+    let operations = vec![Operation::Add, Operation::Mul];
+    let mut trace = build_trace_from_ops(operations, &[1, 2, 3]);
+
+    let mut target = Vec::new();
+    trace
+        .export(&mut target, TraceExportFormat::Csv, false)
+        .unwrap();
+    let csv = String::from_utf8(target).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+
+    // the header row is followed by one line per trace row
+    assert_eq!(trace.length() + 1, lines.len());
+    assert_eq!(main_trace_column_names().join(","), lines[0]);
+
+    // the clock column is the first column of the main trace
+    for (i, line) in lines.iter().skip(1).take(5).enumerate() {
+        let values = line.split(',').collect::<Vec<_>>();
+        assert_eq!(TRACE_WIDTH, values.len());
+        assert_eq!(i.to_string(), values[CLK_COL_IDX]);
+    }
+}
+
+#[test]
+fn export_csv_with_aux() {
+    let operations = vec![Operation::Add, Operation::Mul];
+    let mut trace = build_trace_from_ops(operations, &[1, 2, 3]);
+
+    let mut target = Vec::new();
+    trace
+        .export(&mut target, TraceExportFormat::Csv, true)
+        .unwrap();
+    let csv = String::from_utf8(target).unwrap();
+    let mut lines = csv.lines();
+
+    let header = lines.next().unwrap().split(',').collect::<Vec<_>>();
+    assert_eq!(TRACE_WIDTH + AUX_TRACE_WIDTH, header.len());
+    assert_eq!(aux_trace_column_names(), header[TRACE_WIDTH..].to_vec());
+
+    let values = lines.next().unwrap().split(',').collect::<Vec<_>>();
+    assert_eq!(TRACE_WIDTH + AUX_TRACE_WIDTH, values.len());
+}
+
+#[test]
+fn export_binary() {
+    let operations = vec![Operation::Add, Operation::Mul];
+    let mut trace = build_trace_from_ops(operations, &[1, 2, 3]);
+
+    let mut target = Vec::new();
+    trace
+        .export(&mut target, TraceExportFormat::Binary, false)
+        .unwrap();
+
+    assert_eq!(BINARY_TRACE_MAGIC, target[..4]);
+    assert_eq!(BINARY_TRACE_VERSION, target[4]);
+
+    let num_rows = u64::from_le_bytes(target[5..13].try_into().unwrap()) as usize;
+    let num_main_cols = u32::from_le_bytes(target[13..17].try_into().unwrap()) as usize;
+    let num_aux_cols = u32::from_le_bytes(target[17..21].try_into().unwrap()) as usize;
+    assert_eq!(trace.length(), num_rows);
+    assert_eq!(TRACE_WIDTH, num_main_cols);
+    assert_eq!(0, num_aux_cols);
+
+    // column names are followed by all values of the trace
+    let names_len = main_trace_column_names()
+        .iter()
+        .map(|name| name.len() + 2)
+        .sum::<usize>();
+    assert_eq!(21 + names_len + num_rows * TRACE_WIDTH * 8, target.len());
+}
@@ -6,6 +6,7 @@ use vm_core::{
 };
 
 mod chiplets;
+mod export;
 mod hasher;
 mod range;
 mod stack;
@@ -1,4 +1,4 @@
-use super::{Felt, StarkField, Vec};
+use super::{Felt, StarkField, Vec, ZERO};
 
 // HELPER FUNCTIONS
 // ================================================================================================
@@ -38,3 +38,70 @@ pub fn split_u32_into_u16(value: u64) -> (u16, u16) {
 
     (hi, lo)
 }
+
+/// Computes the values which the u256 division advice injector writes to the advice tape.
+///
+/// `stack_limbs` are the top 16 elements of the stack (from the top): the divisor followed by the
+/// dividend, each split into eight 32-bit limbs with the most significant limb first. The returned
+/// limbs are the remainder followed by the quotient, each with the most significant limb first,
+/// and thus, writing them to the advice tape in order makes the quotient be read first, and then
+/// the remainder, in both cases starting with the least significant limb.
+///
+/// Returns None if the divisor is zero.
+pub fn get_div_result_u256(stack_limbs: [Felt; 16]) -> Option<[Felt; 16]> {
+    let mut divisor = [0u32; 8];
+    let mut dividend = [0u32; 8];
+    for i in 0..8 {
+        divisor[7 - i] = stack_limbs[i].as_int() as u32;
+        dividend[7 - i] = stack_limbs[i + 8].as_int() as u32;
+    }
+
+    if divisor.iter().all(|&limb| limb == 0) {
+        return None;
+    }
+
+    let (quotient, remainder) = div_rem_u256(dividend, divisor);
+
+    let mut result = [ZERO; 16];
+    let limbs = quotient.iter().chain(remainder.iter()).rev();
+    for (value, &limb) in result.iter_mut().zip(limbs) {
+        *value = Felt::new(limb as u64);
+    }
+    Some(result)
+}
+
+/// Divides one 256-bit integer by another and returns the quotient and the remainder. All values
+/// are represented by eight 32-bit limbs, the least significant limb first.
+///
+/// # Panics
+/// Panics in debug mode if the divisor is zero.
+pub fn div_rem_u256(dividend: [u32; 8], divisor: [u32; 8]) -> ([u32; 8], [u32; 8]) {
+    debug_assert!(divisor.iter().any(|&limb| limb != 0), "division by zero");
+
+    // binary long division, starting with the most significant bit of the dividend
+    let mut quotient = [0u32; 8];
+    let mut remainder = [0u32; 8];
+    for i in (0..256).rev() {
+        // shift the next bit of the dividend into the remainder; since the remainder was smaller
+        // than the divisor, the shifted remainder exceeds the divisor when its top bit falls out
+        let mut carry = (dividend[i / 32] >> (i % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let shifted = ((*limb as u64) << 1) | carry as u64;
+            *limb = shifted as u32;
+            carry = (shifted >> 32) as u32;
+        }
+
+        if carry == 1 || remainder.iter().rev().ge(divisor.iter().rev()) {
+            let mut borrow = false;
+            for (limb, &d) in remainder.iter_mut().zip(divisor.iter()) {
+                let (diff, borrow1) = limb.overflowing_sub(d);
+                let (diff, borrow2) = diff.overflowing_sub(borrow as u32);
+                *limb = diff;
+                borrow = borrow1 || borrow2;
+            }
+            quotient[i / 32] |= 1 << (i % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
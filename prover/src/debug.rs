@@ -0,0 +1,487 @@
+use super::{ExecutionError, ExecutionTrace, Program, ProgramInputs, ProofOptions};
+use air::{ProcessorAir, PublicInputs};
+use core::fmt;
+use processor::AsmOpInfo;
+use prover::{Air, AuxTraceRandElements, EvaluationFrame, Matrix, Serializable, Trace};
+use vm_core::{
+    chiplets::hasher::Hasher,
+    utils::{collections::Vec, RandomCoin},
+    Felt, FieldElement, ProgramOutputs, AUX_TRACE_RAND_ELEMENTS, CHIPLETS_RANGE,
+    DECODER_AUX_TRACE_RANGE, DECODER_TRACE_RANGE, HASHER_AUX_TRACE_RANGE,
+    RANGE_CHECK_AUX_TRACE_RANGE, RANGE_CHECK_TRACE_RANGE, STACK_AUX_TRACE_RANGE, STACK_TRACE_RANGE,
+    SYS_TRACE_RANGE,
+};
+
+// CONSTRAINT CHECKER
+// ================================================================================================
+
+/// Executes the specified `program` against the provided `inputs` and checks that the resulting
+/// execution trace satisfies all boundary and transition constraints of [ProcessorAir].
+///
+/// This is intended for debugging proofs which fail to verify: the first failing constraint is
+/// returned together with the assembly instruction executing at the failing cycle. Assembly
+/// instructions are available only if the program was compiled in debug mode.
+///
+/// NOTE: this is a very expensive operation, and it should be used only for debugging purposes.
+///
+/// # Errors
+/// Returns an error if program execution fails, or if the execution trace does not satisfy some
+/// constraint.
+pub fn check_constraints(
+    program: &Program,
+    inputs: &ProgramInputs,
+) -> Result<ProgramOutputs, ConstraintCheckError> {
+    let mut trace =
+        processor::execute_debug(program, inputs).map_err(ConstraintCheckError::ExecutionError)?;
+    let outputs = trace.program_outputs();
+
+    check_trace_constraints(&mut trace, inputs.stack_init(), &outputs)
+        .map_err(ConstraintCheckError::ConstraintFailure)?;
+
+    Ok(outputs)
+}
+
+/// Checks that the provided execution trace satisfies all boundary and transition constraints of
+/// [ProcessorAir] for the specified stack inputs and program outputs.
+///
+/// Constraints are checked in the following order: main segment boundary constraints, auxiliary
+/// segment boundary constraints, and then main and auxiliary transition constraints row by row.
+/// The first failing constraint is returned together with the assembly instruction executing at
+/// the failing cycle, if the trace provides assembly instructions (see [processor::execute_debug()]).
+///
+/// Random elements needed to build the auxiliary trace segment are drawn from the base field
+/// using a random coin seeded with the program hash.
+pub fn check_trace_constraints(
+    trace: &mut ExecutionTrace,
+    stack_inputs: &[Felt],
+    outputs: &ProgramOutputs,
+) -> Result<(), ConstraintFailure> {
+    let (air, aux_segment, aux_rand_elements) = build_air(trace, stack_inputs, outputs);
+    check_segment_constraints(&air, trace.main_segment(), &aux_segment, &aux_rand_elements)
+        .map_err(|failure| failure.with_asmop(trace))
+}
+
+/// Instantiates [ProcessorAir] for the provided execution trace, and builds the auxiliary segment
+/// of the trace together with the random elements used to build it.
+fn build_air(
+    trace: &mut ExecutionTrace,
+    stack_inputs: &[Felt],
+    outputs: &ProgramOutputs,
+) -> (ProcessorAir, Matrix<Felt>, AuxTraceRandElements<Felt>) {
+    let pub_inputs =
+        PublicInputs::new(trace.program_hash(), stack_inputs.to_vec(), outputs.clone());
+    let options = ProofOptions::default().into_inner();
+    let air = ProcessorAir::new(trace.get_info(), pub_inputs, options);
+
+    // build the auxiliary segment of the trace
+    let mut rng = RandomCoin::<Felt, Hasher>::new(&trace.program_hash().to_bytes());
+    let rand_elements = (0..AUX_TRACE_RAND_ELEMENTS)
+        .map(|_| rng.draw().expect("failed to draw a random value"))
+        .collect::<Vec<Felt>>();
+    let aux_segment = trace
+        .build_aux_segment(&[], &rand_elements)
+        .expect("failed to build auxiliary trace segment");
+    let mut aux_rand_elements = AuxTraceRandElements::new();
+    aux_rand_elements.add_segment_elements(rand_elements);
+
+    (air, aux_segment, aux_rand_elements)
+}
+
+/// Checks that the provided main and auxiliary trace segments satisfy all boundary and transition
+/// constraints of the specified [ProcessorAir] instance, and returns the first failing constraint.
+fn check_segment_constraints(
+    air: &ProcessorAir,
+    main_segment: &Matrix<Felt>,
+    aux_segment: &Matrix<Felt>,
+    aux_rand_elements: &AuxTraceRandElements<Felt>,
+) -> Result<(), ConstraintFailure> {
+    let trace_len = main_segment.num_rows();
+
+    // --- check boundary constraints -------------------------------------------------------------
+    for (idx, assertion) in air.get_assertions().iter().enumerate() {
+        let mut failed_step = None;
+        assertion.apply(trace_len, |step, value| {
+            if failed_step.is_none() && main_segment.get(assertion.column(), step) != value {
+                failed_step = Some(step);
+            }
+        });
+        if let Some(step) = failed_step {
+            return Err(ConstraintFailure::new(
+                ConstraintKind::MainBoundary,
+                step,
+                idx,
+                get_main_column_component(assertion.column()),
+            ));
+        }
+    }
+
+    for (idx, assertion) in air.get_aux_assertions(aux_rand_elements).iter().enumerate() {
+        let mut failed_step = None;
+        assertion.apply(trace_len, |step, value| {
+            if failed_step.is_none() && aux_segment.get(assertion.column(), step) != value {
+                failed_step = Some(step);
+            }
+        });
+        if let Some(step) = failed_step {
+            return Err(ConstraintFailure::new(
+                ConstraintKind::AuxBoundary,
+                step,
+                idx,
+                get_aux_column_component(assertion.column()),
+            ));
+        }
+    }
+
+    // --- check transition constraints -----------------------------------------------------------
+    let periodic_columns = air.get_periodic_column_values();
+    let mut periodic_values = vec![Felt::ZERO; periodic_columns.len()];
+
+    let mut main_frame = EvaluationFrame::new(main_segment.num_cols());
+    let mut aux_frame = EvaluationFrame::new(aux_segment.num_cols());
+    let mut main_evaluations = vec![Felt::ZERO; air.context().num_main_transition_constraints()];
+    let mut aux_evaluations = vec![Felt::ZERO; air.context().num_aux_transition_constraints()];
+
+    for step in 0..trace_len - air.context().num_transition_exemptions() {
+        // the value of a periodic column at a given step is the value of the column at the
+        // position of the step within the column's cycle
+        for (column, value) in periodic_columns.iter().zip(periodic_values.iter_mut()) {
+            *value = column[step % column.len()];
+        }
+
+        read_frame(main_segment, step, &mut main_frame);
+        air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
+        if let Some(idx) = main_evaluations
+            .iter()
+            .position(|&value| value != Felt::ZERO)
+        {
+            return Err(ConstraintFailure::new(
+                ConstraintKind::MainTransition,
+                step,
+                idx,
+                air.get_transition_constraint_component(idx),
+            ));
+        }
+
+        read_frame(aux_segment, step, &mut aux_frame);
+        air.evaluate_aux_transition(
+            &main_frame,
+            &aux_frame,
+            &periodic_values,
+            aux_rand_elements,
+            &mut aux_evaluations,
+        );
+        if let Some(idx) = aux_evaluations
+            .iter()
+            .position(|&value| value != Felt::ZERO)
+        {
+            return Err(ConstraintFailure::new(
+                ConstraintKind::AuxTransition,
+                step,
+                idx,
+                air.get_aux_transition_constraint_component(idx),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// CONSTRAINT FAILURE
+// ================================================================================================
+
+/// Type of constraint which was not satisfied by an execution trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    MainBoundary,
+    AuxBoundary,
+    MainTransition,
+    AuxTransition,
+}
+
+impl fmt::Display for ConstraintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MainBoundary => write!(f, "main boundary constraint"),
+            Self::AuxBoundary => write!(f, "auxiliary boundary constraint"),
+            Self::MainTransition => write!(f, "main transition constraint"),
+            Self::AuxTransition => write!(f, "auxiliary transition constraint"),
+        }
+    }
+}
+
+/// Describes the first constraint which was not satisfied by an execution trace.
+///
+/// For boundary constraints, the index refers to the position of the assertion in the list of
+/// assertions returned by [ProcessorAir]; for transition constraints, the index refers to the
+/// position of the constraint in the list of evaluated constraints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintFailure {
+    kind: ConstraintKind,
+    step: usize,
+    index: usize,
+    component: &'static str,
+    asmop: Option<AsmOpInfo>,
+}
+
+impl ConstraintFailure {
+    fn new(kind: ConstraintKind, step: usize, index: usize, component: &'static str) -> Self {
+        Self {
+            kind,
+            step,
+            index,
+            component,
+            asmop: None,
+        }
+    }
+
+    /// Attaches the assembly instruction executing at the cycle of the failure, if the provided
+    /// trace provides assembly instructions.
+    fn with_asmop(mut self, trace: &ExecutionTrace) -> Self {
+        self.asmop = trace.get_asmop_at(self.step as u32);
+        self
+    }
+
+    /// Returns the type of the failed constraint.
+    pub fn kind(&self) -> ConstraintKind {
+        self.kind
+    }
+
+    /// Returns the trace row at which the constraint failed. For transition constraints, this is
+    /// the first of the two rows of the evaluation frame.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the index of the failed constraint.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the name of the VM component to which the failed constraint belongs.
+    pub fn component(&self) -> &'static str {
+        self.component
+    }
+
+    /// Returns the assembly instruction executing at the cycle of the failure, if known.
+    pub fn asmop(&self) -> Option<&AsmOpInfo> {
+        self.asmop.as_ref()
+    }
+}
+
+impl fmt::Display for ConstraintFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} of the {} failed at step {}",
+            self.kind, self.index, self.component, self.step
+        )?;
+        if let Some(asmop) = &self.asmop {
+            write!(
+                f,
+                " while executing `{}` (cycle {} of {})",
+                asmop.op(),
+                asmop.cycle_idx(),
+                asmop.num_cycles()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// CONSTRAINT CHECK ERROR
+// ================================================================================================
+
+/// Error returned by the constraint checker.
+#[derive(Debug)]
+pub enum ConstraintCheckError {
+    ExecutionError(ExecutionError),
+    ConstraintFailure(ConstraintFailure),
+}
+
+impl fmt::Display for ConstraintCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExecutionError(err) => write!(f, "failed to execute program: {:?}", err),
+            Self::ConstraintFailure(failure) => write!(f, "{}", failure),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads an evaluation frame at the specified step from the provided trace segment.
+fn read_frame(segment: &Matrix<Felt>, step: usize, frame: &mut EvaluationFrame<Felt>) {
+    let next_step = (step + 1) % segment.num_rows();
+    segment.read_row_into(step, frame.current_mut());
+    segment.read_row_into(next_step, frame.next_mut());
+}
+
+/// Returns the name of the VM component to which the specified main trace column belongs.
+fn get_main_column_component(column: usize) -> &'static str {
+    if SYS_TRACE_RANGE.contains(&column) {
+        "system"
+    } else if DECODER_TRACE_RANGE.contains(&column) {
+        "decoder"
+    } else if STACK_TRACE_RANGE.contains(&column) {
+        "stack"
+    } else if RANGE_CHECK_TRACE_RANGE.contains(&column) {
+        "range checker"
+    } else if CHIPLETS_RANGE.contains(&column) {
+        "chiplets"
+    } else {
+        "unknown component"
+    }
+}
+
+/// Returns the name of the VM component to which the specified auxiliary trace column belongs.
+fn get_aux_column_component(column: usize) -> &'static str {
+    if DECODER_AUX_TRACE_RANGE.contains(&column) {
+        "decoder"
+    } else if STACK_AUX_TRACE_RANGE.contains(&column) {
+        "stack"
+    } else if RANGE_CHECK_AUX_TRACE_RANGE.contains(&column) {
+        "range checker"
+    } else if HASHER_AUX_TRACE_RANGE.contains(&column) {
+        "hasher"
+    } else {
+        "chiplets"
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_air, check_constraints, check_segment_constraints, ConstraintCheckError,
+        ConstraintKind, ExecutionTrace, Program, ProgramInputs,
+    };
+    use miden_assembly::Assembler;
+    use prover::{Matrix, Trace};
+    use vm_core::{utils::collections::Vec, Felt, FieldElement, STACK_TRACE_OFFSET};
+
+    const SOURCE: &str = "begin push.1 push.2 add push.3 mul add.5 push.4 add end";
+
+    #[test]
+    fn valid_trace_satisfies_constraints() {
+        let program = compile(SOURCE);
+        let outputs = check_constraints(&program, &ProgramInputs::none()).unwrap();
+        assert_eq!(&[18], &outputs.stack()[..1]);
+    }
+
+    #[test]
+    fn corrupted_operation_fails_transition_constraints() {
+        let program = compile(SOURCE);
+        let inputs = ProgramInputs::none();
+        let mut trace = processor::execute_debug(&program, &inputs).unwrap();
+        let outputs = trace.program_outputs();
+
+        // corrupt the result of `mul`, which is located at the top of the stack in the row after
+        // the one at which `mul` is executed
+        let step = get_step_of(&program, &inputs, "mul", 1);
+        let main_segment = corrupt_cell(&trace, STACK_TRACE_OFFSET, step + 1);
+
+        let (air, aux_segment, aux_rand_elements) = build_air(&mut trace, &[], &outputs);
+        let failure =
+            check_segment_constraints(&air, &main_segment, &aux_segment, &aux_rand_elements)
+                .map_err(|failure| failure.with_asmop(&trace))
+                .unwrap_err();
+
+        assert_eq!(ConstraintKind::MainTransition, failure.kind());
+        assert_eq!(step, failure.step());
+        assert_eq!("stack", failure.component());
+
+        let asmop = failure
+            .asmop()
+            .expect("failure not attributed to an instruction");
+        assert_eq!("mul", asmop.op());
+        assert_eq!(1, asmop.num_cycles());
+        assert_eq!(1, asmop.cycle_idx());
+    }
+
+    #[test]
+    fn corrupted_operation_is_attributed_to_instruction_cycle() {
+        let program = compile(SOURCE);
+        let inputs = ProgramInputs::none();
+        let mut trace = processor::execute_debug(&program, &inputs).unwrap();
+        let outputs = trace.program_outputs();
+
+        // `add.5` is executed as `push.5 add`; corrupt the result of the addition
+        let step = get_step_of(&program, &inputs, "add.5", 2);
+        let main_segment = corrupt_cell(&trace, STACK_TRACE_OFFSET, step + 1);
+
+        let (air, aux_segment, aux_rand_elements) = build_air(&mut trace, &[], &outputs);
+        let failure =
+            check_segment_constraints(&air, &main_segment, &aux_segment, &aux_rand_elements)
+                .map_err(|failure| failure.with_asmop(&trace))
+                .unwrap_err();
+
+        assert_eq!(ConstraintKind::MainTransition, failure.kind());
+        assert_eq!(step, failure.step());
+
+        let asmop = failure
+            .asmop()
+            .expect("failure not attributed to an instruction");
+        assert_eq!("add.5", asmop.op());
+        assert_eq!(2, asmop.num_cycles());
+        assert_eq!(2, asmop.cycle_idx());
+    }
+
+    #[test]
+    fn asmops_match_execution_iterator() {
+        let program = compile(SOURCE);
+        let inputs = ProgramInputs::none();
+        let trace = processor::execute_debug(&program, &inputs).unwrap();
+
+        // the operation executed at a given cycle is recorded in the state of the next cycle
+        for state in processor::execute_iter(&program, &inputs).skip(1) {
+            let state = state.unwrap();
+            assert_eq!(state.asmop, trace.get_asmop_at(state.clk - 1));
+        }
+    }
+
+    #[test]
+    fn execution_errors_are_reported() {
+        let program = compile("begin push.1 push.2 assert_eq end");
+        let result = check_constraints(&program, &ProgramInputs::none());
+        assert!(matches!(
+            result,
+            Err(ConstraintCheckError::ExecutionError(_))
+        ));
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn compile(source: &str) -> Program {
+        Assembler::new()
+            .with_debug_mode(true)
+            .compile(source)
+            .unwrap()
+    }
+
+    /// Returns the step at which the specified cycle of the specified instruction is executed.
+    fn get_step_of(program: &Program, inputs: &ProgramInputs, op: &str, cycle_idx: u8) -> usize {
+        processor::execute_iter(program, inputs)
+            .map(|state| state.unwrap())
+            .find(|state| {
+                state.asmop.as_ref().map_or(false, |asmop| {
+                    asmop.op() == op && asmop.cycle_idx() == cycle_idx
+                })
+            })
+            .map(|state| state.clk as usize - 1)
+            .expect("instruction not executed")
+    }
+
+    /// Returns a copy of the main segment of the trace with the value in the specified cell
+    /// incremented by one.
+    fn corrupt_cell(trace: &ExecutionTrace, column: usize, row: usize) -> Matrix<Felt> {
+        let mut columns = (0..trace.main_trace_width())
+            .map(|i| trace.main_segment().get_column(i).to_vec())
+            .collect::<Vec<_>>();
+        columns[column][row] += Felt::ONE;
+        Matrix::new(columns)
+    }
+}
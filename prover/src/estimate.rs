@@ -0,0 +1,312 @@
+use super::{ExecutionTrace, ProofOptions};
+use air::{ProcessorAir, PublicInputs};
+use prover::{
+    crypto::{
+        hashers::{Blake3_192, Blake3_256, Sha3_256},
+        Digest, ElementHasher,
+    },
+    math::{fft, log2},
+    Air, EvaluationFrame, HashFunction, Trace,
+};
+use std::time::{Duration, Instant};
+use vm_core::{utils::collections::Vec, Felt, FieldElement, StarkField};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Size of a serialized base field element in bytes.
+const ELEMENT_SIZE: usize = 8;
+
+/// Number of trace rows used to measure the cost of evaluating transition constraints.
+const NUM_CALIBRATION_ROWS: usize = 1024;
+
+/// Size of the polynomial used to measure the cost of low-degree extension.
+const CALIBRATION_POLY_SIZE: usize = 1 << 12;
+
+/// Query security level (in bits) at or above which the grinding factor is added to the security
+/// provided by queries.
+const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+
+// PROOF ESTIMATE
+// ================================================================================================
+
+/// Predicted cost of proving a program execution with a given set of proof options.
+///
+/// Proof size is an upper bound derived from the structure of the proof: it assumes that Merkle
+/// authentication paths of different queries share only the nodes above the query layer.
+///
+/// Prover memory accounts for the extended trace segments, constraint evaluations, the extended
+/// composition polynomial, and the Merkle trees built over them.
+///
+/// Proving time is derived from the cost of low-degree extension, constraint evaluation, and
+/// hashing measured on the current machine using a single thread. It does not account for
+/// multi-threading enabled by the `concurrent` feature and should be treated as an order of
+/// magnitude estimate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofEstimate {
+    trace_length: usize,
+    main_trace_width: usize,
+    aux_trace_width: usize,
+    lde_domain_size: usize,
+    security_level: u32,
+    proof_size: usize,
+    prover_memory: usize,
+    proving_time: Duration,
+}
+
+impl ProofEstimate {
+    /// Returns the number of rows in the execution trace.
+    pub fn trace_length(&self) -> usize {
+        self.trace_length
+    }
+
+    /// Returns the number of columns in the main segment of the execution trace.
+    pub fn main_trace_width(&self) -> usize {
+        self.main_trace_width
+    }
+
+    /// Returns the number of columns in the auxiliary segment of the execution trace.
+    pub fn aux_trace_width(&self) -> usize {
+        self.aux_trace_width
+    }
+
+    /// Returns the size of the low-degree extension domain.
+    pub fn lde_domain_size(&self) -> usize {
+        self.lde_domain_size
+    }
+
+    /// Returns the conjectured security level (in bits) of the proof.
+    pub fn security_level(&self) -> u32 {
+        self.security_level
+    }
+
+    /// Returns the estimated size of the proof in bytes.
+    pub fn proof_size(&self) -> usize {
+        self.proof_size
+    }
+
+    /// Returns the estimated amount of memory (in bytes) required by the prover.
+    pub fn prover_memory(&self) -> usize {
+        self.prover_memory
+    }
+
+    /// Returns the estimated single-threaded proving time.
+    pub fn proving_time(&self) -> Duration {
+        self.proving_time
+    }
+}
+
+// ESTIMATOR
+// ================================================================================================
+
+/// Estimates the size of a proof for the specified execution trace, as well as the time and
+/// memory needed to generate it with the specified options, without generating the proof.
+///
+/// Costs of the most expensive proving steps are measured on a small sample of work, and so this
+/// function takes only a few milliseconds regardless of the length of the trace.
+pub fn estimate_proof(trace: &ExecutionTrace, options: &ProofOptions) -> ProofEstimate {
+    let pub_inputs = PublicInputs::new(trace.program_hash(), Vec::new(), trace.program_outputs());
+    let air = ProcessorAir::new(trace.get_info(), pub_inputs, options.clone().into_inner());
+
+    let trace_length = trace.length();
+    let main_width = trace.layout().main_trace_width();
+    let aux_width = trace.layout().aux_trace_width();
+    let lde_domain_size = air.context().lde_domain_size();
+    let ce_domain_size = air.context().ce_domain_size();
+    let num_composition_columns = ce_domain_size / trace_length;
+
+    let extension_degree = options.field_extension().degree() as usize;
+    let digest_size = get_digest_size(options.hash_fn());
+
+    // number of base field elements in a single row of each committed matrix
+    let main_row_size = main_width;
+    let aux_row_size = aux_width * extension_degree;
+    let constraint_row_size = num_composition_columns * extension_degree;
+
+    // --- proof size -----------------------------------------------------------------------------
+    let num_queries = options.num_queries();
+    let fri_options = options.to_fri_options();
+    let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+
+    // Merkle authentication paths for a batch of queries against a tree with the specified
+    // number of leaves
+    let batch_path_size = |num_leaves: usize| {
+        let depth = log2(num_leaves) as usize;
+        let shared_depth = log2(num_queries.next_power_of_two()) as usize;
+        num_queries * depth.saturating_sub(shared_depth) * digest_size
+    };
+
+    let commitments_size = (3 + num_fri_layers) * digest_size;
+    let queries_size =
+        num_queries * (main_row_size + aux_row_size + constraint_row_size) * ELEMENT_SIZE
+            + 3 * batch_path_size(lde_domain_size);
+    let ood_frame_size =
+        (2 * (main_width + aux_width) + num_composition_columns) * extension_degree * ELEMENT_SIZE;
+
+    let mut fri_size = 0;
+    let mut domain_size = lde_domain_size;
+    for _ in 0..num_fri_layers {
+        let folding_factor = fri_options.folding_factor();
+        fri_size += num_queries * folding_factor * extension_degree * ELEMENT_SIZE
+            + batch_path_size(domain_size / folding_factor);
+        domain_size /= folding_factor;
+    }
+    fri_size += domain_size * extension_degree * ELEMENT_SIZE;
+
+    let proof_size = commitments_size + queries_size + ood_frame_size + fri_size + ELEMENT_SIZE;
+
+    // --- prover memory --------------------------------------------------------------------------
+    let trace_memory = trace_length * (main_row_size + aux_row_size) * ELEMENT_SIZE;
+    let lde_memory = lde_domain_size * (main_row_size + aux_row_size) * ELEMENT_SIZE;
+    let constraint_memory = ce_domain_size * extension_degree * ELEMENT_SIZE
+        + lde_domain_size * constraint_row_size * ELEMENT_SIZE;
+    let merkle_memory = 3 * 2 * lde_domain_size * digest_size;
+    let prover_memory = trace_memory + lde_memory + constraint_memory + merkle_memory;
+
+    // --- proving time ---------------------------------------------------------------------------
+    let lde_time = measure_lde_time(options.blowup_factor())
+        .mul_f64(lde_cost_ratio(lde_domain_size, options.blowup_factor()))
+        .mul_f64((main_row_size + aux_row_size + constraint_row_size) as f64);
+
+    let constraint_time = measure_constraint_evaluation_time(trace, &air)
+        .mul_f64(ce_domain_size as f64 / NUM_CALIBRATION_ROWS as f64);
+
+    let (row_hash_time, merge_time) = measure_hash_time(options.hash_fn(), main_row_size);
+    // committing to FRI layers requires hashing all evaluations of each layer (in groups of
+    // folding factor size) and building a Merkle tree over the hashes
+    let mut num_fri_elements = 0;
+    let mut num_fri_leaves = 0;
+    let mut domain_size = lde_domain_size;
+    for _ in 0..num_fri_layers {
+        num_fri_elements += domain_size * extension_degree;
+        num_fri_leaves += domain_size / fri_options.folding_factor();
+        domain_size /= fri_options.folding_factor();
+    }
+
+    let num_hashed_elements =
+        lde_domain_size * (main_row_size + aux_row_size + constraint_row_size) + num_fri_elements;
+    let hash_time = row_hash_time.mul_f64(num_hashed_elements as f64 / main_row_size as f64)
+        + merge_time.mul_f64((3 * lde_domain_size + num_fri_leaves) as f64);
+
+    ProofEstimate {
+        trace_length,
+        main_trace_width: main_width,
+        aux_trace_width: aux_width,
+        lde_domain_size,
+        security_level: get_conjectured_security(options, lde_domain_size),
+        proof_size,
+        prover_memory,
+        proving_time: lde_time + constraint_time + hash_time,
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns conjectured security level of proofs generated with the specified options for a
+/// computation with the specified LDE domain size.
+///
+/// This mirrors the computation performed by [prover::StarkProof::security_level()].
+fn get_conjectured_security(options: &ProofOptions, lde_domain_size: usize) -> u32 {
+    let field_size = Felt::MODULUS_BITS * options.field_extension().degree();
+    let field_security = field_size - lde_domain_size.trailing_zeros();
+
+    let hash_fn_security = options.hash_fn().collision_resistance();
+
+    let mut query_security = log2(options.blowup_factor()) * options.num_queries() as u32;
+    if query_security >= GRINDING_CONTRIBUTION_FLOOR {
+        query_security += options.grinding_factor();
+    }
+
+    (field_security.min(query_security) - 1).min(hash_fn_security)
+}
+
+/// Returns the size of a digest (in bytes) produced by the specified hash function.
+fn get_digest_size(hash_fn: HashFunction) -> usize {
+    match hash_fn {
+        HashFunction::Blake3_192 => 24,
+        HashFunction::Blake3_256 | HashFunction::Sha3_256 => 32,
+    }
+}
+
+/// Returns the ratio between the cost of extending a column into a domain of the specified size
+/// and the cost of extending the calibration column.
+fn lde_cost_ratio(lde_domain_size: usize, blowup_factor: usize) -> f64 {
+    let calibration_size = CALIBRATION_POLY_SIZE * blowup_factor;
+    (lde_domain_size as f64 * (log2(lde_domain_size) as f64))
+        / (calibration_size as f64 * (log2(calibration_size) as f64))
+}
+
+/// Measures the time needed to interpolate a single column of the calibration size and evaluate
+/// it over a domain extended by the specified blowup factor.
+fn measure_lde_time(blowup_factor: usize) -> Duration {
+    let mut column = (0..CALIBRATION_POLY_SIZE as u64)
+        .map(Felt::new)
+        .collect::<Vec<_>>();
+    let inv_twiddles = fft::get_inv_twiddles::<Felt>(CALIBRATION_POLY_SIZE);
+    let twiddles = fft::get_twiddles::<Felt>(CALIBRATION_POLY_SIZE);
+
+    let now = Instant::now();
+    fft::interpolate_poly(&mut column, &inv_twiddles);
+    let evaluations =
+        fft::evaluate_poly_with_offset(&column, &twiddles, Felt::GENERATOR, blowup_factor);
+    let elapsed = now.elapsed();
+
+    debug_assert_eq!(CALIBRATION_POLY_SIZE * blowup_factor, evaluations.len());
+    elapsed
+}
+
+/// Measures the time needed to evaluate main transition constraints over a sample of rows of the
+/// execution trace.
+fn measure_constraint_evaluation_time(trace: &ExecutionTrace, air: &ProcessorAir) -> Duration {
+    let periodic_columns = air.get_periodic_column_values();
+    let mut periodic_values = vec![Felt::ZERO; periodic_columns.len()];
+    let mut frame = EvaluationFrame::new(trace.main_trace_width());
+    let mut evaluations = vec![Felt::ZERO; air.context().num_main_transition_constraints()];
+
+    let num_rows = NUM_CALIBRATION_ROWS.min(trace.length() - 1);
+    let now = Instant::now();
+    for step in 0..num_rows {
+        for (column, value) in periodic_columns.iter().zip(periodic_values.iter_mut()) {
+            *value = column[step % column.len()];
+        }
+        trace.read_main_frame(step, &mut frame);
+        air.evaluate_transition(&frame, &periodic_values, &mut evaluations);
+    }
+    now.elapsed()
+        .mul_f64(NUM_CALIBRATION_ROWS as f64 / num_rows as f64)
+}
+
+/// Measures the time needed to hash a single row of the specified size, and the time needed to
+/// merge two digests into a node of a Merkle tree, using the specified hash function.
+fn measure_hash_time(hash_fn: HashFunction, row_size: usize) -> (Duration, Duration) {
+    match hash_fn {
+        HashFunction::Blake3_192 => measure_hasher_time::<Blake3_192<Felt>>(row_size),
+        HashFunction::Blake3_256 => measure_hasher_time::<Blake3_256<Felt>>(row_size),
+        HashFunction::Sha3_256 => measure_hasher_time::<Sha3_256<Felt>>(row_size),
+    }
+}
+
+fn measure_hasher_time<H: ElementHasher<BaseField = Felt>>(
+    row_size: usize,
+) -> (Duration, Duration) {
+    let mut row = (0..row_size as u64).map(Felt::new).collect::<Vec<_>>();
+    let num_rows = NUM_CALIBRATION_ROWS as u32;
+
+    // each row depends on the digest of the previous one so that hashing cannot be optimized away
+    let now = Instant::now();
+    for _ in 0..num_rows {
+        let digest = H::hash_elements(&row);
+        row[0] = Felt::new(digest.as_bytes()[0] as u64);
+    }
+    let hash_time = now.elapsed() / num_rows;
+
+    let mut digest = H::hash_elements(&row);
+    let now = Instant::now();
+    for _ in 0..num_rows {
+        digest = H::merge(&[digest, digest]);
+    }
+    let merge_time = now.elapsed() / num_rows;
+
+    (hash_time, merge_time)
+}
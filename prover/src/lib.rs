@@ -1,23 +1,35 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
 use air::{ProcessorAir, PublicInputs};
-use processor::ExecutionTrace;
 use prover::Prover;
 use vm_core::{utils::collections::Vec, Felt, ProgramOutputs};
 
 #[cfg(feature = "std")]
 use log::debug;
 #[cfg(feature = "std")]
-use prover::Trace;
-#[cfg(feature = "std")]
 use std::time::Instant;
 
+mod debug;
+pub use debug::{
+    check_constraints, check_trace_constraints, ConstraintCheckError, ConstraintFailure,
+    ConstraintKind,
+};
+
+#[cfg(feature = "std")]
+mod estimate;
+#[cfg(feature = "std")]
+pub use estimate::{estimate_proof, ProofEstimate};
+
 // EXPORTS
 // ================================================================================================
 
 pub use air::{FieldExtension, HashFunction, ProofOptions};
-pub use processor::ExecutionError;
-pub use prover::StarkProof;
+pub use processor::{ExecutionError, ExecutionTrace};
+pub use prover::{StarkProof, Trace};
 pub use vm_core::{
     chiplets::hasher::Digest,
     errors::{AdviceSetError, InputError},
@@ -0,0 +1,4 @@
+//! Helpers for producing inputs for the digital signature verification procedures of the
+//! standard library.
+
+pub mod schnorr_ecgfp5;
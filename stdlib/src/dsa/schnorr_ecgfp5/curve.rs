@@ -0,0 +1,146 @@
+use super::{field::Ext5, scalar::Scalar};
+use core::ops::{Add, Neg};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Coefficient a' of the short Weierstraß equation y^2 = x^3 + a'x + b' of the curve.
+const A_PRIME: Ext5 = Ext5::new([6148914689804861439, 263, 0, 0, 0]);
+
+/// The value a / 3, where a = 2 is the coefficient of the double-odd equation of the curve.
+const A_DIV_3: Ext5 = Ext5::new([6148914689804861441, 0, 0, 0, 0]);
+
+// ELLIPTIC CURVE POINT
+// ================================================================================================
+
+/// A point of the prime-order group of ecGFp5 in short Weierstraß coordinates (x, y), matching the
+/// point representation used by `std::math::ec_ext5`.
+///
+/// See https://ia.cr/2022/274 for the description of the curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Point {
+    x: Ext5,
+    y: Ext5,
+    is_infinity: bool,
+}
+
+impl Point {
+    /// The neutral element of the group.
+    pub const NEUTRAL: Self = Self {
+        x: Ext5::ZERO,
+        y: Ext5::ZERO,
+        is_infinity: true,
+    };
+
+    /// The generator of the group used by the signature scheme; its encoding is w = 4.
+    pub const GENERATOR: Self = Self {
+        x: Ext5::new([
+            11712523173042564207,
+            14090224426659529053,
+            13197813503519687414,
+            16280770174934269299,
+            15998333998318935536,
+        ]),
+        y: Ext5::new([
+            14639054205878357578,
+            17426078571020221072,
+            2548978194165003307,
+            8663895577921260088,
+            9793640284382595140,
+        ]),
+        is_infinity: false,
+    };
+
+    /// Returns true if this point is the neutral element of the group.
+    pub fn is_infinity(&self) -> bool {
+        self.is_infinity
+    }
+
+    /// Returns the canonical encoding of this point as a single element of GF(p^5), i.e.,
+    /// w = y / (a/3 - x), or zero for the neutral element.
+    ///
+    /// This is the same value as the one computed by `ec_ext5::encode`.
+    pub fn encode(&self) -> Ext5 {
+        if self.is_infinity {
+            Ext5::ZERO
+        } else {
+            self.y / (A_DIV_3 - self.x)
+        }
+    }
+
+    /// Returns this point doubled.
+    pub fn double(&self) -> Self {
+        if self.is_infinity {
+            return *self;
+        }
+
+        let lambda = (Ext5::new([3, 0, 0, 0, 0]) * self.x.square() + A_PRIME) / (self.y + self.y);
+        let x = lambda.square() - self.x - self.x;
+        let y = lambda * (self.x - x) - self.y;
+
+        Self {
+            x,
+            y,
+            is_infinity: false,
+        }
+    }
+
+    /// Returns this point multiplied by the provided scalar.
+    ///
+    /// The scalar is processed starting with the least significant bit, in the same way as it is
+    /// done by `ec_ext5::mul`.
+    pub fn mul(&self, scalar: &Scalar) -> Self {
+        let mut base = *self;
+        let mut result = Self::NEUTRAL;
+        for bit in scalar.bits() {
+            if bit {
+                result = result + base;
+            }
+            base = base.double();
+        }
+        result
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if self.is_infinity {
+            return rhs;
+        }
+        if rhs.is_infinity {
+            return self;
+        }
+        if self.x == rhs.x {
+            // the points are either equal or opposite to each other
+            return if self.y == rhs.y {
+                self.double()
+            } else {
+                Self::NEUTRAL
+            };
+        }
+
+        let lambda = (rhs.y - self.y) / (rhs.x - self.x);
+        let x = lambda.square() - self.x - rhs.x;
+        let y = lambda * (self.x - x) - self.y;
+
+        Self {
+            x,
+            y,
+            is_infinity: false,
+        }
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            is_infinity: self.is_infinity,
+        }
+    }
+}
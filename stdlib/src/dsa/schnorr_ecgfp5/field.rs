@@ -0,0 +1,137 @@
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use vm_core::{Felt, FieldElement};
+
+// QUINTIC EXTENSION FIELD
+// ================================================================================================
+
+/// An element of GF(p^5) = GF(p)[z] / (z^5 - 3), where p = 2^64 - 2^32 + 1 is the modulus of the
+/// base field of the VM.
+///
+/// Coefficients are ordered from the constant term to the coefficient of z^4, which is the same
+/// order in which `std::math::ext5` expects them on the stack (the constant term at the top).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ext5(pub [Felt; 5]);
+
+impl Ext5 {
+    /// The additive identity.
+    pub const ZERO: Self = Self::new([0; 5]);
+
+    /// Returns a new element with the provided coefficients.
+    pub const fn new(coeffs: [u64; 5]) -> Self {
+        Self([
+            Felt::new(coeffs[0]),
+            Felt::new(coeffs[1]),
+            Felt::new(coeffs[2]),
+            Felt::new(coeffs[3]),
+            Felt::new(coeffs[4]),
+        ])
+    }
+
+    /// Returns the square of this element.
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns the multiplicative inverse of this element, or zero if this element is zero.
+    ///
+    /// The inverse is computed as a^(r - 1) / a^r, where r = 1 + p + p^2 + p^3 + p^4. Since a^r
+    /// belongs to the base field, only a single inversion in the base field is required.
+    ///
+    /// See https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L709-L734
+    pub fn inv(self) -> Self {
+        let t0 = self.frobenius_once();
+        let t1 = t0 * t0.frobenius_once();
+        let t2 = t1 * t1.frobenius_twice();
+
+        // a^r = a * a^(r - 1); only the constant term of the product can be non-zero
+        let [a0, a1, a2, a3, a4] = self.0;
+        let [b0, b1, b2, b3, b4] = t2.0;
+        let norm = a0 * b0 + Felt::new(3) * (a1 * b4 + a2 * b3 + a3 * b2 + a4 * b1);
+
+        let norm_inv = norm.inv();
+        Self(t2.0.map(|coeff| coeff * norm_inv))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Raises this element to the power p.
+    fn frobenius_once(self) -> Self {
+        let [a0, a1, a2, a3, a4] = self.0;
+        Self([
+            a0,
+            a1 * Felt::new(1041288259238279555),
+            a2 * Felt::new(15820824984080659046),
+            a3 * Felt::new(211587555138949697),
+            a4 * Felt::new(1373043270956696022),
+        ])
+    }
+
+    /// Raises this element to the power p^2.
+    fn frobenius_twice(self) -> Self {
+        let [a0, a1, a2, a3, a4] = self.0;
+        Self([
+            a0,
+            a1 * Felt::new(15820824984080659046),
+            a2 * Felt::new(1373043270956696022),
+            a3 * Felt::new(1041288259238279555),
+            a4 * Felt::new(211587555138949697),
+        ])
+    }
+}
+
+impl Add for Ext5 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut result = self.0;
+        result.iter_mut().zip(rhs.0).for_each(|(a, b)| *a += b);
+        Self(result)
+    }
+}
+
+impl Sub for Ext5 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let mut result = self.0;
+        result.iter_mut().zip(rhs.0).for_each(|(a, b)| *a -= b);
+        Self(result)
+    }
+}
+
+impl Neg for Ext5 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|coeff| -coeff))
+    }
+}
+
+impl Mul for Ext5 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // schoolbook multiplication; the terms of degree 5 and higher are reduced using z^5 = 3
+        let mut result = [Felt::ZERO; 5];
+        for (i, &a) in self.0.iter().enumerate() {
+            for (j, &b) in rhs.0.iter().enumerate() {
+                if i + j < 5 {
+                    result[i + j] += a * b;
+                } else {
+                    result[i + j - 5] += Felt::new(3) * a * b;
+                }
+            }
+        }
+        Self(result)
+    }
+}
+
+impl Div for Ext5 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
@@ -0,0 +1,241 @@
+//! Schnorr signatures over the ecGFp5 curve.
+//!
+//! This module is a reference implementation of the signature scheme verified by
+//! `std::crypto::dsa::schnorr_ecgfp5::verify`, and it is intended to be used for generating inputs
+//! for the Miden VM programs which verify signatures. The scheme works as follows:
+//! - A secret key is a non-zero scalar x modulo the order n of the prime-order group of ecGFp5,
+//!   and the corresponding public key is the point P = x * G, where G is the generator of the
+//!   group.
+//! - A signature of a message M (a word) is a pair (R, s), where R = k * G for a nonce k, and
+//!   s = k + e * x mod n. The challenge e is computed as the Rescue Prime hash of the encodings
+//!   of R and P, and of the message, i.e., e = hash(w_R || w_P || M), where the four elements of
+//!   the digest are interpreted as a 256-bit integer.
+//! - A signature is valid if s * G - e * P = R.
+//!
+//! Points are always represented by their canonical encodings (single elements of GF(p^5)), and
+//! the nonce k is derived deterministically by hashing the secret key together with the
+//! message. Thus, signing does not require a source of randomness.
+
+use vm_core::{chiplets::hasher, utils::collections::Vec, Felt, StarkField, Word};
+
+mod curve;
+use curve::Point;
+
+mod field;
+use field::Ext5;
+
+mod scalar;
+use scalar::{Scalar, NUM_LIMBS};
+
+// SECRET KEY
+// ================================================================================================
+
+/// A secret key of the Schnorr signature scheme over ecGFp5.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretKey {
+    scalar: Scalar,
+}
+
+impl SecretKey {
+    /// Returns a secret key derived deterministically from the provided seed.
+    ///
+    /// # Panics
+    /// Panics if the derived key is zero; this happens with negligible probability.
+    pub fn from_seed(seed: Word) -> Self {
+        let scalar = hash_to_scalar(&seed);
+        assert!(!scalar.is_zero(), "secret key must not be zero");
+        Self { scalar }
+    }
+
+    /// Returns the public key corresponding to this secret key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            point: Point::GENERATOR.mul(&self.scalar),
+        }
+    }
+
+    /// Returns a signature of the provided message.
+    pub fn sign(&self, message: Word) -> Signature {
+        // derive the nonce from the secret key and the message
+        let mut nonce_seed = Vec::with_capacity(NUM_LIMBS + message.len());
+        nonce_seed.extend(self.scalar.limbs().map(|limb| Felt::new(limb as u64)));
+        nonce_seed.extend_from_slice(&message);
+        let nonce = hash_to_scalar(&nonce_seed);
+
+        let r = Point::GENERATOR.mul(&nonce).encode();
+        let e = challenge(r, self.public_key().point.encode(), message);
+        let s = nonce + e * self.scalar;
+
+        Signature { r, s }
+    }
+}
+
+// PUBLIC KEY
+// ================================================================================================
+
+/// A public key of the Schnorr signature scheme over ecGFp5.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    point: Point,
+}
+
+impl PublicKey {
+    /// Returns the encoding of this public key, i.e., five elements [w0, w1, w2, w3, w4] of the
+    /// encoded point.
+    ///
+    /// `schnorr_ecgfp5::verify` expects these elements at the top of the stack, with w0 being
+    /// the topmost element.
+    pub fn to_elements(&self) -> [Felt; 5] {
+        self.point.encode().0
+    }
+
+    /// Returns true if the provided signature of the message is valid for this public key.
+    pub fn verify(&self, message: Word, signature: &Signature) -> bool {
+        let e = challenge(signature.r, self.point.encode(), message);
+        let r = Point::GENERATOR.mul(&signature.s) + -self.point.mul(&e);
+        !self.point.is_infinity() && r.encode() == signature.r
+    }
+}
+
+// SIGNATURE
+// ================================================================================================
+
+/// A signature (R, s) of the Schnorr signature scheme over ecGFp5.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r: Ext5,
+    s: Scalar,
+}
+
+impl Signature {
+    /// Returns the elements of this signature in the order in which `schnorr_ecgfp5::verify`
+    /// reads them from the advice tape.
+    ///
+    /// These are the five elements of the encoding of R, followed by ten 32-bit limbs of s, the
+    /// most significant limb first.
+    pub fn to_elements(&self) -> [Felt; 5 + NUM_LIMBS] {
+        let mut elements = [Felt::new(0); 5 + NUM_LIMBS];
+        elements[..5].copy_from_slice(&self.r.0);
+        for (element, limb) in elements[5..].iter_mut().zip(self.s.limbs().iter().rev()) {
+            *element = Felt::new(*limb as u64);
+        }
+        elements
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Computes the challenge e = hash(w_R || w_P || M), and interprets the digest as a 256-bit
+/// integer, such that each element of the digest contributes two 32-bit limbs.
+fn challenge(r: Ext5, public_key: Ext5, message: Word) -> Scalar {
+    let mut elements = [Felt::new(0); 14];
+    elements[..5].copy_from_slice(&r.0);
+    elements[5..10].copy_from_slice(&public_key.0);
+    elements[10..].copy_from_slice(&message);
+
+    let digest: Word = hasher::hash_elements(&elements).into();
+    Scalar::from_wide_limbs(&to_u32_limbs(&digest))
+}
+
+/// Hashes the provided elements into a scalar which is close to uniformly distributed.
+///
+/// The elements are hashed twice with different domain separators, and the resulting 512 bits
+/// are reduced modulo the order of the group.
+fn hash_to_scalar(elements: &[Felt]) -> Scalar {
+    let mut limbs = [0; 16];
+    for (i, chunk) in limbs.chunks_mut(8).enumerate() {
+        let mut input = Vec::with_capacity(elements.len() + 1);
+        input.extend_from_slice(elements);
+        input.push(Felt::new(i as u64));
+        let digest: Word = hasher::hash_elements(&input).into();
+        chunk.copy_from_slice(&to_u32_limbs(&digest));
+    }
+    Scalar::from_wide_limbs(&limbs)
+}
+
+/// Splits the elements of the provided word into 32-bit limbs, the lower limb first.
+fn to_u32_limbs(word: &Word) -> [u32; 8] {
+    let mut limbs = [0; 8];
+    for (chunk, element) in limbs.chunks_mut(2).zip(word) {
+        chunk[0] = element.as_int() as u32;
+        chunk[1] = (element.as_int() >> 32) as u32;
+    }
+    limbs
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Ext5, Felt, Point, Scalar, SecretKey};
+
+    #[test]
+    fn generator() {
+        assert_eq!(Ext5::new([4, 0, 0, 0, 0]), Point::GENERATOR.encode());
+
+        // n * G = 0, and thus, (n - 1) * G = -G
+        let n_minus_1 = Scalar::from_wide_limbs(&[
+            2492202976, 3893352854, 3609501852, 3901250617, 3484943929, 2147483622, 22, 2147483633,
+            2147483655, 2147483645,
+        ]);
+        assert_eq!(-Point::GENERATOR, Point::GENERATOR.mul(&n_minus_1));
+        assert_eq!(
+            Point::NEUTRAL,
+            Point::GENERATOR.mul(&n_minus_1) + Point::GENERATOR
+        );
+    }
+
+    #[test]
+    fn field_inversion() {
+        let a = Ext5::new([1, 2, 3, 4, 5]);
+        assert_eq!(Ext5::new([1, 0, 0, 0, 0]), a * a.inv());
+        assert_eq!(Ext5::ZERO, Ext5::ZERO.inv());
+    }
+
+    #[test]
+    fn scalar_arithmetic() {
+        // (n - 1) + 2 = 1 mod n
+        let n_minus_1 = Scalar::from_wide_limbs(&[
+            2492202976, 3893352854, 3609501852, 3901250617, 3484943929, 2147483622, 22, 2147483633,
+            2147483655, 2147483645,
+        ]);
+        let one = Scalar::from_wide_limbs(&[1]);
+        let two = Scalar::from_wide_limbs(&[2]);
+        assert_eq!(one, n_minus_1 + two);
+
+        // (n - 1)^2 = 1 mod n
+        assert_eq!(one, n_minus_1 * n_minus_1);
+
+        // 2^320 = 2^320 - 2n mod n
+        let two_pow_320 = Scalar::from_wide_limbs(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            [
+                3605528638, 803228882, 1370930886, 787433356, 1620046732, 50, 4294967251, 29,
+                4294967281, 4
+            ],
+            two_pow_320.limbs()
+        );
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let secret_key =
+            SecretKey::from_seed([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let public_key = secret_key.public_key();
+        let message = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+        let signature = secret_key.sign(message);
+        assert!(public_key.verify(message, &signature));
+
+        // signing is deterministic
+        assert_eq!(signature, secret_key.sign(message));
+
+        // the signature is not valid for a different message or a different key
+        let other_message = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(9)];
+        assert!(!public_key.verify(other_message, &signature));
+        let other_key = SecretKey::from_seed([Felt::new(0); 4]).public_key();
+        assert!(!other_key.verify(message, &signature));
+    }
+}
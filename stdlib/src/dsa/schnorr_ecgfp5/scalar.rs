@@ -0,0 +1,130 @@
+use core::ops::{Add, Mul};
+
+// SCALAR FIELD
+// ================================================================================================
+
+/// Number of 32-bit limbs in a scalar.
+pub const NUM_LIMBS: usize = 10;
+
+/// Order of the prime-order group of ecGFp5, i.e.
+/// n = 1067993516717146951041484916571792702745057740581727230159139685185762082554198619328292418486241.
+const ORDER: [u32; NUM_LIMBS] = [
+    2492202977, 3893352854, 3609501852, 3901250617, 3484943929, 2147483622, 22, 2147483633,
+    2147483655, 2147483645,
+];
+
+/// An integer modulo the order n of the prime-order group of ecGFp5.
+///
+/// The integer is represented by ten 32-bit limbs, the least significant limb first, and it is
+/// always smaller than n.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Scalar([u32; NUM_LIMBS]);
+
+impl Scalar {
+    /// Returns the integer represented by the provided limbs (the least significant limb first)
+    /// reduced modulo n.
+    ///
+    /// When the limbs encode a uniformly random integer which is much larger than n (e.g., 512
+    /// bits), the result is close to uniformly distributed modulo n.
+    pub fn from_wide_limbs(limbs: &[u32]) -> Self {
+        // Horner's method over the bits of the integer, starting with the most significant one
+        let mut result = Self::default();
+        for limb in limbs.iter().rev() {
+            for i in (0..32).rev() {
+                result = result + result;
+                if (limb >> i) & 1 == 1 {
+                    result = result + Self::one();
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the limbs of this scalar, the least significant limb first.
+    pub fn limbs(&self) -> [u32; NUM_LIMBS] {
+        self.0
+    }
+
+    /// Returns true if this scalar is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&limb| limb == 0)
+    }
+
+    /// Returns the bits of this scalar, starting with the least significant one.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        self.0
+            .iter()
+            .flat_map(|&limb| (0..32).map(move |i| (limb >> i) & 1 == 1))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn one() -> Self {
+        let mut limbs = [0; NUM_LIMBS];
+        limbs[0] = 1;
+        Self(limbs)
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (sum, carry) = add_limbs(&self.0, &rhs.0);
+
+        // both operands are smaller than n, and thus, subtracting n once is always enough
+        let (diff, borrow) = sub_limbs(&sum, &ORDER);
+        if carry || !borrow {
+            Self(diff)
+        } else {
+            Self(sum)
+        }
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // double-and-add over the bits of rhs, starting with the most significant one
+        let mut result = Self::default();
+        for limb in rhs.0.iter().rev() {
+            for i in (0..32).rev() {
+                result = result + result;
+                if (limb >> i) & 1 == 1 {
+                    result = result + self;
+                }
+            }
+        }
+        result
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a + b together with a flag indicating whether the sum overflowed.
+fn add_limbs(a: &[u32; NUM_LIMBS], b: &[u32; NUM_LIMBS]) -> ([u32; NUM_LIMBS], bool) {
+    let mut result = [0; NUM_LIMBS];
+    let mut carry = 0u64;
+    for ((r, &a), &b) in result.iter_mut().zip(a).zip(b) {
+        let sum = a as u64 + b as u64 + carry;
+        *r = sum as u32;
+        carry = sum >> 32;
+    }
+    (result, carry == 1)
+}
+
+/// Returns a - b together with a flag indicating whether the subtraction underflowed.
+fn sub_limbs(a: &[u32; NUM_LIMBS], b: &[u32; NUM_LIMBS]) -> ([u32; NUM_LIMBS], bool) {
+    let mut result = [0; NUM_LIMBS];
+    let mut borrow = false;
+    for ((r, &a), &b) in result.iter_mut().zip(a).zip(b) {
+        let (diff, borrow1) = a.overflowing_sub(b);
+        let (diff, borrow2) = diff.overflowing_sub(borrow as u32);
+        *r = diff;
+        borrow = borrow1 || borrow2;
+    }
+    (result, borrow)
+}
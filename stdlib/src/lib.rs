@@ -7,6 +7,7 @@ use vm_core::utils::{
 };
 
 pub mod asm;
+pub mod dsa;
 use asm::MODULES;
 
 // CONSTANTS
@@ -0,0 +1,104 @@
+use super::{verify, verify_with_min_security, Digest, StarkProof, VerificationError};
+use vm_core::{utils::collections::Vec, ProgramOutputs};
+
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+
+// BATCH VERIFIER
+// ================================================================================================
+
+/// Public inputs, outputs, and proof of a single program execution to be verified as a part of a
+/// batch.
+pub type BatchItem = (Vec<u64>, ProgramOutputs, StarkProof);
+
+/// Verifies proofs of many executions of the same program.
+///
+/// All proofs in a batch are verified against the same program hash and, optionally, the same
+/// minimum security level. When the `concurrent` feature is enabled, proofs are verified in
+/// multiple threads.
+///
+/// Currently, each proof is still verified independently: the underlying STARK verifier does not
+/// expose a way to reuse AIR setup between proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchVerifier {
+    program_hash: Digest,
+    min_security: Option<u32>,
+}
+
+impl BatchVerifier {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new batch verifier for the program with the specified hash.
+    pub fn new(program_hash: Digest) -> Self {
+        Self {
+            program_hash,
+            min_security: None,
+        }
+    }
+
+    /// Returns this verifier modified to reject proofs which provide less than `min_security`
+    /// bits of conjectured security.
+    pub fn with_min_security(mut self, min_security: u32) -> Self {
+        self.min_security = Some(min_security);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the program against which proofs are verified.
+    pub fn program_hash(&self) -> Digest {
+        self.program_hash
+    }
+
+    /// Returns the minimum security level required from the proofs, if any.
+    pub fn min_security(&self) -> Option<u32> {
+        self.min_security
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies all provided proofs and returns the result of verification for each of them. The
+    /// results are in the same order as the provided items.
+    ///
+    /// Stack inputs and outputs of each item follow the same ordering conventions as the
+    /// parameters of the [verify()] function.
+    pub fn verify(&self, items: Vec<BatchItem>) -> Vec<Result<(), VerificationError>> {
+        #[cfg(not(feature = "concurrent"))]
+        let results = items
+            .into_iter()
+            .map(|(stack_inputs, outputs, proof)| self.verify_one(&stack_inputs, &outputs, proof))
+            .collect();
+
+        #[cfg(feature = "concurrent")]
+        let results = items
+            .into_par_iter()
+            .map(|(stack_inputs, outputs, proof)| self.verify_one(&stack_inputs, &outputs, proof))
+            .collect();
+
+        results
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies a single proof against the program hash of this verifier.
+    fn verify_one(
+        &self,
+        stack_inputs: &[u64],
+        outputs: &ProgramOutputs,
+        proof: StarkProof,
+    ) -> Result<(), VerificationError> {
+        match self.min_security {
+            Some(min_security) => verify_with_min_security(
+                self.program_hash,
+                stack_inputs,
+                outputs,
+                proof,
+                min_security,
+            ),
+            None => verify(self.program_hash, stack_inputs, outputs, proof),
+        }
+    }
+}
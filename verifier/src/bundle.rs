@@ -0,0 +1,249 @@
+use super::{verify, verify_with_min_security, Digest, StarkProof, VerificationError};
+use air::ProofOptions;
+use vm_core::{
+    utils::{
+        collections::Vec, ByteReader, ByteWriter, Deserializable, DeserializationError,
+        Serializable, SliceReader,
+    },
+    Felt, ProgramOutputs, StarkField,
+};
+use winterfell::ProofOptions as WinterProofOptions;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes at the start of every serialized proof bundle.
+pub const PROOF_BUNDLE_MAGIC: [u8; 4] = *b"MVMP";
+
+/// Version of the proof bundle serialization format.
+pub const PROOF_BUNDLE_VERSION: u8 = 1;
+
+// PROOF BUNDLE
+// ================================================================================================
+
+/// A self-describing container for everything needed to verify a proof of program execution.
+///
+/// A bundle holds the hash of the executed program, the public stack inputs, the program outputs,
+/// the options with which the proof was generated, and the proof itself. Stack inputs and outputs
+/// follow the same ordering conventions as the parameters of the [verify()] function.
+///
+/// The program hash stored in a bundle is not authenticated by the proof: a valid proof of any
+/// program can be bundled with its own hash. Thus, a bundle can be verified only against the hash
+/// of the program which the verifier expects to have been executed.
+///
+/// When serialized, a bundle consists of the magic bytes `MVMP`, a version byte, the program hash,
+/// stack inputs and outputs (each as a u32 length followed by u64 values), proof options, and the
+/// proof bytes (prefixed with a u32 length). All integers are little-endian.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofBundle {
+    program_hash: Digest,
+    stack_inputs: Vec<u64>,
+    outputs: ProgramOutputs,
+    proof: StarkProof,
+}
+
+impl ProofBundle {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new proof bundle for the specified program hash, public inputs and outputs, and
+    /// execution proof.
+    pub fn new(
+        program_hash: Digest,
+        stack_inputs: Vec<u64>,
+        outputs: ProgramOutputs,
+        proof: StarkProof,
+    ) -> Self {
+        Self {
+            program_hash,
+            stack_inputs,
+            outputs,
+            proof,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the program whose execution is proven by this bundle.
+    pub fn program_hash(&self) -> Digest {
+        self.program_hash
+    }
+
+    /// Returns the values with which the stack was initialized prior to program execution.
+    pub fn stack_inputs(&self) -> &[u64] {
+        &self.stack_inputs
+    }
+
+    /// Returns the outputs of the program execution.
+    pub fn outputs(&self) -> &ProgramOutputs {
+        &self.outputs
+    }
+
+    /// Returns the options with which the proof in this bundle was generated.
+    pub fn options(&self) -> ProofOptions {
+        self.proof.options().clone().into()
+    }
+
+    /// Returns the proof of program execution.
+    pub fn proof(&self) -> &StarkProof {
+        &self.proof
+    }
+
+    /// Returns the conjectured security level (in bits) of the proof in this bundle.
+    pub fn security_level(&self) -> u32 {
+        self.proof.security_level(true)
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the proof in this bundle against the inputs and outputs stored in the bundle, and
+    /// makes sure that the proof was generated for the program with the specified hash.
+    ///
+    /// If `min_security` is provided, the proof must also provide at least `min_security` bits of
+    /// conjectured security; otherwise, proofs generated with any proof options are accepted.
+    ///
+    /// # Errors
+    /// Returns an error if the bundle is for a different program, if the security level of the
+    /// proof is too low, or if the proof does not prove a correct execution of the program.
+    pub fn verify(
+        self,
+        program_hash: Digest,
+        min_security: Option<u32>,
+    ) -> Result<(), VerificationError> {
+        self.check_program_hash(program_hash)?;
+        match min_security {
+            Some(min_security) => verify_with_min_security(
+                program_hash,
+                &self.stack_inputs,
+                &self.outputs,
+                self.proof,
+                min_security,
+            ),
+            None => verify(program_hash, &self.stack_inputs, &self.outputs, self.proof),
+        }
+    }
+
+    /// Returns an error if this bundle is not for the program with the specified hash.
+    fn check_program_hash(&self, program_hash: Digest) -> Result<(), VerificationError> {
+        if self.program_hash != program_hash {
+            return Err(VerificationError::ProgramHashMismatch {
+                expected: program_hash,
+                actual: self.program_hash,
+            });
+        }
+        Ok(())
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this bundle into a vector of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.write_into(&mut result);
+        result
+    }
+
+    /// Returns a proof bundle read from the specified `source`.
+    ///
+    /// # Errors
+    /// Returns an error if a valid bundle could not be read from the source, or if the source
+    /// contains bytes beyond the end of the bundle.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(source);
+        let bundle = Self::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(bundle)
+    }
+}
+
+impl Serializable for ProofBundle {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8_slice(&PROOF_BUNDLE_MAGIC);
+        target.write_u8(PROOF_BUNDLE_VERSION);
+        self.program_hash.write_into(target);
+        write_u64_vec(target, &self.stack_inputs);
+        write_u64_vec(target, self.outputs.stack());
+        write_u64_vec(target, self.outputs.overflow_addrs());
+        self.proof.options().write_into(target);
+
+        let proof_bytes = self.proof.to_bytes();
+        target.write_u32(proof_bytes.len() as u32);
+        target.write_u8_slice(&proof_bytes);
+    }
+}
+
+impl Deserializable for ProofBundle {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic = source.read_u8_array::<4>()?;
+        if magic != PROOF_BUNDLE_MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "not a Miden proof bundle".into(),
+            ));
+        }
+        let version = source.read_u8()?;
+        if version != PROOF_BUNDLE_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported proof bundle version {}; expected version {}",
+                version, PROOF_BUNDLE_VERSION
+            )));
+        }
+
+        let program_hash = Digest::read_from(source)?;
+        let stack_inputs = read_element_vec(source, "stack input")?;
+        let stack = read_element_vec(source, "stack output")?;
+        let overflow_addrs = read_element_vec(source, "overflow address")?;
+        let options = WinterProofOptions::read_from(source)?;
+
+        let proof_len = source.read_u32()? as usize;
+        let proof = StarkProof::from_bytes(&source.read_u8_vec(proof_len)?)?;
+        if proof.options() != &options {
+            return Err(DeserializationError::InvalidValue(
+                "bundle proof options do not match the options of the proof".into(),
+            ));
+        }
+
+        Ok(Self {
+            program_hash,
+            stack_inputs,
+            outputs: ProgramOutputs::new(stack, overflow_addrs),
+            proof,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the length of the provided slice as u32 followed by all of its values.
+fn write_u64_vec<W: ByteWriter>(target: &mut W, values: &[u64]) {
+    target.write_u32(values.len() as u32);
+    for &value in values {
+        target.write_u64(value);
+    }
+}
+
+/// Reads a vector of u64 values written by [write_u64_vec()].
+fn read_u64_vec<R: ByteReader>(source: &mut R) -> Result<Vec<u64>, DeserializationError> {
+    let len = source.read_u32()? as usize;
+    (0..len).map(|_| source.read_u64()).collect()
+}
+
+/// Reads a vector of u64 values written by [write_u64_vec()] and makes sure that all values are
+/// valid field elements.
+fn read_element_vec<R: ByteReader>(
+    source: &mut R,
+    value_name: &str,
+) -> Result<Vec<u64>, DeserializationError> {
+    let values = read_u64_vec(source)?;
+    if let Some(value) = values.iter().find(|&&value| value >= Felt::MODULUS) {
+        return Err(DeserializationError::InvalidValue(format!(
+            "{} {} is not a valid field element",
+            value_name, value
+        )));
+    }
+    Ok(values)
+}
@@ -0,0 +1,127 @@
+use super::Digest;
+use core::fmt;
+use vm_core::{
+    stack::STACK_TOP_SIZE,
+    utils::string::{String, ToString},
+};
+use winterfell::crypto::Digest as _;
+use winterfell::VerifierError;
+
+// VERIFICATION ERROR
+// ================================================================================================
+
+/// Describes why a proof of program execution failed verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationError {
+    /// The proof was generated for a program other than the expected one.
+    ProgramHashMismatch { expected: Digest, actual: Digest },
+    /// A stack input is not a valid field element.
+    InputNotFieldElement(u64),
+    /// A stack output or an overflow address is not a valid field element.
+    OutputNotFieldElement(u64),
+    /// Fewer stack outputs than the number of elements at the top of the stack were provided.
+    NotEnoughStackOutputs(usize),
+    /// The number of overflow addresses does not match the number of stack outputs which are
+    /// located in the overflow table.
+    InconsistentOverflowAddrs {
+        num_overflow_values: usize,
+        num_overflow_addrs: usize,
+    },
+    /// Constraint evaluations at the out-of-domain point are inconsistent with the proof. This
+    /// happens when the program hash, stack inputs, or outputs differ from the ones the proof was
+    /// generated for.
+    PublicInputsMismatch,
+    /// Security level of the proof is below the required minimum.
+    InsufficientSecurity { required: u32, actual: u32 },
+    /// The proof could not be decoded or is not a proof for the Miden VM field.
+    MalformedProof(String),
+    /// Queried trace or constraint evaluations do not match the commitments in the proof.
+    QueryCommitmentMismatch,
+    /// The proof-of-work nonce included in the proof is not valid.
+    ProofOfWorkFailed,
+    /// The low-degree (FRI) proof included in the proof is not valid.
+    FriVerificationFailed(String),
+}
+
+impl From<VerifierError> for VerificationError {
+    fn from(err: VerifierError) -> Self {
+        match err {
+            VerifierError::InconsistentOodConstraintEvaluations => Self::PublicInputsMismatch,
+            VerifierError::TraceQueryDoesNotMatchCommitment
+            | VerifierError::ConstraintQueryDoesNotMatchCommitment => Self::QueryCommitmentMismatch,
+            VerifierError::QuerySeedProofOfWorkVerificationFailed => Self::ProofOfWorkFailed,
+            VerifierError::FriVerificationFailed(err) => {
+                Self::FriVerificationFailed(err.to_string())
+            }
+            VerifierError::InconsistentBaseField
+            | VerifierError::UnsupportedFieldExtension(_)
+            | VerifierError::ProofDeserializationError(_)
+            | VerifierError::RandomCoinError => Self::MalformedProof(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProgramHashMismatch { expected, actual } => {
+                write!(f, "proof is for program ")?;
+                write_hex(f, actual)?;
+                write!(f, " but program ")?;
+                write_hex(f, expected)?;
+                write!(f, " was expected")
+            }
+            Self::InputNotFieldElement(value) => {
+                write!(f, "stack input {} is not a valid field element", value)
+            }
+            Self::OutputNotFieldElement(value) => {
+                write!(f, "output {} is not a valid field element", value)
+            }
+            Self::NotEnoughStackOutputs(num_outputs) => write!(
+                f,
+                "expected at least {} stack outputs, but {} were provided",
+                STACK_TOP_SIZE, num_outputs
+            ),
+            Self::InconsistentOverflowAddrs {
+                num_overflow_values,
+                num_overflow_addrs,
+            } => write!(
+                f,
+                "expected {} overflow addresses for {} stack outputs in the overflow table, but {} were provided",
+                num_overflow_values + 1,
+                num_overflow_values,
+                num_overflow_addrs
+            ),
+            Self::PublicInputsMismatch => write!(
+                f,
+                "proof does not match the public inputs; the program hash, stack inputs, or outputs \
+                differ from the ones the proof was generated for"
+            ),
+            Self::InsufficientSecurity { required, actual } => write!(
+                f,
+                "proof provides {} bits of conjectured security, but at least {} bits are required",
+                actual, required
+            ),
+            Self::MalformedProof(msg) => write!(f, "proof is malformed: {}", msg),
+            Self::QueryCommitmentMismatch => write!(
+                f,
+                "queried trace or constraint evaluations do not match commitments in the proof"
+            ),
+            Self::ProofOfWorkFailed => write!(f, "proof-of-work verification failed"),
+            Self::FriVerificationFailed(msg) => {
+                write!(f, "verification of the low-degree proof failed: {}", msg)
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the bytes of the specified digest as a hex string.
+fn write_hex(f: &mut fmt::Formatter<'_>, digest: &Digest) -> fmt::Result {
+    for byte in digest.as_bytes() {
+        write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+}
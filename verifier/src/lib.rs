@@ -1,14 +1,23 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
 use air::{ProcessorAir, PublicInputs};
-use core::fmt;
-use vm_core::{utils::collections::Vec, ProgramOutputs};
-use winterfell::VerifierError;
+use vm_core::{stack::STACK_TOP_SIZE, utils::collections::Vec, Felt, ProgramOutputs, StarkField};
+
+mod batch;
+mod bundle;
+mod errors;
 
 // EXPORTS
 // ================================================================================================
 
 pub use assembly;
+pub use batch::{BatchItem, BatchVerifier};
+pub use bundle::{ProofBundle, PROOF_BUNDLE_MAGIC, PROOF_BUNDLE_VERSION};
+pub use errors::VerificationError;
 pub use vm_core::chiplets::hasher::Digest;
 pub use winterfell::StarkProof;
 
@@ -31,7 +40,8 @@ pub use winterfell::StarkProof;
 /// order on the stack. This is the reverse of the order of the `stack_inputs` slice.
 ///
 /// # Errors
-/// Returns an error if the provided proof does not prove a correct execution of the program.
+/// Returns an error if the provided proof does not prove a correct execution of the program. The
+/// error describes the reason for the failure; see [VerificationError] for details.
 pub fn verify(
     program_hash: Digest,
     stack_inputs: &[u64],
@@ -48,25 +58,73 @@ pub fn verify(
         );
     }
 
+    // make sure the outputs are well-formed
+    validate_outputs(outputs)?;
+
     // build public inputs and try to verify the proof
     let pub_inputs = PublicInputs::new(program_hash, stack_input_felts, outputs.clone());
-    winterfell::verify::<ProcessorAir>(proof, pub_inputs).map_err(VerificationError::VerifierError)
+    winterfell::verify::<ProcessorAir>(proof, pub_inputs).map_err(VerificationError::from)
 }
 
-// ERRORS
-// ================================================================================================
+/// Returns Ok(()) if the specified program was executed correctly against the specified inputs
+/// and outputs, and if the proof provides at least `min_security` bits of conjectured security.
+///
+/// Conjectured security level of a proof is determined by the options with which the proof was
+/// generated: the number of queries, blowup factor, grinding factor, field extension, and hash
+/// function. Checking the security level prevents a prover from submitting a proof generated with
+/// weak options (e.g., a single query). Other than that, this function behaves the same as
+/// [verify()].
+///
+/// # Errors
+/// Returns an error if the security level of the proof is below `min_security` bits, or if the
+/// provided proof does not prove a correct execution of the program.
+pub fn verify_with_min_security(
+    program_hash: Digest,
+    stack_inputs: &[u64],
+    outputs: &ProgramOutputs,
+    proof: StarkProof,
+    min_security: u32,
+) -> Result<(), VerificationError> {
+    let security_level = proof.security_level(true);
+    if security_level < min_security {
+        return Err(VerificationError::InsufficientSecurity {
+            required: min_security,
+            actual: security_level,
+        });
+    }
 
-/// TODO: add docs, implement Display
-#[derive(Debug, PartialEq, Eq)]
-pub enum VerificationError {
-    VerifierError(VerifierError),
-    InputNotFieldElement(u64),
-    OutputNotFieldElement(u64),
+    verify(program_hash, stack_inputs, outputs, proof)
 }
 
-impl fmt::Display for VerificationError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // TODO: implement friendly messages
-        write!(f, "{self:?}")
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Makes sure that all outputs are valid field elements, that the outputs contain at least the
+/// full top of the stack, and that the overflow addresses are consistent with the number of stack
+/// outputs in the overflow table.
+fn validate_outputs(outputs: &ProgramOutputs) -> Result<(), VerificationError> {
+    if let Some(&value) = outputs
+        .stack()
+        .iter()
+        .chain(outputs.overflow_addrs())
+        .find(|&&value| value >= Felt::MODULUS)
+    {
+        return Err(VerificationError::OutputNotFieldElement(value));
     }
+
+    let num_outputs = outputs.stack().len();
+    if num_outputs < STACK_TOP_SIZE {
+        return Err(VerificationError::NotEnoughStackOutputs(num_outputs));
+    }
+
+    let num_overflow_values = num_outputs - STACK_TOP_SIZE;
+    let num_overflow_addrs = outputs.overflow_addrs().len();
+    if outputs.has_overflow() && num_overflow_addrs != num_overflow_values + 1 {
+        return Err(VerificationError::InconsistentOverflowAddrs {
+            num_overflow_values,
+            num_overflow_addrs,
+        });
+    }
+
+    Ok(())
 }